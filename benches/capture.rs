@@ -0,0 +1,102 @@
+//! Average time to capture the whole primary screen, over 100 captures, against the
+//! `CreateCompatibleBitmap` + `GetDIBits` path `capture_screen` used before it drew into a DIB
+//! section. Fails unless the DIB section is at least 20% faster. Needs a Windows desktop.
+//!
+//! ```text
+//! cargo bench --bench capture
+//! ```
+
+mod common;
+
+use image::RgbaImage;
+use mouse::modules::image_recognition::capture_screen;
+use std::mem::size_of;
+use windows::Win32::Foundation::HWND;
+use windows::Win32::Graphics::Gdi;
+
+const CAPTURES: u32 = 100;
+
+/// The most the DIB section capture may take, as a share of the baseline's time
+const REQUIRED_RATIO: f64 = 0.8;
+
+/// The old capture: BitBlt into a device-dependent bitmap, then copy it out with `GetDIBits`
+/// and swap the channels in a second pass. `None` if any call fails.
+fn capture_screen_get_dibits() -> Option<RgbaImage> {
+    unsafe {
+        let screen_dc = Gdi::GetDC(HWND(0));
+        if screen_dc.is_invalid() {
+            return None;
+        }
+        let screen_width = Gdi::GetDeviceCaps(screen_dc, Gdi::HORZRES);
+        let screen_height = Gdi::GetDeviceCaps(screen_dc, Gdi::VERTRES);
+
+        let compatible_dc = Gdi::CreateCompatibleDC(screen_dc);
+        let bitmap = Gdi::CreateCompatibleBitmap(screen_dc, screen_width, screen_height);
+        let old_bitmap = Gdi::SelectObject(compatible_dc, bitmap);
+        let copied = Gdi::BitBlt(compatible_dc, 0, 0, screen_width, screen_height, screen_dc, 0, 0, Gdi::SRCCOPY).is_ok();
+
+        let mut bitmap_info = Gdi::BITMAPINFO {
+            bmiHeader: Gdi::BITMAPINFOHEADER {
+                biSize: size_of::<Gdi::BITMAPINFOHEADER>() as u32,
+                biWidth: screen_width,
+                biHeight: -screen_height, // Negative height for top-down DIB
+                biPlanes: 1,
+                biBitCount: 32,
+                biCompression: 0, // BI_RGB = 0
+                ..Default::default()
+            },
+            bmiColors: [Gdi::RGBQUAD::default()],
+        };
+
+        let buffer_size = (screen_width * screen_height * 4) as usize;
+        let mut buffer = vec![0u8; buffer_size];
+        let lines = Gdi::GetDIBits(
+            compatible_dc,
+            bitmap,
+            0,
+            screen_height as u32,
+            Some(buffer.as_mut_ptr() as *mut std::ffi::c_void),
+            &mut bitmap_info,
+            Gdi::DIB_RGB_COLORS
+        );
+
+        Gdi::SelectObject(compatible_dc, old_bitmap);
+        Gdi::DeleteObject(bitmap);
+        Gdi::DeleteDC(compatible_dc);
+        Gdi::ReleaseDC(HWND(0), screen_dc);
+
+        if !copied || lines == 0 {
+            return None;
+        }
+
+        for i in (0..buffer_size).step_by(4) {
+            buffer.swap(i, i + 2);
+        }
+        RgbaImage::from_raw(screen_width as u32, screen_height as u32, buffer)
+    }
+}
+
+fn main() {
+    let screen = match capture_screen() {
+        Ok(screen) => screen,
+        Err(e) => {
+            println!("Skipping the capture bench, the screen can't be captured here: {}", e);
+            return;
+        },
+    };
+    println!("capture_screen, {}x{}", screen.width(), screen.height());
+
+    let baseline = common::bench("CreateCompatibleBitmap + GetDIBits", CAPTURES, || {
+        capture_screen_get_dibits().expect("baseline screen capture failed")
+    });
+    let dib_section = common::bench("capture_screen (DIB section)", CAPTURES, || capture_screen().expect("screen capture failed"));
+
+    let ratio = dib_section.as_secs_f64() / baseline.as_secs_f64();
+    println!("time of the baseline: {:.0}%", ratio * 100.0);
+    assert!(
+        ratio <= REQUIRED_RATIO,
+        "capture_screen took {:.0}% of the baseline's time, it should be at most {:.0}%",
+        ratio * 100.0,
+        REQUIRED_RATIO * 100.0
+    );
+}
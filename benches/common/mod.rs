@@ -0,0 +1,29 @@
+//! Timing helpers shared by the benches. They run on the stable toolchain with `harness = false`,
+//! so each bench is a plain `main` that prints its own results.
+
+use image::GrayImage;
+use rand::{Rng, SeedableRng};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Run `f` once untimed, then `iterations` times, and print the mean time per run
+pub fn bench<T>(name: &str, iterations: u32, mut f: impl FnMut() -> T) -> Duration {
+    black_box(f());
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(f());
+    }
+    let mean = start.elapsed() / iterations;
+
+    println!("{:<48} {:>10.3} ms", name, mean.as_secs_f64() * 1000.0);
+    mean
+}
+
+/// A screen-sized image of blurred noise, the same for the same seed. The blur gives it the
+/// smooth regions real screenshots have, so neighboring positions correlate like they would there.
+pub fn blurred_noise(width: u32, height: u32, seed: u64) -> GrayImage {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+    let noise = GrayImage::from_fn(width, height, |_, _| image::Luma([rng.gen()]));
+    image::imageops::blur(&noise, 3.0)
+}
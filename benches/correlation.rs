@@ -0,0 +1,40 @@
+//! The correlation loop of the template search: a 50×50 template scored at every other position
+//! of a 1920×1080 screen, as the coarse first pass does, with and without the AVX2 path.
+//!
+//! The AVX2 path is only compiled in for targets with AVX2, so build for the local CPU to time it:
+//!
+//! ```text
+//! RUSTFLAGS="-C target-cpu=native" cargo bench --bench correlation
+//! ```
+
+mod common;
+
+use image::GrayImage;
+use mouse::modules::image_recognition::{correlation_at, correlation_at_scalar};
+
+const STEP: usize = 2;
+
+/// Best score over the first-pass positions, so the work can't be optimized away
+fn first_pass(screen: &GrayImage, template: &GrayImage, score: fn(&GrayImage, &GrayImage, u32, u32, usize) -> f32) -> f32 {
+    let max_x = screen.width() - template.width();
+    let max_y = screen.height() - template.height();
+
+    (0..=max_y)
+        .step_by(STEP)
+        .flat_map(|y| (0..=max_x).step_by(STEP).map(move |x| (x, y)))
+        .map(|(x, y)| score(screen, template, x, y, STEP))
+        .fold(0.0, f32::max)
+}
+
+fn main() {
+    let screen = common::blurred_noise(1920, 1080, 2);
+    let template = image::imageops::crop_imm(&screen, 700, 400, 50, 50).to_image();
+
+    let avx2 = cfg!(all(target_arch = "x86_64", target_feature = "avx2"));
+    println!("First pass, 50x50 on 1920x1080, AVX2 {}", if avx2 { "compiled in" } else { "not compiled in" });
+
+    let scalar = common::bench("correlation_at_scalar", 3, || first_pass(&screen, &template, correlation_at_scalar));
+    let vectorized = common::bench("correlation_at", 3, || first_pass(&screen, &template, correlation_at));
+
+    println!("speedup: {:.2}x", scalar.as_secs_f64() / vectorized.as_secs_f64());
+}
@@ -0,0 +1,28 @@
+//! Full template search of an 80×80 template on a 1920×1080 screen.
+//!
+//! Compare the single-threaded and parallel row scans with
+//!
+//! ```text
+//! cargo bench --bench matching
+//! cargo bench --bench matching --features parallel-matching
+//! ```
+
+mod common;
+
+use image::DynamicImage;
+use mouse::modules::image_recognition::{find_template, DEFAULT_THRESHOLD};
+
+fn main() {
+    let screen = common::blurred_noise(1920, 1080, 1);
+    let template = image::imageops::crop_imm(&screen, 1201, 633, 80, 80).to_image();
+    let (screen, template) = (DynamicImage::ImageLuma8(screen), DynamicImage::ImageLuma8(template));
+
+    let scan = if cfg!(feature = "parallel-matching") { "parallel rows" } else { "one thread" };
+    println!("find_template, 80x80 on 1920x1080, {}", scan);
+
+    common::bench("find_template", 3, || {
+        let found = find_template(&screen, &template, DEFAULT_THRESHOLD);
+        assert_eq!(found, Some((1201, 633)));
+        found
+    });
+}
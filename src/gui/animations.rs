@@ -2,6 +2,7 @@ use eframe::egui::{self, Color32, Ui, Vec2, Pos2};
 use std::time::{Duration, Instant};
 
 /// Animation state for smooth transitions
+#[derive(Clone)]
 pub struct Animation {
     start_time: Instant,
     duration: Duration,
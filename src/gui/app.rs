@@ -1,17 +1,34 @@
 use eframe::{egui, CreationContext};
 use egui::{Context, RichText, Visuals};
 use std::sync::{Arc, Mutex};
+use std::sync::mpsc::Receiver;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::path::PathBuf;
+use std::collections::{HashMap, VecDeque};
+use chrono::{DateTime, Local};
 
 use crate::gui::clicker::ClickerThread;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use crate::modules::config::Config;
 use crate::modules::profiles::ProfileManager;
-use crate::gui::views::{ProfileView, AreaView, SettingsView, StatsView, ImageView};
+use crate::modules::preferences::AppPreferences;
+use crate::modules::paths;
+use crate::modules::sound::{self, SoundAlert};
+use crate::modules::notifications;
+use crate::modules::ui::{format_duration, format_thousands};
+use crate::modules::i18n;
+use crate::t;
+use crate::modules::event_log::{ClickEvent, EventKind, SessionEvent};
+use crate::modules::broadcast::Broadcaster;
+use crate::modules::swap_cell::SwapCell;
+use crate::modules::webhook::{DeliveryResult, WebhookSender};
+use crate::gui::views::stats_view::SessionSnapshot;
+use crate::gui::views::{ProfileView, AreaView, SettingsView, StatsView, ImageView, HudView, LogView, OverlayView};
 use crate::gui::theme::AppTheme;
 use crate::gui::components;
-use crate::gui::animations::Animation;
+use crate::gui::animations::{lerp_color, Animation};
+use crate::gui::onboarding::{Onboarding, OnboardingStep};
 
 /// Enum representing the current view in the application
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -21,6 +38,8 @@ pub enum AppView {
     Settings,
     Stats,
     Images,
+    Logs,
+    Overlay,
     Running,
 }
 
@@ -32,39 +51,261 @@ pub enum ClickerStatus {
     Paused,
 }
 
+/// Maximum number of configuration snapshots kept for undo
+const CONFIG_HISTORY_LIMIT: usize = 20;
+
+/// Oldest session events are dropped past this many
+const EVENT_LOG_LIMIT: usize = 10_000;
+
+/// Session snapshots kept for comparison in the stats view
+const SESSION_SNAPSHOT_LIMIT: usize = 5;
+
+/// Length of one on/off cycle of the dry run banner
+const DRY_RUN_BLINK_SECS: f32 = 1.2;
+
+/// Length of one pulse of the sidebar's running indicator
+const RUNNING_PULSE_SECS: f32 = 1.5;
+
+/// How long the webhook indicator takes to dim after a delivery
+const WEBHOOK_FADE_SECS: f32 = 30.0;
+
 /// Main application state
 pub struct AppState {
     pub current_view: AppView,
     pub clicker_status: ClickerStatus,
     pub profile_manager: ProfileManager,
     pub current_config: Config,
+    /// Snapshot of `current_config` read by the clicker thread, refreshed by `publish_config`
+    pub live_config: Arc<SwapCell<Config>>,
+    /// Kept out of `current_config` so loading a profile, resetting or undoing can't turn it off.
+    /// `publish_config` copies it into `live_config`.
+    pub dry_run: bool,
     pub profiles_dir: PathBuf,
-    pub click_count: u32,
+    /// Updated by the clicker thread without taking the state lock
+    pub click_count: Arc<AtomicU64>,
+    /// Every click performed, delivered to each subscriber
+    pub click_events: Arc<Broadcaster<ClickEvent>>,
+    /// Errors that ended a session, shown in the status bar
+    pub error_queue: Arc<Broadcaster<String>>,
+    /// Every entry added to `event_log`, for the webhook sender
+    pub session_events: Arc<Broadcaster<SessionEvent>>,
     pub start_time: Option<Instant>,
+    /// Seconds left before the first click, while the clicker thread counts down after Start
+    pub startup_countdown: Option<u32>,
     pub is_dark_mode: bool,
     pub theme: AppTheme,
     pub view_transition: Animation,
+    pub config_history: VecDeque<Config>,
+    pub config_redo: VecDeque<Config>,
+    pub config_history_times: VecDeque<DateTime<Local>>,
+    /// Results of earlier sessions saved from the stats view, oldest first
+    pub session_snapshots: VecDeque<SessionSnapshot>,
+    committed_config: Config,
+    pub preferences: AppPreferences,
+    pub hud_repositioning: bool,
+    pub next_click_at: Arc<SwapCell<Option<Instant>>>,
+    /// Where each named area was last clicked, for areas anchored to it
+    pub last_click_positions: Arc<Mutex<HashMap<String, (i32, i32)>>>,
+    pub rate_limited_count: u32,
+    /// Clicks skipped because their button was already held down
+    pub skipped_double_presses: u32,
+    pub bursts_completed: u32,
+    pub last_presence_confirmation: Option<Instant>,
+    pub presence_prompt_since: Option<Instant>,
+    pub event_log: Vec<SessionEvent>,
+    /// Whether the last webhook delivery succeeded, and when it happened
+    pub last_webhook_status: Option<(bool, Instant)>,
+    /// Tooltip summary of the last delivery, e.g. "OK" or "FAILED: 403"
+    pub last_webhook_summary: String,
+    /// Dims the status bar indicator after a delivery
+    pub webhook_fade: Animation,
 }
 
 impl Default for AppState {
     fn default() -> Self {
-        let profiles_dir = PathBuf::from("profiles");
+        let profiles_dir = paths::profiles_dir();
         if !profiles_dir.exists() {
             std::fs::create_dir_all(&profiles_dir).expect("Failed to create profiles directory");
         }
 
-        // Make sure we don't start on the Images view
+        let preferences = AppPreferences::load(&paths::preferences_file()).unwrap_or_else(|e| {
+            eprintln!("Failed to load preferences: {}", e);
+            AppPreferences::default()
+        });
+
+        Self::new(profiles_dir, preferences)
+    }
+}
+
+impl AppState {
+    /// State for a session that keeps its profiles in `profiles_dir`
+    pub fn new(profiles_dir: PathBuf, preferences: AppPreferences) -> Self {
+        let mut theme = preferences.custom_theme.clone().unwrap_or_else(AppTheme::dark);
+        theme.apply_overrides(&preferences.color_overrides);
+        i18n::set_language(&preferences.language);
+
+        // The session starts from the defaults plus any MOUSE_CLICKER_* variables, for runs without a profile file
+        let mut config = Config::default();
+        config.apply_env_overrides();
+
         Self {
             current_view: AppView::Profiles, // Default view
             clicker_status: ClickerStatus::Stopped,
-            profile_manager: ProfileManager::new("profiles"),
-            current_config: Config::default(),
+            profile_manager: ProfileManager::new(&profiles_dir),
+            current_config: config.clone(),
+            live_config: Arc::new(SwapCell::new(config.clone())),
+            dry_run: false,
             profiles_dir,
-            click_count: 0,
+            click_count: Arc::new(AtomicU64::new(0)),
+            click_events: Arc::new(Broadcaster::new()),
+            error_queue: Arc::new(Broadcaster::new()),
+            session_events: Arc::new(Broadcaster::new()),
             start_time: None,
-            is_dark_mode: true,
-            theme: AppTheme::dark(),
+            startup_countdown: None,
+            is_dark_mode: theme.is_dark(),
+            theme,
             view_transition: Animation::new(0.3),
+            config_history: VecDeque::new(),
+            config_redo: VecDeque::new(),
+            config_history_times: VecDeque::new(),
+            session_snapshots: VecDeque::new(),
+            committed_config: config,
+            preferences,
+            hud_repositioning: false,
+            next_click_at: Arc::new(SwapCell::new(None)),
+            last_click_positions: Arc::new(Mutex::new(HashMap::new())),
+            rate_limited_count: 0,
+            skipped_double_presses: 0,
+            bursts_completed: 0,
+            last_presence_confirmation: None,
+            presence_prompt_since: None,
+            event_log: Vec::new(),
+            last_webhook_status: None,
+            last_webhook_summary: String::new(),
+            webhook_fade: Animation::new(WEBHOOK_FADE_SECS),
+        }
+    }
+
+    /// Whether the configuration is read-only because a session is active
+    pub fn is_config_locked(&self) -> bool {
+        self.clicker_status != ClickerStatus::Stopped
+    }
+
+    /// Remember the outcome of a webhook delivery for the status bar indicator
+    pub fn record_webhook_delivery(&mut self, result: &DeliveryResult) {
+        self.last_webhook_status = Some((result.is_success(), Instant::now()));
+        self.last_webhook_summary = result.summary();
+        self.webhook_fade.reset();
+    }
+
+    /// Write the application preferences to disk
+    pub fn save_preferences(&self) {
+        if let Err(e) = self.preferences.save(&paths::preferences_file()) {
+            eprintln!("Failed to save preferences: {}", e);
+        }
+    }
+
+    /// Append a timestamped entry to the session event log
+    pub fn log_event(&mut self, kind: EventKind, detail: Option<String>) {
+        let sounds = &self.preferences.sound_alerts;
+        let alert = match kind {
+            EventKind::Stopped if sounds.on_stop => Some(SoundAlert::Stopped),
+            EventKind::Error if sounds.on_error => Some(SoundAlert::Error),
+            EventKind::TargetFound if sounds.on_target_found => Some(SoundAlert::TargetFound),
+            _ => None,
+        };
+        if let Some(alert) = alert {
+            sound::play(alert);
+        }
+
+        if kind == EventKind::Error && self.preferences.desktop_notifications && sounds.on_error {
+            notifications::show(detail.clone().unwrap_or_else(|| "The session failed".to_string()));
+        }
+
+        let event = SessionEvent::new(kind, detail);
+        self.session_events.send(event.clone());
+        self.event_log.push(event);
+        if self.event_log.len() > EVENT_LOG_LIMIT {
+            let excess = self.event_log.len() - EVENT_LOG_LIMIT;
+            self.event_log.drain(..excess);
+        }
+    }
+
+    /// Keep a session's results for comparison, dropping the oldest past the limit
+    pub fn save_session_snapshot(&mut self, snapshot: SessionSnapshot) {
+        self.session_snapshots.push_back(snapshot);
+        while self.session_snapshots.len() > SESSION_SNAPSHOT_LIMIT {
+            self.session_snapshots.pop_front();
+        }
+    }
+
+    /// Tell the user a session ended without them, if they've asked for notifications.
+    /// Call before `start_time` is cleared.
+    pub fn notify_session_complete(&self) {
+        if !self.preferences.desktop_notifications {
+            return;
+        }
+        let clicks = self.click_count.load(Ordering::Relaxed);
+        let minutes = self.start_time.map(|start| start.elapsed().as_secs() / 60).unwrap_or(0);
+        notifications::show(t!("app.session_complete", clicks = format_thousands(clicks), minutes = minutes));
+    }
+
+    /// The current configuration as the clicker should run it, with this session's dry run setting
+    pub fn session_config(&self) -> Config {
+        let mut config = self.current_config.clone();
+        config.dry_run = self.dry_run;
+        config
+    }
+
+    /// Make the current configuration visible to the clicker thread if it has changed
+    pub fn publish_config(&self) {
+        let config = self.session_config();
+        if *self.live_config.load() != config {
+            self.live_config.store(config);
+        }
+    }
+
+    /// Record the last committed configuration in the undo history if it has changed since
+    pub fn commit_config_changes(&mut self) {
+        if self.current_config == self.committed_config {
+            return;
+        }
+
+        let previous = std::mem::replace(&mut self.committed_config, self.current_config.clone());
+        self.config_history.push_back(previous);
+        self.config_history_times.push_back(Local::now());
+        if self.config_history.len() > CONFIG_HISTORY_LIMIT {
+            self.config_history.pop_front();
+            self.config_history_times.pop_front();
+        }
+        self.config_redo.clear();
+    }
+
+    /// Revert to the previous configuration snapshot
+    pub fn undo_config(&mut self) -> bool {
+        match self.config_history.pop_back() {
+            Some(previous) => {
+                self.config_history_times.pop_back();
+                let current = std::mem::replace(&mut self.current_config, previous);
+                self.config_redo.push_back(current);
+                self.committed_config = self.current_config.clone();
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Re-apply the most recently undone configuration snapshot
+    pub fn redo_config(&mut self) -> bool {
+        match self.config_redo.pop_back() {
+            Some(next) => {
+                let current = std::mem::replace(&mut self.current_config, next);
+                self.config_history.push_back(current);
+                self.config_history_times.push_back(Local::now());
+                self.committed_config = self.current_config.clone();
+                true
+            },
+            None => false,
         }
     }
 }
@@ -77,7 +318,18 @@ pub struct MouseClickerApp {
     settings_view: SettingsView,
     stats_view: StatsView,
     image_view: ImageView,
+    hud_view: HudView,
+    log_view: LogView,
+    overlay_view: OverlayView,
+    onboarding: Onboarding,
     clicker_thread: ClickerThread,
+    show_change_log: bool,
+    dry_run_blink: Animation,
+    running_pulse: Animation,
+    errors: Receiver<String>,
+    /// Most recent error from `errors`, until dismissed
+    last_error: Option<String>,
+    _webhook_sender: WebhookSender,
 }
 
 
@@ -100,6 +352,12 @@ impl MouseClickerApp {
         let settings_view = SettingsView::new(Arc::clone(&state));
         let stats_view = StatsView::new(Arc::clone(&state));
         let image_view = ImageView::new(Arc::clone(&state));
+        let hud_view = HudView::new(Arc::clone(&state));
+        let log_view = LogView::new(Arc::clone(&state));
+        let overlay_view = OverlayView::new(Arc::clone(&state));
+        let onboarding = Onboarding::new(Arc::clone(&state));
+        let errors = state.lock().unwrap().error_queue.subscribe();
+        let webhook_sender = WebhookSender::start(Arc::clone(&state));
 
         Self {
             state,
@@ -108,7 +366,17 @@ impl MouseClickerApp {
             settings_view,
             stats_view,
             image_view,
+            hud_view,
+            log_view,
+            overlay_view,
+            onboarding,
             clicker_thread: ClickerThread::new(),
+            show_change_log: false,
+            dry_run_blink: Animation::new(DRY_RUN_BLINK_SECS),
+            running_pulse: Animation::new(RUNNING_PULSE_SECS),
+            errors,
+            last_error: None,
+            _webhook_sender: webhook_sender,
         }
     }
 
@@ -117,27 +385,191 @@ impl MouseClickerApp {
         let mut state = self.state.lock().unwrap();
         state.is_dark_mode = !state.is_dark_mode;
 
-        if state.is_dark_mode {
-            state.theme = AppTheme::dark();
-        } else {
-            state.theme = AppTheme::light();
-        }
+        state.theme = AppTheme::preset(state.is_dark_mode);
+        let overrides = state.preferences.color_overrides.clone();
+        state.theme.apply_overrides(&overrides);
 
         // Apply the updated theme
         state.theme.apply_to_ctx(ctx);
+
+        // Remember the theme so it's restored on the next start
+        state.preferences.custom_theme = Some(state.theme.clone());
+        state.save_preferences();
+    }
+
+    /// Pause the run for a presence check when due, and stop it if the check goes unanswered
+    fn check_dead_man_switch(&mut self, ctx: &Context) {
+        let mut state = self.state.lock().unwrap();
+        let safety = state.current_config.safety.clone();
+
+        if !safety.dead_man_enabled || state.clicker_status == ClickerStatus::Stopped {
+            state.last_presence_confirmation = None;
+            state.presence_prompt_since = None;
+            return;
+        }
+
+        let last_confirmation = *state.last_presence_confirmation.get_or_insert_with(Instant::now);
+
+        match state.presence_prompt_since {
+            None => {
+                let interval = Duration::from_secs(safety.dead_man_interval_minutes as u64 * 60);
+                if state.clicker_status == ClickerStatus::Running && last_confirmation.elapsed() >= interval {
+                    self.clicker_thread.pause();
+                    state.clicker_status = ClickerStatus::Paused;
+                    state.presence_prompt_since = Some(Instant::now());
+                    state.log_event(EventKind::Paused, Some("Presence confirmation requested".to_string()));
+
+                    // Make sure the prompt is seen even if the window was minimized
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+                    ctx.send_viewport_cmd(egui::ViewportCommand::RequestUserAttention(egui::UserAttentionType::Critical));
+                }
+            },
+            Some(prompt_since) => {
+                let timeout = Duration::from_secs(safety.dead_man_timeout_secs as u64);
+                let remaining = timeout.saturating_sub(prompt_since.elapsed());
+                if remaining.is_zero() {
+                    drop(state);
+                    self.clicker_thread.stop();
+                    let mut state = self.state.lock().unwrap();
+                    state.notify_session_complete();
+                    state.clicker_status = ClickerStatus::Stopped;
+                    state.start_time = None;
+                    state.presence_prompt_since = None;
+                    state.last_presence_confirmation = None;
+                    state.log_event(EventKind::Stopped, Some("Presence confirmation timed out".to_string()));
+                    return;
+                }
+
+                let theme = state.theme.clone();
+                drop(state);
+
+                let mut confirmed = false;
+                egui::Window::new(t!("app.still_there_title"))
+                    .collapsible(false)
+                    .resizable(false)
+                    .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                    .show(ctx, |ui| {
+                        ui.label(RichText::new(t!(
+                            "app.still_there_countdown",
+                            seconds = remaining.as_secs() + 1
+                        )).size(18.0).strong().color(theme.warning));
+                        ui.add_space(8.0);
+                        ui.label(t!("app.still_there_body"));
+                        ui.add_space(8.0);
+                        ui.vertical_centered(|ui| {
+                            confirmed = components::primary_button(ui, &theme, &t!("app.still_there_confirm"), None);
+                        });
+                    });
+
+                let mut state = self.state.lock().unwrap();
+                if confirmed {
+                    self.clicker_thread.resume();
+                    state.clicker_status = ClickerStatus::Running;
+                    state.presence_prompt_since = None;
+                    state.last_presence_confirmation = Some(Instant::now());
+                    state.log_event(EventKind::Resumed, Some("Presence confirmed".to_string()));
+                } else if state.clicker_status == ClickerStatus::Running {
+                    // Resumed from the status bar, which counts as a confirmation
+                    state.presence_prompt_since = None;
+                    state.last_presence_confirmation = Some(Instant::now());
+                }
+            },
+        }
+    }
+
+    /// Handle the app-level undo/redo shortcuts for configuration changes
+    fn handle_undo_shortcuts(&self, ctx: &Context) {
+        // Text fields have their own undo handling
+        if ctx.wants_keyboard_input() {
+            return;
+        }
+
+        let (undo, redo) = ctx.input(|i| {
+            let undo = i.modifiers.command && !i.modifiers.shift && i.key_pressed(egui::Key::Z);
+            let redo = i.modifiers.command
+                && (i.key_pressed(egui::Key::Y) || (i.modifiers.shift && i.key_pressed(egui::Key::Z)));
+            (undo, redo)
+        });
+
+        let mut state = self.state.lock().unwrap();
+        if state.is_config_locked() {
+            return;
+        }
+
+        if undo {
+            state.undo_config();
+        } else if redo {
+            state.redo_config();
+        }
     }
+
+    /// Show the configuration change log window
+    fn change_log_window(&mut self, ctx: &Context) {
+        let state = self.state.lock().unwrap();
+        let theme = state.theme.clone();
+
+        egui::Window::new("Change Log")
+            .open(&mut self.show_change_log)
+            .default_size([420.0, 360.0])
+            .show(ctx, |ui| {
+                if state.config_history.is_empty() {
+                    ui.label(RichText::new("No changes recorded yet.").italics());
+                    return;
+                }
+
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    // Newest changes first; each snapshot is compared with the one that replaced it
+                    for i in (0..state.config_history.len()).rev() {
+                        let before = &state.config_history[i];
+                        let after = state.config_history.get(i + 1).unwrap_or(&state.current_config);
+                        let timestamp = state.config_history_times[i].format("%H:%M:%S");
+
+                        ui.label(RichText::new(t!("app.change_at", number = i + 1, time = timestamp)).strong());
+                        for field in before.changed_fields(after) {
+                            ui.label(RichText::new(format!("  • {}", field)).color(theme.muted_text).monospace());
+                        }
+                        ui.separator();
+                    }
+                });
+            });
+    }
+}
+
+/// Colored dot for the last webhook delivery: green if it succeeded, red if it failed, gray if nothing was sent yet
+fn webhook_indicator(ui: &mut egui::Ui, theme: &AppTheme, state: &AppState) {
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(12.0, 12.0), egui::Sense::hover());
+
+    let (color, tooltip) = match state.last_webhook_status {
+        None => (theme.muted_text, "Last webhook: never sent".to_string()),
+        Some((succeeded, at)) => {
+            let color = if succeeded { theme.success } else { theme.error };
+            // Bright right after a delivery, dimming as it becomes old news
+            let color = color.gamma_multiply(state.webhook_fade.lerp(1.0, 0.35));
+            let tooltip = t!("app.last_webhook", ms = at.elapsed().as_millis(), summary = state.last_webhook_summary);
+            (color, tooltip)
+        },
+    };
+
+    ui.painter().circle_filled(rect.center(), 4.0, color);
+    components::AnimatedTooltip::attach(ui, theme, &response, Some(&tooltip));
 }
 
 impl eframe::App for MouseClickerApp {
     fn update(&mut self, ctx: &Context, _frame: &mut eframe::Frame) {
-        // Check if we're on the Images view and redirect if needed
-        {
-            let mut state = self.state.lock().unwrap();
-            if state.current_view == AppView::Images {
-                // Uncomment the following line to re-enable redirection
-                // state.current_view = AppView::Areas; // Redirect to Areas view
-            }
+        self.handle_undo_shortcuts(ctx);
+        self.profile_view.finish_profile_switch(false);
+        self.stats_view.receive_click_events();
+        self.area_view.receive_click_events();
+
+        // Join a thread that died mid-run so Start works again
+        if self.clicker_thread.has_crashed() {
+            self.clicker_thread.stop();
+        }
+        if let Some(error) = self.errors.try_iter().last() {
+            self.last_error = Some(error);
         }
+
         // Top menu bar
         egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
             egui::menu::bar(ui, |ui| {
@@ -151,8 +583,7 @@ impl eframe::App for MouseClickerApp {
 
                     if ui.button("Save Profile").clicked() {
                         let state = self.state.lock().unwrap();
-                        let profile_path = state.profile_manager.get_profile_path(&state.current_config.profile_name);
-                        if let Err(e) = state.current_config.save(profile_path.to_str().unwrap()) {
+                        if let Err(e) = state.profile_manager.save_profile(&state.current_config) {
                             eprintln!("Failed to save profile: {}", e);
                         }
                         ui.close_menu();
@@ -186,7 +617,7 @@ impl eframe::App for MouseClickerApp {
                     ui.label(status_text);
 
                     // Profile name
-                    ui.label(RichText::new(format!("Profile: {}", state.current_config.profile_name)).strong());
+                    ui.label(RichText::new(t!("app.profile_value", name = state.current_config.profile_name)).strong());
 
                     // Dark mode toggle
                     let mut is_dark = state.is_dark_mode;
@@ -221,48 +652,82 @@ impl eframe::App for MouseClickerApp {
                 };
 
                 // Navigation buttons
-                if components::sidebar_button(ui, &theme, "Profiles", "📋", current_view == AppView::Profiles) {
+                let profiles = ui.scope(|ui| components::sidebar_button(ui, &theme, "Profiles", "📋", current_view == AppView::Profiles, Some("Load, save and compare click profiles")));
+                self.onboarding.mark(&[OnboardingStep::CreateProfile], profiles.response.rect);
+                if profiles.inner {
                     let mut state = self.state.lock().unwrap();
                     state.current_view = AppView::Profiles;
                     state.view_transition.reset();
                 }
 
-                if components::sidebar_button(ui, &theme, "Click Areas", "🎯", current_view == AppView::Areas) {
+                let areas = ui.scope(|ui| components::sidebar_button(ui, &theme, "Click Areas", "🎯", current_view == AppView::Areas, Some("Choose where on screen clicks land")));
+                self.onboarding.mark(&[OnboardingStep::DefineArea], areas.response.rect);
+                if areas.inner {
                     let mut state = self.state.lock().unwrap();
                     state.current_view = AppView::Areas;
                     state.view_transition.reset();
                 }
 
-                // Image Recognition feature is temporarily disabled
-                // Uncomment the following code to re-enable it
-                /*
-                if components::sidebar_button(ui, &theme, "Image Recognition", "🔍", current_view == AppView::Images) {
+                if components::sidebar_button(ui, &theme, "Image Recognition", "🔍", current_view == AppView::Images, Some("Click on targets found by matching screen images")) {
                     let mut state = self.state.lock().unwrap();
                     state.current_view = AppView::Images;
                     state.view_transition.reset();
                 }
-                */
 
-                if components::sidebar_button(ui, &theme, "Settings", "⚙", current_view == AppView::Settings) {
+                let settings = ui.scope(|ui| components::sidebar_button(ui, &theme, "Settings", "⚙", current_view == AppView::Settings, Some("Timing, click type and safety options")));
+                self.onboarding.mark(&[OnboardingStep::ChooseClickType, OnboardingStep::SetTiming], settings.response.rect);
+                if settings.inner {
                     let mut state = self.state.lock().unwrap();
                     state.current_view = AppView::Settings;
                     state.view_transition.reset();
                 }
 
-                if components::sidebar_button(ui, &theme, "Statistics", "📊", current_view == AppView::Stats) {
+                if components::sidebar_button(ui, &theme, "Statistics", "📊", current_view == AppView::Stats, Some("Statistics and events for the current session")) {
                     let mut state = self.state.lock().unwrap();
                     state.current_view = AppView::Stats;
                     state.view_transition.reset();
                 }
 
+                if components::sidebar_button(ui, &theme, "Click Log", "📜", current_view == AppView::Logs, Some("Browse recorded per-click session logs")) {
+                    let mut state = self.state.lock().unwrap();
+                    state.current_view = AppView::Logs;
+                    state.view_transition.reset();
+                }
+
                 ui.add_space(16.0);
                 ui.separator();
                 ui.add_space(16.0);
 
-                // Version information
+                let status = self.state.lock().unwrap().clicker_status;
+
+                // Version information, with the session status above it
                 ui.with_layout(egui::Layout::bottom_up(egui::Align::Center), |ui| {
                     ui.label(RichText::new("v1.0.0").color(theme.muted_text).small());
                     ui.label(RichText::new("Mouse Clicker").color(theme.muted_text).small());
+
+                    let indicator = match status {
+                        ClickerStatus::Running => {
+                            if self.running_pulse.is_completed() {
+                                self.running_pulse.reset();
+                            }
+                            ui.ctx().request_repaint_after(Duration::from_millis(16));
+
+                            // Fade down and back up once per cycle
+                            let t = 1.0 - (2.0 * self.running_pulse.progress() - 1.0).abs();
+                            Some((lerp_color(theme.success.linear_multiply(0.3), theme.success, t), "Running"))
+                        },
+                        ClickerStatus::Paused => Some((theme.warning, "Paused")),
+                        ClickerStatus::Stopped => None,
+                    };
+
+                    if let Some((color, label)) = indicator {
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            let (rect, _) = ui.allocate_exact_size(egui::Vec2::splat(10.0), egui::Sense::hover());
+                            ui.painter().circle_filled(rect.center(), 5.0, color);
+                            ui.label(RichText::new(label).color(theme.text).strong());
+                        });
+                    }
                 });
             });
 
@@ -285,6 +750,8 @@ impl eframe::App for MouseClickerApp {
                 AppView::Settings => "Settings",
                 AppView::Stats => "Statistics",
                 AppView::Images => "Image Recognition",
+                AppView::Logs => "Click Log",
+                AppView::Overlay => "Session Overlay",
                 AppView::Running => "Running",
             };
 
@@ -296,24 +763,13 @@ impl eframe::App for MouseClickerApp {
                     AppView::Profiles => self.profile_view.ui(ui),
                     AppView::Areas => self.area_view.ui(ui),
                     AppView::Settings => self.settings_view.ui(ui),
-                    AppView::Stats => self.stats_view.ui(ui),
-                    AppView::Images => {
-                        // Image Recognition feature is temporarily disabled
-                        // Redirect to the Areas view
-                        ui.vertical_centered(|ui| {
-                            ui.add_space(20.0);
-                            ui.heading("Image Recognition is temporarily disabled");
-                            ui.add_space(10.0);
-                            ui.label("This feature has been temporarily disabled.");
-                            ui.add_space(20.0);
-
-                            if ui.button("Go to Click Areas").clicked() {
-                                let mut state = self.state.lock().unwrap();
-                                state.current_view = AppView::Areas;
-                                state.view_transition.reset();
-                            }
-                        });
+                    AppView::Stats => {
+                        self.stats_view.set_performance_stats(self.clicker_thread.get_performance_stats());
+                        self.stats_view.ui(ui)
                     },
+                    AppView::Logs => self.log_view.ui(ui),
+                    AppView::Overlay => self.overlay_view.ui(ui),
+                    AppView::Images => self.image_view.ui(ui),
                     AppView::Running => {
                         // TODO: Implement running view
                         ui.heading("Running");
@@ -334,57 +790,125 @@ impl eframe::App for MouseClickerApp {
                 ui.horizontal(|ui| {
                     let state = self.state.lock().unwrap();
 
-                    if let Some(start_time) = state.start_time {
+                    if state.dry_run {
+                        if self.dry_run_blink.is_completed() {
+                            self.dry_run_blink.reset();
+                        }
+                        let fill = if self.dry_run_blink.progress() < 0.5 { theme.warning } else { theme.warning.gamma_multiply(0.45) };
+
+                        egui::Frame::new()
+                            .fill(fill)
+                            .corner_radius(4.0)
+                            .inner_margin(egui::Margin::symmetric(8, 2))
+                            .show(ui, |ui| {
+                                ui.label(RichText::new("DRY RUN").color(egui::Color32::BLACK).strong());
+                            })
+                            .response
+                            .on_hover_text("The cursor doesn't move and no clicks are sent");
+                        ui.separator();
+                    }
+
+                    if let Some(remaining) = state.startup_countdown {
+                        ui.label(RichText::new(t!("app.starting_in", seconds = remaining)).color(theme.warning).strong());
+                        ui.separator();
+                    } else if let Some(start_time) = state.start_time {
                         let elapsed = start_time.elapsed();
-                        ui.label(RichText::new(format!("Running time: {:.1}s", elapsed.as_secs_f32())).strong());
+                        ui.label(RichText::new(t!("app.running_time_value", time = format_duration(elapsed.as_secs_f32()))).strong());
+                        ui.separator();
+                    }
+
+                    ui.label(RichText::new(t!("app.clicks_value", count = state.click_count.load(Ordering::Relaxed))).strong());
+
+                    ui.separator();
+                    webhook_indicator(ui, &theme, &state);
+
+                    if let Some(error) = &self.last_error {
                         ui.separator();
+                        ui.label(RichText::new(error).color(theme.error).strong());
+                        if ui.small_button("Dismiss").clicked() {
+                            self.last_error = None;
+                        }
                     }
 
-                    ui.label(RichText::new(format!("Clicks: {}", state.click_count)).strong());
+                    if !state.config_history.is_empty() {
+                        ui.separator();
+                        ui.label(RichText::new(t!("app.changes_value", count = state.config_history.len())).color(theme.primary).strong());
+                        if ui.small_button("View Change Log").clicked() {
+                            self.show_change_log = true;
+                        }
+                    }
 
                     // Right-aligned controls
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                         let status = state.clicker_status;
+                        let counting_down = state.startup_countdown.is_some();
                         drop(state); // Drop the lock before UI interactions
 
                         match status {
+                            ClickerStatus::Running if counting_down => {
+                                if components::accent_button(ui, &theme, "Cancel Startup", Some("Stop before the first click")) {
+                                    self.clicker_thread.stop();
+                                    let mut state = self.state.lock().unwrap();
+                                    state.clicker_status = ClickerStatus::Stopped;
+                                    state.start_time = None;
+                                    state.startup_countdown = None;
+                                    state.log_event(EventKind::Stopped, Some("Cancelled during the startup countdown".to_string()));
+                                }
+                            },
                             ClickerStatus::Stopped => {
-                                if components::secondary_button(ui, &theme, "Start") {
+                                let start = ui.scope(|ui| components::secondary_button(ui, &theme, "Start", Some("Start clicking with the current profile")));
+                                self.onboarding.mark(&[OnboardingStep::StartClicking], start.response.rect);
+                                if start.inner {
                                     println!("Start button clicked");
-                                    let state_arc = Arc::clone(&self.state);
-                                    self.clicker_thread.start(state_arc);
+                                    // A profile picked just before Start is what the user expects to run
+                                    self.profile_view.finish_profile_switch(true);
+                                    let all_areas_disabled = {
+                                        let state = self.state.lock().unwrap();
+                                        let multi_area = &state.current_config.multi_area;
+                                        multi_area.enabled && !multi_area.areas.is_empty() && multi_area.active_areas().is_empty()
+                                    };
+                                    if all_areas_disabled {
+                                        self.last_error = Some("Every click area is disabled. Enable at least one to start.".to_string());
+                                    } else {
+                                        let state_arc = Arc::clone(&self.state);
+                                        self.clicker_thread.start(state_arc);
+                                    }
                                 }
                             },
                             ClickerStatus::Running => {
-                                if components::primary_button(ui, &theme, "Pause") {
+                                if components::primary_button(ui, &theme, "Pause", Some("Pause clicking without ending the session")) {
                                     self.clicker_thread.pause();
                                     let mut state = self.state.lock().unwrap();
                                     state.clicker_status = ClickerStatus::Paused;
+                                    state.log_event(EventKind::Paused, None);
                                 }
 
                                 ui.add_space(8.0);
 
-                                if components::accent_button(ui, &theme, "Stop") {
+                                if components::accent_button(ui, &theme, "Stop", Some("End the session")) {
                                     self.clicker_thread.stop();
                                     let mut state = self.state.lock().unwrap();
                                     state.clicker_status = ClickerStatus::Stopped;
                                     state.start_time = None;
+                                    state.log_event(EventKind::Stopped, None);
                                 }
                             },
                             ClickerStatus::Paused => {
-                                if components::primary_button(ui, &theme, "Resume") {
+                                if components::primary_button(ui, &theme, "Resume", Some("Continue the paused session")) {
                                     self.clicker_thread.resume();
                                     let mut state = self.state.lock().unwrap();
                                     state.clicker_status = ClickerStatus::Running;
+                                    state.log_event(EventKind::Resumed, None);
                                 }
 
                                 ui.add_space(8.0);
 
-                                if components::accent_button(ui, &theme, "Stop") {
+                                if components::accent_button(ui, &theme, "Stop", Some("End the session")) {
                                     self.clicker_thread.stop();
                                     let mut state = self.state.lock().unwrap();
                                     state.clicker_status = ClickerStatus::Stopped;
                                     state.start_time = None;
+                                    state.log_event(EventKind::Stopped, None);
                                 }
                             },
                         }
@@ -393,7 +917,53 @@ impl eframe::App for MouseClickerApp {
             });
         });
 
+        if self.show_change_log {
+            self.change_log_window(ctx);
+        }
+
+        self.check_dead_man_switch(ctx);
+
+        self.hud_view.show(ctx);
+
+        self.overlay_view.show(ctx);
+
+        self.onboarding.show(ctx);
+
+        {
+            let mut state = self.state.lock().unwrap();
+            state.publish_config();
+
+            // Only record a change once the user has let go, so a slider drag counts as one edit
+            if !ctx.input(|i| i.pointer.any_down()) {
+                state.commit_config_changes();
+            }
+        }
+
         // Request a repaint for animations
         ctx.request_repaint();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loading_a_profile_keeps_dry_run_on() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut state = AppState::new(dir.path().to_path_buf(), AppPreferences::default());
+        state.dry_run = true;
+        state.profile_manager.save_profile(&Config::new("Loaded")).unwrap();
+
+        state.current_config = state.profile_manager.load_profile("Loaded").unwrap();
+        state.publish_config();
+        assert!(state.live_config.load().dry_run);
+
+        // Undoing back to a snapshot from before the profile was loaded doesn't turn it off either
+        state.commit_config_changes();
+        assert!(state.undo_config());
+        state.publish_config();
+        assert_eq!(state.live_config.load().profile_name, Config::default().profile_name);
+        assert!(state.live_config.load().dry_run);
+    }
+}
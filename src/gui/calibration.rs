@@ -0,0 +1,263 @@
+use eframe::egui::{self, Context, RichText, Vec2};
+use enigo::Enigo;
+use rand::thread_rng;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::gui::app::AppState;
+use crate::gui::components::{self, StatusMessageType};
+use crate::modules::config::{ClickModifiers, ClickType, Config};
+use crate::modules::mouse::{human_like_click, simulate_human_movement};
+
+/// Number of test clicks performed during a calibration run
+const CALIBRATION_TRIALS: usize = 10;
+
+/// How long a trial waits for its click to show up before giving up
+const TRIAL_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Results are rejected if the spread between the fastest and slowest click exceeds this multiple of the median
+const MAX_SPREAD_RATIO: f64 = 1.5;
+
+/// Results are rejected if fewer than this many trials registered a click
+const MIN_VALID_TRIALS: usize = CALIBRATION_TRIALS / 2;
+
+/// Timing shared between the calibration thread and the UI that observes the clicks
+#[derive(Default)]
+struct CalibrationProgress {
+    /// When each trial decided to click
+    decided_at: Vec<Instant>,
+    /// When the UI saw each trial's click arrive, if it did
+    arrived_at: Vec<Option<Instant>>,
+    finished: bool,
+}
+
+/// Summary of a finished calibration run
+#[derive(Debug, Clone, Copy)]
+struct CalibrationResult {
+    min_ms: f64,
+    median_ms: f64,
+    max_ms: f64,
+    valid_trials: usize,
+}
+
+impl CalibrationResult {
+    fn from_latencies(mut latencies: Vec<f64>) -> Option<Self> {
+        if latencies.is_empty() {
+            return None;
+        }
+
+        latencies.sort_by(|a, b| a.total_cmp(b));
+        Some(Self {
+            min_ms: latencies[0],
+            median_ms: latencies[latencies.len() / 2],
+            max_ms: latencies[latencies.len() - 1],
+            valid_trials: latencies.len(),
+        })
+    }
+
+    /// Why these measurements can't be trusted, if they can't
+    fn rejection_reason(&self) -> Option<String> {
+        if self.valid_trials < MIN_VALID_TRIALS {
+            return Some(format!(
+                "Only {} of {} test clicks were detected",
+                self.valid_trials, CALIBRATION_TRIALS
+            ));
+        }
+
+        let spread = self.max_ms - self.min_ms;
+        if spread > self.median_ms * MAX_SPREAD_RATIO {
+            return Some(format!(
+                "Measurements varied too much ({:.0} ms spread around a {:.0} ms median)",
+                spread, self.median_ms
+            ));
+        }
+
+        None
+    }
+}
+
+/// Wizard that measures how long a click takes to land and stores it as a delay compensation
+pub struct CalibrationWizard {
+    state: Arc<Mutex<AppState>>,
+    pub open: bool,
+    progress: Option<Arc<Mutex<CalibrationProgress>>>,
+    result: Option<CalibrationResult>,
+    status: Option<(String, StatusMessageType)>,
+}
+
+impl CalibrationWizard {
+    pub fn new(state: Arc<Mutex<AppState>>) -> Self {
+        Self {
+            state,
+            open: false,
+            progress: None,
+            result: None,
+            status: None,
+        }
+    }
+
+    fn is_running(&self) -> bool {
+        self.progress
+            .as_ref()
+            .is_some_and(|progress| !progress.lock().unwrap().finished)
+    }
+
+    /// Start clicking the target at the given physical screen position
+    fn start(&mut self, target: (i32, i32)) {
+        let progress = Arc::new(Mutex::new(CalibrationProgress::default()));
+        self.progress = Some(Arc::clone(&progress));
+        self.result = None;
+        self.status = None;
+
+        // Test clicks are plain left clicks so the target always registers them
+        let mut config: Config = self.state.lock().unwrap().current_config.clone();
+        config.click_options.randomize_click_type = false;
+        config.click_options.click_type = ClickType::Single;
+        config.click_options.modifiers = ClickModifiers::default();
+        // Latency can only be measured from clicks that are really sent
+        config.dry_run = false;
+
+        thread::spawn(move || {
+            let mut enigo = Enigo::new();
+            let mut rng = thread_rng();
+
+            for trial in 0..CALIBRATION_TRIALS {
+                // Start each trial from a different spot so movement time is part of the measurement
+                let offset = if trial % 2 == 0 { 150 } else { -150 };
+                let _ = simulate_human_movement(&mut enigo, target.0 + offset, target.1 + offset / 2, &config.mouse_movement, false, &mut rng);
+                thread::sleep(Duration::from_millis(300));
+
+                {
+                    let mut progress = progress.lock().unwrap();
+                    progress.decided_at.push(Instant::now());
+                    progress.arrived_at.push(None);
+                }
+
+                let _ = simulate_human_movement(&mut enigo, target.0, target.1, &config.mouse_movement, false, &mut rng);
+                if let Err(e) = human_like_click(&mut enigo, &mut rng, &config) {
+                    eprintln!("Calibration click failed: {}", e);
+                }
+
+                // Wait for the UI to see the click before moving on
+                let wait_start = Instant::now();
+                while wait_start.elapsed() < TRIAL_TIMEOUT {
+                    if progress.lock().unwrap().arrived_at[trial].is_some() {
+                        break;
+                    }
+                    thread::sleep(Duration::from_millis(10));
+                }
+            }
+
+            progress.lock().unwrap().finished = true;
+        });
+    }
+
+    /// Turn the recorded timings into a result once the run is over
+    fn collect_result(&mut self) {
+        let Some(progress) = &self.progress else { return };
+        let progress = progress.lock().unwrap();
+        if !progress.finished || self.result.is_some() {
+            return;
+        }
+
+        let latencies: Vec<f64> = progress.decided_at
+            .iter()
+            .zip(&progress.arrived_at)
+            .filter_map(|(decided, arrived)| arrived.map(|arrived| arrived.duration_since(*decided).as_secs_f64() * 1000.0))
+            .collect();
+
+        self.result = CalibrationResult::from_latencies(latencies);
+        if self.result.is_none() {
+            self.status = Some(("No test clicks were detected. Keep the wizard window visible and try again.".to_string(), StatusMessageType::Error));
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        if !self.open {
+            return;
+        }
+
+        self.collect_result();
+
+        let theme = self.state.lock().unwrap().theme.clone();
+        let running = self.is_running();
+        let mut open = self.open;
+
+        egui::Window::new("Click Latency Calibration")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .default_width(360.0)
+            .show(ctx, |ui| {
+                ui.label("The mouse will move to the target below and click it several times. \
+                          Don't touch the mouse until the run is finished.");
+                ui.add_space(8.0);
+
+                let target = ui.vertical_centered(|ui| {
+                    ui.add_sized(Vec2::new(140.0, 60.0), egui::Button::new(RichText::new("Target").strong()))
+                }).inner;
+
+                // The press event reaching egui is the ground truth for when the click arrived
+                if running && ctx.input(|i| i.pointer.primary_pressed()) && target.contains_pointer() {
+                    if let Some(progress) = &self.progress {
+                        let mut progress = progress.lock().unwrap();
+                        if let Some(slot) = progress.arrived_at.last_mut() {
+                            slot.get_or_insert_with(Instant::now);
+                        }
+                    }
+                }
+
+                ui.add_space(8.0);
+
+                if running {
+                    let done = self.progress.as_ref().map_or(0, |p| p.lock().unwrap().decided_at.len());
+                    ui.label(format!("Measuring... trial {} of {}", done, CALIBRATION_TRIALS));
+                } else if ui.button("Start Calibration").clicked() {
+                    // Convert the target's center from window points to physical screen pixels
+                    let pixels_per_point = ctx.pixels_per_point();
+                    let window_origin = ctx.input(|i| i.viewport().inner_rect.map(|r| r.min)).unwrap_or_default();
+                    let center = (window_origin + target.rect.center().to_vec2()) * pixels_per_point;
+                    self.start((center.x as i32, center.y as i32));
+                }
+
+                if let Some(result) = self.result {
+                    ui.add_space(8.0);
+                    egui::Grid::new("calibration_results").num_columns(2).show(ui, |ui| {
+                        ui.label("Fastest:");
+                        ui.label(format!("{:.0} ms", result.min_ms));
+                        ui.end_row();
+                        ui.label("Median:");
+                        ui.label(format!("{:.0} ms", result.median_ms));
+                        ui.end_row();
+                        ui.label("Slowest:");
+                        ui.label(format!("{:.0} ms", result.max_ms));
+                        ui.end_row();
+                        ui.label("Detected:");
+                        ui.label(format!("{} of {}", result.valid_trials, CALIBRATION_TRIALS));
+                        ui.end_row();
+                    });
+
+                    ui.add_space(8.0);
+                    match result.rejection_reason() {
+                        Some(reason) => {
+                            components::status_message(ui, &theme, &format!("{}. Not saving.", reason), StatusMessageType::Warning);
+                        },
+                        None => {
+                            if components::primary_button(ui, &theme, &format!("Apply {:.0} ms Compensation", result.median_ms), Some("Subtract this delay from every sleep between clicks")) {
+                                let mut state = self.state.lock().unwrap();
+                                state.current_config.click_timing.latency_compensation_ms = result.median_ms;
+                                self.status = Some(("Compensation saved to the current profile settings".to_string(), StatusMessageType::Success));
+                            }
+                        },
+                    }
+                }
+
+                if let Some((message, message_type)) = &self.status {
+                    components::status_message(ui, &theme, message, *message_type);
+                }
+            });
+
+        self.open = open;
+    }
+}
@@ -1,16 +1,195 @@
 use enigo::Enigo;
 use rand::thread_rng;
+use std::any::Any;
+use std::collections::VecDeque;
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::{Arc, Mutex, atomic::{AtomicBool, Ordering}};
 use std::thread::{self, JoinHandle};
 use std::time::{Duration, Instant};
 
-use crate::modules::mouse::{get_next_click_area, generate_random_coordinates, simulate_human_movement, human_like_click, handle_sleep_period};
+use crate::modules::mouse::{get_next_click_area, generate_random_coordinates, simulate_human_movement, human_like_click, handle_sleep_period, random_sleep_duration, reaction_delay};
 use crate::gui::app::{AppState, ClickerStatus};
+use crate::modules::config::{ColorCondition, PRIMARY_AREA_NAME};
+use crate::modules::event_log::{ClickEvent, EventKind, EventLogger};
+use crate::modules::error::Result;
+use crate::modules::image_recognition::{average_region_color, capture_screen, ImageLibrary};
+use crate::modules::paths;
+use crate::modules::timing;
+
+/// Minimum time between ClickPerformed entries in the session event log
+const CLICK_EVENT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Length of the window used to enforce the clicks-per-minute cap
+const RATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// How often the start condition's region is sampled while waiting for it
+const START_CONDITION_POLL: Duration = Duration::from_millis(500);
+
+/// Wait before taking another screenshot when no image target was found
+const IMAGE_SEARCH_RETRY: Duration = Duration::from_millis(500);
+
+/// Rolling record of recent click times used to enforce `max_cpm`
+struct RateLimiter {
+    clicks: VecDeque<Instant>,
+}
+
+impl RateLimiter {
+    fn new() -> Self {
+        Self { clicks: VecDeque::new() }
+    }
+
+    /// How long to wait before the next click so the rolling count stays within `max_cpm`
+    fn required_wait(&mut self, max_cpm: f32, now: Instant) -> Option<Duration> {
+        while self.clicks.front().is_some_and(|&t| now.duration_since(t) >= RATE_WINDOW) {
+            self.clicks.pop_front();
+        }
+
+        if (self.clicks.len() + 1) as f32 > max_cpm {
+            let oldest = *self.clicks.front()?;
+            Some(RATE_WINDOW.saturating_sub(now.duration_since(oldest)))
+        } else {
+            None
+        }
+    }
+
+    fn record(&mut self, max_cpm: f32, now: Instant) {
+        self.clicks.push_back(now);
+
+        let capacity = max_cpm.ceil() as usize + 10;
+        while self.clicks.len() > capacity {
+            self.clicks.pop_front();
+        }
+    }
+}
+
+/// Measured click rate and phase timings over the last minute, next to what the config asks for
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ClickerPerfStats {
+    /// `60 / ((min_delay + max_delay) / 2)`, ignoring movement and click time
+    pub configured_expected_cpm: f32,
+    pub actual_cpm_last_60s: f32,
+    /// Average time slept beyond the requested delay, from OS scheduling
+    pub sleep_overhead_ms: f64,
+    pub movement_time_ms: f64,
+    pub click_time_ms: f64,
+}
+
+/// How long the phases of one click took
+struct CycleTiming {
+    at: Instant,
+    movement: Duration,
+    click: Duration,
+    /// Unset until the delay after the click finishes, and left unset if it was cut short
+    sleep_overhead: Option<Duration>,
+}
+
+/// Phase timings of the clicks in the last `RATE_WINDOW`
+#[derive(Default)]
+struct PerfRecorder {
+    cycles: VecDeque<CycleTiming>,
+    expected_cpm: f32,
+    started: Option<Instant>,
+}
+
+impl PerfRecorder {
+    fn record_click(&mut self, movement: Duration, click: Duration, expected_cpm: f32) {
+        let now = Instant::now();
+        while self.cycles.front().is_some_and(|cycle| now.duration_since(cycle.at) >= RATE_WINDOW) {
+            self.cycles.pop_front();
+        }
+
+        self.cycles.push_back(CycleTiming { at: now, movement, click, sleep_overhead: None });
+        self.expected_cpm = expected_cpm;
+    }
+
+    fn record_sleep(&mut self, requested: Duration, slept: Duration) {
+        if let Some(cycle) = self.cycles.back_mut() {
+            cycle.sleep_overhead = Some(slept.saturating_sub(requested));
+        }
+    }
+
+    fn stats(&self) -> ClickerPerfStats {
+        let now = Instant::now();
+        let recent: Vec<&CycleTiming> = self.cycles.iter().filter(|cycle| now.duration_since(cycle.at) < RATE_WINDOW).collect();
+        let average_ms = |durations: Vec<Duration>| {
+            if durations.is_empty() {
+                0.0
+            } else {
+                durations.iter().map(|d| d.as_secs_f64() * 1000.0).sum::<f64>() / durations.len() as f64
+            }
+        };
+
+        // Early in a run the window isn't full yet, so scale by how long it's actually covered
+        let window = self.started.map_or(RATE_WINDOW, |started| now.duration_since(started).min(RATE_WINDOW));
+        let actual_cpm = if window.is_zero() { 0.0 } else { recent.len() as f32 * 60.0 / window.as_secs_f32() };
+
+        ClickerPerfStats {
+            configured_expected_cpm: self.expected_cpm,
+            actual_cpm_last_60s: actual_cpm,
+            sleep_overhead_ms: average_ms(recent.iter().filter_map(|cycle| cycle.sleep_overhead).collect()),
+            movement_time_ms: average_ms(recent.iter().map(|cycle| cycle.movement).collect()),
+            click_time_ms: average_ms(recent.iter().map(|cycle| cycle.click).collect()),
+        }
+    }
+}
+
+/// Sample the condition's region every 500 ms until its average color matches.
+/// Returns false if the run was stopped first.
+fn wait_for_start_condition(condition: &ColorCondition, should_stop: &AtomicBool) -> bool {
+    while !should_stop.load(Ordering::SeqCst) {
+        match average_region_color(condition.x, condition.y, condition.width, condition.height) {
+            Ok(color) if condition.matches(color) => return true,
+            Ok(_) => {},
+            Err(e) => eprintln!("Failed to sample start condition region: {}", e),
+        }
+        thread::sleep(START_CONDITION_POLL);
+    }
+
+    false
+}
+
+/// Look for the configured targets in one screenshot, returning the click point and the name of the
+/// target found. Threshold changes made by the search go to the session log.
+fn find_image_target(library: &mut ImageLibrary, target_ids: &[String], app_state: &Mutex<AppState>) -> Result<Option<(i32, i32, String)>> {
+    let screen = capture_screen()?;
+    let ids: Vec<&str> = target_ids.iter().map(String::as_str).collect();
+    let found = library.find_any_on_screen(&screen, &ids);
+
+    let adjustments = library.take_threshold_adjustments();
+    if !adjustments.is_empty() {
+        if let Ok(mut state) = app_state.lock() {
+            for adjustment in adjustments {
+                state.log_event(EventKind::ThresholdAdjusted, Some(adjustment));
+            }
+        }
+    }
+
+    Ok(found?.map(|(id, x, y)| {
+        let name = library.get_targets().iter()
+            .find(|target| target.id == id)
+            .map_or(id, |target| target.name.clone());
+        (x, y, name)
+    }))
+}
+
+/// Text of a panic payload, which is a `&str` or `String` for every `panic!` with a message
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
 
 pub struct ClickerThread {
     thread_handle: Option<JoinHandle<()>>,
     is_paused: Arc<AtomicBool>,
     should_stop: Arc<AtomicBool>,
+    /// Set by the thread when it exits because of a panic rather than a stop request
+    crashed: Arc<AtomicBool>,
+    perf: Arc<Mutex<PerfRecorder>>,
 }
 
 impl ClickerThread {
@@ -19,6 +198,8 @@ impl ClickerThread {
             thread_handle: None,
             is_paused: Arc::new(AtomicBool::new(false)),
             should_stop: Arc::new(AtomicBool::new(false)),
+            crashed: Arc::new(AtomicBool::new(false)),
+            perf: Arc::new(Mutex::new(PerfRecorder::default())),
         }
     }
 
@@ -34,84 +215,323 @@ impl ClickerThread {
         // Reset the flags
         self.is_paused.store(false, Ordering::SeqCst);
         self.should_stop.store(false, Ordering::SeqCst);
+        self.crashed.store(false, Ordering::SeqCst);
 
         // Clone the Arc pointers for the thread
         let is_paused = Arc::clone(&self.is_paused);
         let should_stop = Arc::clone(&self.should_stop);
+        let crashed = Arc::clone(&self.crashed);
+        let perf = Arc::clone(&self.perf);
+        if let Ok(mut recorder) = perf.lock() {
+            *recorder = PerfRecorder { started: Some(Instant::now()), ..Default::default() };
+        }
         let app_state_clone = Arc::clone(&app_state);
 
+        // The thread must start from the config as it is now, not as of the last frame
+        if let Ok(state) = app_state.lock() {
+            state.publish_config();
+        }
+
         // Start the clicker thread
         self.thread_handle = Some(thread::spawn(move || {
             println!("Clicker thread started");
             let mut enigo = Enigo::new();
             let mut rng = thread_rng();
             let mut current_area_index = 0;
+            let mut rate_limiter = RateLimiter::new();
+            let mut last_click_event: Option<Instant> = None;
+            let mut burst_clicks = 0;
+
+            // The per-click path reads the config and reports progress without taking the state lock
+            let (live_config, click_count, click_events, next_click_at, last_click_positions, log_enabled, priority) = match app_state_clone.lock() {
+                Ok(state) => (
+                    Arc::clone(&state.live_config),
+                    Arc::clone(&state.click_count),
+                    Arc::clone(&state.click_events),
+                    Arc::clone(&state.next_click_at),
+                    Arc::clone(&state.last_click_positions),
+                    state.preferences.click_event_log,
+                    state.preferences.thread_priority,
+                ),
+                Err(e) => {
+                    eprintln!("Failed to lock app state: {}", e);
+                    return;
+                }
+            };
+
+            if let Some(warning) = priority.warning() {
+                eprintln!("Warning: {}", warning);
+            }
+            if let Err(e) = timing::set_current_thread_priority(priority) {
+                eprintln!("{}", e);
+            }
+
+            // The config is locked while running, so image targets are only loaded once
+            let mut image_library = live_config.load().image_click.enabled.then(|| {
+                let mut library = ImageLibrary::new(paths::targets_dir());
+                if let Err(e) = library.load_targets() {
+                    eprintln!("Failed to load image targets: {}", e);
+                }
+                library
+            });
+
+            // The logger writes from its own thread and flushes when dropped at the end of the run
+            let _event_logger = if log_enabled {
+                EventLogger::start(paths::logs_dir(), click_events.subscribe())
+                    .map_err(|e| eprintln!("Failed to start click event log: {}", e))
+                    .ok()
+            } else {
+                None
+            };
+
+            // Give the user time to switch to the target window
+            let startup_delay = live_config.load().startup_delay_secs;
+            for remaining in (1..=startup_delay).rev() {
+                if should_stop.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(mut state) = app_state_clone.lock() {
+                    state.startup_countdown = Some(remaining);
+                }
+                thread::sleep(Duration::from_secs(1));
+            }
+            if let Ok(mut state) = app_state_clone.lock() {
+                state.startup_countdown = None;
+            }
+
+            // Hold the first cycle until the start condition's region shows its color
+            let start_condition = live_config.load().start_condition.clone();
+            if let Some(condition) = start_condition {
+                if wait_for_start_condition(&condition, &should_stop) {
+                    if let Ok(mut state) = app_state_clone.lock() {
+                        state.log_event(EventKind::StartConditionMet, Some("Start condition met".to_string()));
+                    }
+                }
+            }
 
             // Main clicking loop
             while !should_stop.load(Ordering::SeqCst) {
-                if !is_paused.load(Ordering::SeqCst) {
-                    println!("Performing click operation");
-                    // Get the current config
-                    let config = match app_state_clone.lock() {
-                        Ok(state) => state.current_config.clone(),
-                        Err(e) => {
-                            eprintln!("Failed to lock app state: {}", e);
-                            continue;
+                // A panic anywhere in a cycle stops the run cleanly instead of silently killing the thread
+                let cycle = panic::catch_unwind(AssertUnwindSafe(|| {
+                    if !is_paused.load(Ordering::SeqCst) {
+                        println!("Performing click operation");
+                        // Get the config most recently published by the GUI thread
+                        let config = live_config.load();
+
+                        // Image targets replace the click areas; a cycle without a match clicks nothing
+                        let image_target = match image_library.as_mut().filter(|_| config.image_click.enabled) {
+                            Some(library) => match find_image_target(library, &config.image_click.targets, &app_state_clone) {
+                                Ok(Some(found)) => Some(found),
+                                Ok(None) => {
+                                    let _ = handle_sleep_period(&mut enigo, &mut rng, &is_paused, &should_stop, IMAGE_SEARCH_RETRY, &config.anti_detection, config.dry_run);
+                                    return;
+                                },
+                                Err(e) => {
+                                    eprintln!("Warning: Image search failed: {}", e);
+                                    if let Ok(mut state) = app_state_clone.lock() {
+                                        state.log_event(EventKind::Error, Some(format!("Image search failed: {}", e)));
+                                    }
+                                    let _ = handle_sleep_period(&mut enigo, &mut rng, &is_paused, &should_stop, IMAGE_SEARCH_RETRY, &config.anti_detection, config.dry_run);
+                                    return;
+                                }
+                            },
+                            None => None,
+                        };
+
+                        // The area name is kept so areas anchored to it can follow its clicks
+                        let (x, y, area_name, area_index, source) = if let Some((x, y, target_name)) = image_target {
+                            (x, y, None, None, target_name)
+                        } else {
+                            // Get the next click area
+                            let (area, (area_start_x, area_start_y), area_index) = if config.multi_area.enabled {
+                                println!("Using multi-area mode");
+                                get_next_click_area(&config, &mut current_area_index, &mut rng)
+                            } else {
+                                println!("Using single area mode");
+                                // Calculate the centered area if needed
+                                let (start_x, start_y) = crate::modules::mouse::calculate_click_area(&enigo, &config.click_area);
+                                (config.click_area.clone(), (start_x, start_y), None)
+                            };
+
+                            println!("Click area: {}x{} at ({}, {})", area.width, area.height, area_start_x, area_start_y);
+
+                            // An anchored area follows its anchor's last click. Until the anchor has one it stays where its offsets put it.
+                            let anchor = area.anchor_area_name.as_ref()
+                                .and_then(|name| last_click_positions.lock().ok()?.get(name).copied());
+                            let (start_x, start_y) = if anchor.is_some() {
+                                (area.x_offset, area.y_offset)
+                            } else {
+                                (area_start_x, area_start_y)
+                            };
+
+                            // Generate random coordinates within the clicking area
+                            let (x, y) = generate_random_coordinates(
+                                start_x,
+                                start_y,
+                                area.width,
+                                area.height,
+                                anchor,
+                                &mut rng
+                            );
+
+                            let source = if !config.multi_area.enabled {
+                                PRIMARY_AREA_NAME.to_string()
+                            } else if area.name.is_empty() {
+                                "Unnamed area".to_string()
+                            } else {
+                                area.name.clone()
+                            };
+
+                            (x, y, Some(area.name).filter(|name| !name.is_empty()), area_index, source)
+                        };
+
+                        println!("Clicking at position: ({}, {})", x, y);
+
+                        // Simulate human-like mouse movement
+                        let movement_start = Instant::now();
+                        if let Err(e) = simulate_human_movement(&mut enigo, x, y, &config.mouse_movement, config.dry_run, &mut rng) {
+                            eprintln!("Warning: Mouse movement failed: {}", e);
+                            if let Ok(mut state) = app_state_clone.lock() {
+                                state.log_event(EventKind::Error, Some(format!("Mouse movement failed: {}", e)));
+                            }
+                            return;
                         }
-                    };
 
-                    // Get the next click area
-                    let (area, (area_start_x, area_start_y)) = if config.multi_area.enabled {
-                        println!("Using multi-area mode");
-                        get_next_click_area(&config, &mut current_area_index, &mut rng)
-                    } else {
-                        println!("Using single area mode");
-                        // Calculate the centered area if needed
-                        let (start_x, start_y) = crate::modules::mouse::calculate_click_area(&enigo, &config.click_area);
-                        (config.click_area.clone(), (start_x, start_y))
-                    };
-
-                    println!("Click area: {}x{} at ({}, {})", area.width, area.height, area_start_x, area_start_y);
-
-                    // Generate random coordinates within the clicking area
-                    let (x, y) = generate_random_coordinates(
-                        area_start_x,
-                        area_start_y,
-                        area.width,
-                        area.height,
-                        &mut rng
-                    );
-
-                    println!("Clicking at position: ({}, {})", x, y);
-
-                    // Simulate human-like mouse movement
-                    if let Err(e) = simulate_human_movement(&mut enigo, x, y, &mut rng) {
-                        eprintln!("Warning: Mouse movement failed: {}", e);
-                        continue;
-                    }
+                        let movement_time = movement_start.elapsed();
 
-                    // Perform the click with human-like duration
-                    if let Err(e) = human_like_click(&mut enigo, &mut rng, &config) {
-                        eprintln!("Warning: Click action failed: {}", e);
-                        continue;
-                    }
+                        // Hold the click back if it would break the clicks-per-minute cap
+                        if let Some(max_cpm) = config.click_timing.max_cpm {
+                            if let Some(wait) = rate_limiter.required_wait(max_cpm, Instant::now()) {
+                                println!("Rate limited, waiting {:.1}s", wait.as_secs_f32());
+                                if let Ok(mut state) = app_state_clone.lock() {
+                                    state.rate_limited_count += 1;
+                                }
 
-                    // Update the click count
-                    if let Ok(mut state) = app_state_clone.lock() {
-                        state.click_count += 1;
-                        println!("Click count: {}", state.click_count);
+                                let _ = handle_sleep_period(&mut enigo, &mut rng, &is_paused, &should_stop, wait, &config.anti_detection, config.dry_run);
+                                if should_stop.load(Ordering::SeqCst) || is_paused.load(Ordering::SeqCst) {
+                                    return;
+                                }
+                            }
+                            rate_limiter.record(max_cpm, Instant::now());
+                        }
+
+                        // Pause like a person would between reaching the target and pressing the button
+                        let reaction = reaction_delay(&mut rng, &config);
+                        if let Some(delay) = reaction {
+                            thread::sleep(delay);
+                        }
+
+                        // Perform the click with human-like duration
+                        let click_start = Instant::now();
+                        let click_type = match human_like_click(&mut enigo, &mut rng, &config) {
+                            Ok(Some(click_type)) => click_type,
+                            Ok(None) => {
+                                // Wait for the button to come back up rather than retrying straight away
+                                if let Ok(mut state) = app_state_clone.lock() {
+                                    state.skipped_double_presses += 1;
+                                }
+                                timing::poll_sleep(100, &config.anti_detection);
+                                return;
+                            },
+                            Err(e) => {
+                                eprintln!("Warning: Click action failed: {}", e);
+                                if let Ok(mut state) = app_state_clone.lock() {
+                                    state.log_event(EventKind::Error, Some(format!("Click action failed: {}", e)));
+                                }
+                                return;
+                            }
+                        };
+
+                        let click_time = click_start.elapsed();
+                        let mean_delay = (config.click_timing.min_delay + config.click_timing.max_delay) / 2.0;
+                        let expected_cpm = if mean_delay > 0.0 { 60.0 / mean_delay } else { 0.0 };
+                        if let Ok(mut recorder) = perf.lock() {
+                            recorder.record_click(movement_time, click_time, expected_cpm);
+                        }
+
+                        if let Some(area_name) = area_name {
+                            if let Ok(mut positions) = last_click_positions.lock() {
+                                positions.insert(area_name, (x, y));
+                            }
+                        }
+
+                        let count = click_count.fetch_add(1, Ordering::Relaxed) + 1;
+                        println!("Click count: {}", count);
+
+                        click_events.send(ClickEvent {
+                            timestamp: chrono::Local::now(),
+                            x,
+                            y,
+                            click_type,
+                            source,
+                            verified: None,
+                            reaction_delay_ms: reaction.map(|delay| delay.as_millis() as u64),
+                            click_area_index: area_index,
+                        });
+
+                        // Throttled so a fast run doesn't flood the log or contend for the lock
+                        if last_click_event.is_none_or(|at| at.elapsed() >= CLICK_EVENT_INTERVAL) {
+                            if let Ok(mut state) = app_state_clone.lock() {
+                                state.log_event(EventKind::ClickPerformed, Some(format!("{:?} at ({}, {})", click_type, x, y)));
+                            }
+                            last_click_event = Some(Instant::now());
+                        }
+
+                        // Inside a burst only the short delay separates clicks
+                        let burst = &config.burst;
+                        if burst.enabled {
+                            burst_clicks += 1;
+                            if burst_clicks < burst.clicks_per_burst {
+                                let delay = Duration::from_millis(burst.intra_burst_delay_ms);
+                                let _ = handle_sleep_period(&mut enigo, &mut rng, &is_paused, &should_stop, delay, &config.anti_detection, config.dry_run);
+                                return;
+                            }
+
+                            burst_clicks = 0;
+                            if let Ok(mut state) = app_state_clone.lock() {
+                                state.bursts_completed += 1;
+                            }
+                        }
+
+                        // Handle sleep period
+                        println!("Sleeping before next click");
+                        let sleep_duration = random_sleep_duration(&mut rng, &config);
+                        next_click_at.store(Some(Instant::now() + sleep_duration));
+
+                        let sleep_start = Instant::now();
+                        if let Err(e) = handle_sleep_period(&mut enigo, &mut rng, &is_paused, &should_stop, sleep_duration, &config.anti_detection, config.dry_run) {
+                            eprintln!("Warning: Sleep period failed: {}", e);
+                        }
+
+                        // A sleep cut short by pause or stop says nothing about scheduling overhead
+                        if !should_stop.load(Ordering::SeqCst) && !is_paused.load(Ordering::SeqCst) {
+                            if let Ok(mut recorder) = perf.lock() {
+                                recorder.record_sleep(sleep_duration, sleep_start.elapsed());
+                            }
+                        }
+
+                        next_click_at.store(None);
                     } else {
-                        eprintln!("Failed to lock app state to update click count");
+                        println!("Clicker is paused");
+                        timing::poll_sleep(100, &live_config.load().anti_detection);
                     }
+                }));
 
-                    // Handle sleep period
-                    println!("Sleeping before next click");
-                    if let Err(e) = handle_sleep_period(&mut enigo, &mut rng, &is_paused, &should_stop, &config) {
-                        eprintln!("Warning: Sleep period failed: {}", e);
-                    }
-                } else {
-                    println!("Clicker is paused");
-                    thread::sleep(Duration::from_millis(100));
+                if let Err(payload) = cycle {
+                    let message = format!("Clicker stopped after an internal error: {}", panic_message(payload.as_ref()));
+                    eprintln!("{}", message);
+                    crashed.store(true, Ordering::SeqCst);
+
+                    // The panic may have happened while the state was locked
+                    let mut state = app_state_clone.lock().unwrap_or_else(|poisoned| {
+                        app_state_clone.clear_poison();
+                        poisoned.into_inner()
+                    });
+                    state.clicker_status = ClickerStatus::Stopped;
+                    state.start_time = None;
+                    state.error_queue.send(message.clone());
+                    state.log_event(EventKind::Error, Some(message));
+                    break;
                 }
             }
             println!("Clicker thread stopped");
@@ -121,6 +541,11 @@ impl ClickerThread {
         let mut state = app_state.lock().unwrap();
         state.clicker_status = ClickerStatus::Running;
         state.start_time = Some(Instant::now());
+        state.rate_limited_count = 0;
+        state.skipped_double_presses = 0;
+        state.bursts_completed = 0;
+        let detail = format!("Profile '{}'", state.current_config.profile_name);
+        state.log_event(EventKind::Started, Some(detail));
         println!("Clicker status set to Running");
     }
 
@@ -151,6 +576,22 @@ impl ClickerThread {
     pub fn is_paused(&self) -> bool {
         self.is_paused.load(Ordering::SeqCst)
     }
+
+    /// Timings of the current or last run
+    pub fn get_performance_stats(&self) -> ClickerPerfStats {
+        self.perf.lock().map(|recorder| recorder.stats()).unwrap_or_default()
+    }
+
+    /// Whether the thread has died from a panic and still needs to be joined
+    pub fn has_crashed(&self) -> bool {
+        self.thread_handle.is_some() && self.crashed.load(Ordering::SeqCst)
+    }
+}
+
+impl Default for ClickerThread {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Drop for ClickerThread {
@@ -1,6 +1,10 @@
 use eframe::egui::{self, Color32, RichText, Ui, Vec2, Stroke};
 use eframe::epaint::CornerRadius;
+use std::collections::HashMap;
 use crate::gui::theme::AppTheme;
+use crate::gui::animations::Animation;
+use crate::modules::error::AppError;
+use crate::t;
 
 /// Create a section header with consistent styling
 pub fn section_header(ui: &mut Ui, theme: &AppTheme, text: &str) {
@@ -41,22 +45,28 @@ pub fn card<R>(
 }
 
 /// Create a primary button with text
-pub fn primary_button(ui: &mut Ui, theme: &AppTheme, text: &str) -> bool {
-    theme.primary_button(ui, text)
+pub fn primary_button(ui: &mut Ui, theme: &AppTheme, text: &str, tooltip: Option<&str>) -> bool {
+    let response = ui.add(theme.primary_button_widget(text));
+    AnimatedTooltip::attach(ui, theme, &response, tooltip);
+    response.clicked()
 }
 
 /// Create a secondary button with text
-pub fn secondary_button(ui: &mut Ui, theme: &AppTheme, text: &str) -> bool {
-    theme.secondary_button(ui, text)
+pub fn secondary_button(ui: &mut Ui, theme: &AppTheme, text: &str, tooltip: Option<&str>) -> bool {
+    let response = ui.add(theme.secondary_button_widget(text));
+    AnimatedTooltip::attach(ui, theme, &response, tooltip);
+    response.clicked()
 }
 
 /// Create an accent button with text
-pub fn accent_button(ui: &mut Ui, theme: &AppTheme, text: &str) -> bool {
-    theme.accent_button(ui, text)
+pub fn accent_button(ui: &mut Ui, theme: &AppTheme, text: &str, tooltip: Option<&str>) -> bool {
+    let response = ui.add(theme.accent_button_widget(text));
+    AnimatedTooltip::attach(ui, theme, &response, tooltip);
+    response.clicked()
 }
 
 /// Create a sidebar button with icon and text
-pub fn sidebar_button(ui: &mut Ui, theme: &AppTheme, text: &str, icon: &str, selected: bool) -> bool {
+pub fn sidebar_button(ui: &mut Ui, theme: &AppTheme, text: &str, icon: &str, selected: bool, tooltip: Option<&str>) -> bool {
     let fill_color = if selected { theme.selected } else { theme.panel_background };
     let text_color = if selected { theme.primary } else { theme.text };
 
@@ -66,7 +76,70 @@ pub fn sidebar_button(ui: &mut Ui, theme: &AppTheme, text: &str, icon: &str, sel
     .fill(fill_color)
     .min_size(Vec2::new(180.0, 36.0));
 
-    ui.add(button).clicked()
+    let response = ui.add(button);
+    AnimatedTooltip::attach(ui, theme, &response, tooltip);
+    response.clicked()
+}
+
+/// How long a tooltip takes to fade in
+const TOOLTIP_FADE_SECS: f32 = 0.15;
+
+/// Fade-in animations for the tooltips currently being hovered, kept in the context's temp data
+#[derive(Clone, Default)]
+struct TooltipState {
+    active: HashMap<egui::Id, (Animation, String)>,
+}
+
+/// Themed tooltip that fades in next to the cursor, used in place of `on_hover_text`
+pub struct AnimatedTooltip;
+
+impl AnimatedTooltip {
+    /// Show `text` while `response` is hovered. Does nothing when there is no text.
+    pub fn attach(ui: &Ui, theme: &AppTheme, response: &egui::Response, text: Option<&str>) {
+        let Some(text) = text else { return };
+        let ctx = ui.ctx();
+        let state_id = egui::Id::new("animated_tooltip_state");
+
+        if !response.hovered() {
+            ctx.data_mut(|data| {
+                data.get_temp_mut_or_default::<TooltipState>(state_id).active.remove(&response.id);
+            });
+            return;
+        }
+
+        let (alpha, fading) = ctx.data_mut(|data| {
+            let state = data.get_temp_mut_or_default::<TooltipState>(state_id);
+            let (animation, stored_text) = state.active
+                .entry(response.id)
+                .or_insert_with(|| (Animation::new(TOOLTIP_FADE_SECS), text.to_string()));
+            if stored_text != text {
+                *stored_text = text.to_string();
+            }
+            (animation.lerp(0.0, 1.0), !animation.is_completed())
+        });
+
+        if fading {
+            ctx.request_repaint();
+        }
+
+        let Some(pointer) = ctx.pointer_hover_pos() else { return };
+
+        egui::Area::new(response.id.with("animated_tooltip"))
+            .order(egui::Order::Tooltip)
+            .fixed_pos(pointer + Vec2::new(14.0, 18.0))
+            .interactable(false)
+            .show(ctx, |ui| {
+                egui::Frame::new()
+                    .fill(theme.card_background.gamma_multiply(alpha))
+                    .stroke(Stroke::new(1.0, theme.border.gamma_multiply(alpha)))
+                    .corner_radius(CornerRadius::same(4))
+                    .inner_margin(6.0)
+                    .show(ui, |ui| {
+                        ui.set_max_width(260.0);
+                        ui.label(RichText::new(text).color(theme.text.gamma_multiply(alpha)).size(12.0));
+                    });
+            });
+    }
 }
 
 /// Create a tooltip with consistent styling
@@ -94,7 +167,44 @@ pub fn status_message(ui: &mut Ui, theme: &AppTheme, text: &str, message_type: S
     });
 }
 
+/// An error status message. Errors explained for the user show only their message, with a
+/// button that copies the underlying errors for a bug report.
+pub fn error_message(ui: &mut Ui, theme: &AppTheme, error: &AppError) {
+    status_message(ui, theme, &error.to_string(), StatusMessageType::Error);
+
+    if matches!(error, AppError::UserFacing { .. }) && ui.small_button(t!("components.copy_technical_details")).clicked() {
+        ui.ctx().copy_text(error.technical_details());
+    }
+}
+
+/// Single-line text field drawn with an error-colored border while `error` is set
+pub fn validated_text_edit(ui: &mut Ui, theme: &AppTheme, text: &mut String, error: Option<&str>) -> egui::Response {
+    ui.scope(|ui| {
+        if error.is_some() {
+            let visuals = ui.visuals_mut();
+            let stroke = Stroke::new(1.5, theme.error);
+            visuals.widgets.inactive.bg_stroke = stroke;
+            visuals.widgets.hovered.bg_stroke = stroke;
+            visuals.widgets.active.bg_stroke = stroke;
+            visuals.selection.stroke = stroke;
+        }
+        ui.text_edit_singleline(text)
+    }).inner
+}
+
+/// Create a banner explaining that the configuration can't be edited during a session
+pub fn config_locked_banner(ui: &mut Ui, theme: &AppTheme) {
+    status_message(
+        ui,
+        theme,
+        &t!("components.session_active_banner"),
+        StatusMessageType::Warning,
+    );
+    ui.add_space(theme.spacing_small());
+}
+
 /// Status message types
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum StatusMessageType {
     Info,
     Success,
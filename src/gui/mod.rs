@@ -4,6 +4,8 @@ pub mod clicker;
 pub mod theme;
 pub mod components;
 pub mod animations;
+pub mod calibration;
+pub mod onboarding;
 
 // Re-export the main app for convenience
 pub use app::MouseClickerApp;
@@ -0,0 +1,172 @@
+use eframe::egui::{self, Context, Id, LayerId, Order, Rect, RichText, Stroke};
+use std::sync::{Arc, Mutex};
+
+use crate::gui::animations::Animation;
+use crate::gui::app::{AppState, AppView};
+
+/// Length of one pulse of the highlight border
+const PULSE_SECS: f32 = 1.2;
+
+/// Steps of the first-run tutorial, in the order they're shown
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OnboardingStep {
+    CreateProfile,
+    DefineArea,
+    ChooseClickType,
+    SetTiming,
+    StartClicking,
+}
+
+impl OnboardingStep {
+    pub const ALL: [OnboardingStep; 5] = [
+        OnboardingStep::CreateProfile,
+        OnboardingStep::DefineArea,
+        OnboardingStep::ChooseClickType,
+        OnboardingStep::SetTiming,
+        OnboardingStep::StartClicking,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            OnboardingStep::CreateProfile => "Create your first profile",
+            OnboardingStep::DefineArea => "Define a click area",
+            OnboardingStep::ChooseClickType => "Choose a click type",
+            OnboardingStep::SetTiming => "Set timing",
+            OnboardingStep::StartClicking => "Start clicking",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            OnboardingStep::CreateProfile => "Profiles hold everything about how and where to click. Enter a name on the Profiles page and create one to keep your settings.",
+            OnboardingStep::DefineArea => "Click Areas sets the part of the screen clicks land in. Pick a size and position, or center it on the screen.",
+            OnboardingStep::ChooseClickType => "Under Settings, choose which mouse button to click and whether to single or double click.",
+            OnboardingStep::SetTiming => "Also under Settings, set the delay between clicks. A wider range looks more natural.",
+            OnboardingStep::StartClicking => "Press Start in the status bar when you're ready. Pause and Stop take its place while clicking.",
+        }
+    }
+
+    /// The page that holds the controls this step is about
+    fn view(self) -> AppView {
+        match self {
+            OnboardingStep::CreateProfile => AppView::Profiles,
+            OnboardingStep::DefineArea => AppView::Areas,
+            OnboardingStep::ChooseClickType | OnboardingStep::SetTiming => AppView::Settings,
+            OnboardingStep::StartClicking => AppView::Profiles,
+        }
+    }
+}
+
+/// Walks a new user through setting up their first run, highlighting the control each step is about
+pub struct Onboarding {
+    state: Arc<Mutex<AppState>>,
+    pulse: Animation,
+    /// Where the current step's control was drawn this frame
+    highlight: Option<Rect>,
+}
+
+impl Onboarding {
+    pub fn new(state: Arc<Mutex<AppState>>) -> Self {
+        Self {
+            state,
+            pulse: Animation::new(PULSE_SECS),
+            highlight: None,
+        }
+    }
+
+    /// The step being shown, or `None` once the tutorial is finished or skipped
+    pub fn current_step(&self) -> Option<OnboardingStep> {
+        let state = self.state.lock().unwrap();
+        if state.preferences.onboarding_complete {
+            return None;
+        }
+        OnboardingStep::ALL.get(state.preferences.onboarding_step).copied()
+    }
+
+    /// Record where a control was drawn, so it's highlighted while one of `steps` is shown
+    pub fn mark(&mut self, steps: &[OnboardingStep], rect: Rect) {
+        if self.current_step().is_some_and(|step| steps.contains(&step)) {
+            self.highlight = Some(rect);
+        }
+    }
+
+    /// Show the wizard and the highlight. Call after the rest of the UI so this frame's `mark`s are in.
+    pub fn show(&mut self, ctx: &Context) {
+        let highlight = self.highlight.take();
+        let Some(step) = self.current_step() else { return };
+        let index = OnboardingStep::ALL.iter().position(|&s| s == step).unwrap_or(0);
+        let theme = {
+            let state = self.state.lock().unwrap();
+            state.theme.clone()
+        };
+
+        if let Some(rect) = highlight {
+            if self.pulse.is_completed() {
+                self.pulse.reset();
+            }
+
+            // Fade in and out once per pulse
+            let strength = (self.pulse.progress() * std::f32::consts::PI).sin();
+            let color = theme.warning.gamma_multiply(0.4 + 0.6 * strength);
+            let painter = ctx.layer_painter(LayerId::new(Order::Foreground, Id::new("onboarding_highlight")));
+            painter.rect_stroke(
+                rect.expand(2.0 + 2.0 * strength),
+                6.0,
+                Stroke::new(2.0 + strength, color),
+                egui::epaint::StrokeKind::Outside,
+            );
+        }
+
+        let mut next_step = None;
+        let mut finished = false;
+
+        egui::Window::new("Welcome to Mouse Clicker")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::RIGHT_BOTTOM, [-16.0, -48.0])
+            .default_width(300.0)
+            .show(ctx, |ui| {
+                ui.label(RichText::new(format!("Step {} of {}", index + 1, OnboardingStep::ALL.len())).color(theme.muted_text).small());
+                ui.heading(step.title());
+                ui.add_space(4.0);
+                ui.label(step.description());
+                ui.add_space(8.0);
+
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(index > 0, egui::Button::new("Back")).clicked() {
+                        next_step = Some(index - 1);
+                    }
+
+                    let last = index + 1 == OnboardingStep::ALL.len();
+                    if ui.button(if last { "Finish" } else { "Next" }).clicked() {
+                        if last {
+                            finished = true;
+                        } else {
+                            next_step = Some(index + 1);
+                        }
+                    }
+
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.link("Skip Tutorial").clicked() {
+                            finished = true;
+                        }
+                    });
+                });
+            });
+
+        if finished || next_step.is_some() {
+            let mut state = self.state.lock().unwrap();
+            if finished {
+                state.preferences.onboarding_complete = true;
+                state.preferences.onboarding_step = 0;
+            } else if let Some(next) = next_step {
+                // Saved on every step so the tutorial picks up where it left off after a restart
+                state.preferences.onboarding_step = next;
+                state.current_view = OnboardingStep::ALL[next].view();
+                state.view_transition.reset();
+                self.pulse.reset();
+            }
+            state.save_preferences();
+        }
+    }
+}
@@ -1,39 +1,100 @@
 use eframe::egui::{self, Color32, Stroke, Vec2, Ui, RichText};
 use eframe::epaint::{CornerRadius, Margin};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Colors that can be changed one at a time under Settings > Appearance, as (preference key, label)
+pub const OVERRIDABLE_COLORS: [(&str, &str); 5] = [
+    ("primary", "Primary"),
+    ("secondary", "Secondary"),
+    ("accent", "Accent"),
+    ("background", "Background"),
+    ("border", "Border"),
+];
 
 /// Modern theme with smooth colors and consistent styling
-#[derive(Clone)]
+/// A `Color32` as stored on disk: its RGBA bytes, premultiplied the way egui keeps them
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SerializableColor(pub [u8; 4]);
+
+impl From<Color32> for SerializableColor {
+    fn from(color: Color32) -> Self {
+        Self(color.to_array())
+    }
+}
+
+impl From<SerializableColor> for Color32 {
+    fn from(color: SerializableColor) -> Self {
+        let [r, g, b, a] = color.0;
+        Color32::from_rgba_premultiplied(r, g, b, a)
+    }
+}
+
+/// Serde adapter for `Color32` fields, going through `SerializableColor`
+mod serde_color {
+    use super::SerializableColor;
+    use eframe::egui::Color32;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(color: &Color32, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializableColor::from(*color).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color32, D::Error> {
+        SerializableColor::deserialize(deserializer).map(Color32::from)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AppTheme {
     // Primary colors
+    #[serde(with = "serde_color")]
     pub primary: Color32,
+    #[serde(with = "serde_color")]
     pub primary_light: Color32,
+    #[serde(with = "serde_color")]
     pub primary_dark: Color32,
 
     // Secondary colors
+    #[serde(with = "serde_color")]
     pub secondary: Color32,
+    #[serde(with = "serde_color")]
     pub accent: Color32,
 
     // Background colors
+    #[serde(with = "serde_color")]
     pub background: Color32,
+    #[serde(with = "serde_color")]
     pub card_background: Color32,
+    #[serde(with = "serde_color")]
     pub panel_background: Color32,
 
     // Text colors
+    #[serde(with = "serde_color")]
     pub text: Color32,
+    #[serde(with = "serde_color")]
     pub muted_text: Color32,
+    #[serde(with = "serde_color")]
     pub header_text: Color32,
 
     // Interactive colors
+    #[serde(with = "serde_color")]
     pub hover: Color32,
+    #[serde(with = "serde_color")]
     pub active: Color32,
+    #[serde(with = "serde_color")]
     pub selected: Color32,
 
     // Status colors
+    #[serde(with = "serde_color")]
     pub success: Color32,
+    #[serde(with = "serde_color")]
     pub warning: Color32,
+    #[serde(with = "serde_color")]
     pub error: Color32,
 
     // Border colors
+    #[serde(with = "serde_color")]
     pub border: Color32,
 
     // Animation settings
@@ -121,6 +182,42 @@ impl AppTheme {
         }
     }
 
+    /// The built-in dark or light theme
+    pub fn preset(dark: bool) -> Self {
+        if dark { Self::dark() } else { Self::light() }
+    }
+
+    /// One of the `OVERRIDABLE_COLORS`, by its preference key
+    pub fn color(&self, key: &str) -> Option<Color32> {
+        self.clone().color_mut(key).copied()
+    }
+
+    pub fn color_mut(&mut self, key: &str) -> Option<&mut Color32> {
+        match key {
+            "primary" => Some(&mut self.primary),
+            "secondary" => Some(&mut self.secondary),
+            "accent" => Some(&mut self.accent),
+            "background" => Some(&mut self.background),
+            "border" => Some(&mut self.border),
+            _ => None,
+        }
+    }
+
+    /// Replace individual colors with the user's saved choices
+    pub fn apply_overrides(&mut self, overrides: &HashMap<String, [u8; 3]>) {
+        for (key, [r, g, b]) in overrides {
+            if let Some(color) = self.color_mut(key) {
+                *color = Color32::from_rgb(*r, *g, *b);
+            }
+        }
+    }
+
+    /// Whether the theme has a dark background, so the dark mode toggle can reflect a saved theme
+    pub fn is_dark(&self) -> bool {
+        let [r, g, b, _] = self.background.to_array();
+        (r as u32 + g as u32 + b as u32) / 3 < 128
+    }
+
     /// Apply the theme to the context
     pub fn apply_to_ctx(&self, ctx: &egui::Context) {
         let mut style = (*ctx.style()).clone();
@@ -164,38 +261,47 @@ impl AppTheme {
 
     /// Create a primary button
     pub fn primary_button(&self, ui: &mut Ui, text: &str) -> bool {
-        let button = egui::Button::new(
+        ui.add(self.primary_button_widget(text)).clicked()
+    }
+
+    /// Create a secondary button
+    pub fn secondary_button(&self, ui: &mut Ui, text: &str) -> bool {
+        ui.add(self.secondary_button_widget(text)).clicked()
+    }
+
+    /// Create an accent button
+    pub fn accent_button(&self, ui: &mut Ui, text: &str) -> bool {
+        ui.add(self.accent_button_widget(text)).clicked()
+    }
+
+    /// Primary button widget, for callers that need the full response
+    pub fn primary_button_widget<'a>(&self, text: &str) -> egui::Button<'a> {
+        egui::Button::new(
             RichText::new(text).color(Color32::WHITE).size(14.0)
         )
         .fill(self.primary)
         .stroke(Stroke::new(1.0, self.primary_dark))
-        .corner_radius(CornerRadius::same(4));
-
-        ui.add(button).clicked()
+        .corner_radius(CornerRadius::same(4))
     }
 
-    /// Create a secondary button
-    pub fn secondary_button(&self, ui: &mut Ui, text: &str) -> bool {
-        let button = egui::Button::new(
+    /// Secondary button widget, for callers that need the full response
+    pub fn secondary_button_widget<'a>(&self, text: &str) -> egui::Button<'a> {
+        egui::Button::new(
             RichText::new(text).color(Color32::WHITE).size(14.0)
         )
         .fill(self.secondary)
         .stroke(Stroke::new(1.0, self.secondary))
-        .corner_radius(CornerRadius::same(4));
-
-        ui.add(button).clicked()
+        .corner_radius(CornerRadius::same(4))
     }
 
-    /// Create an accent button
-    pub fn accent_button(&self, ui: &mut Ui, text: &str) -> bool {
-        let button = egui::Button::new(
+    /// Accent button widget, for callers that need the full response
+    pub fn accent_button_widget<'a>(&self, text: &str) -> egui::Button<'a> {
+        egui::Button::new(
             RichText::new(text).color(Color32::WHITE).size(14.0)
         )
         .fill(self.accent)
         .stroke(Stroke::new(1.0, self.accent))
-        .corner_radius(CornerRadius::same(4));
-
-        ui.add(button).clicked()
+        .corner_radius(CornerRadius::same(4))
     }
 
     /// Create a card frame
@@ -1,9 +1,22 @@
-use egui::{Ui, ScrollArea, Color32, Stroke, Rect, Vec2, Pos2};
+use egui::{Ui, ScrollArea, Color32, Stroke, Rect, Vec2, Pos2, TextureHandle, TextureOptions};
+use std::collections::VecDeque;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
+use std::sync::mpsc::Receiver;
 use enigo::{Enigo, MouseControllable};
+use regex::Regex;
 
 use crate::gui::app::AppState;
-use crate::modules::config::{ClickArea, AreaSelectionMode};
+use crate::gui::animations::lerp_color;
+use crate::gui::components;
+use crate::gui::theme::AppTheme;
+use crate::modules::config::{ClickArea, ClickType, AreaSelectionMode, Config, MultiAreaConfig, ValidationWarning, PRIMARY_AREA_NAME};
+use crate::modules::dialogs;
+use crate::modules::error::{AppError, Result};
+use crate::modules::event_log::ClickEvent;
+use crate::modules::image_recognition::capture_window;
+use crate::modules::monitors::{self, MonitorInfo};
+use crate::t;
 
 pub struct AreaView {
     state: Arc<Mutex<AppState>>,
@@ -14,77 +27,782 @@ pub struct AreaView {
     screen_height: i32,
     drag_start: Option<Pos2>,
     current_drag: Option<Rect>,
+    coords_file_path: String,
+    import_message: Option<Result<String>>,
+    area_search: String,
+    monitors: Vec<MonitorInfo>,
+    preview_monitor: u32,
+    switch_target_monitor: u32,
+    click_events: Receiver<ClickEvent>,
+    /// Screen position and area index of recent clicks, oldest first
+    heatmap_points: VecDeque<(i32, i32, Option<usize>)>,
+    show_heatmap: bool,
+    /// Whether the area list had keyboard focus last frame, so its focus ring can be drawn
+    list_has_focus: bool,
+    scroll_to_selected: bool,
+    /// Index of the area waiting for the user to confirm its removal
+    pending_delete: Option<usize>,
+    /// Area being edited in place: its index and the unsaved area and weight
+    editing_area: Option<(usize, ClickArea, f32)>,
+    /// Area whose weight bar is being dragged, and the weight it has been dragged to
+    weight_drag: Option<(usize, f32)>,
+    /// Primary screen size according to enigo, which clicks are sent in
+    enigo_screen: (i32, i32),
+    /// Primary screen size according to GDI, which screenshots are captured at
+    gdi_screen: (i32, i32),
+    /// Title of the window to show behind the preview
+    preview_window_title: String,
+    /// That window's captured client area and its top-left corner in desktop coordinates
+    window_preview: Option<(TextureHandle, (i32, i32))>,
+    window_preview_error: Option<String>,
 }
 
+/// Ring drawn around the selected area while the list has keyboard focus
+const FOCUS_RING_COLOR: Color32 = Color32::from_rgb(66, 133, 244);
+
+/// Diameter of the weighted selection pie chart
+const PIE_SIZE: f32 = 150.0;
+
+/// Height of the draggable weight bar under each area in the list
+const WEIGHT_BAR_HEIGHT: f32 = 6.0;
+
+/// Oldest clicks drop out of the heatmap past this many
+const HEATMAP_MAX_POINTS: usize = 5_000;
+
+/// Size of a heatmap cell in preview pixels
+const HEATMAP_CELL_PX: f32 = 8.0;
+
+/// How much a click adds to its own cell and the eight around it
+const HEATMAP_KERNEL: [[f32; 3]; 3] = [
+    [0.25, 0.5, 0.25],
+    [0.5, 1.0, 0.5],
+    [0.25, 0.5, 0.25],
+];
+
 impl AreaView {
     pub fn new(state: Arc<Mutex<AppState>>) -> Self {
-        // Get screen dimensions from enigo
-        let enigo = enigo::Enigo::new();
-        let screen_size = enigo.main_display_size();
-        let screen_width = screen_size.0 as i32;
-        let screen_height = screen_size.1 as i32;
-
-        Self {
+        let click_events = state.lock().unwrap().click_events.subscribe();
+        let mut view = Self {
             state,
             selected_area_index: None,
             new_area: ClickArea::default(),
             is_adding_area: false,
-            screen_width,
-            screen_height,
+            screen_width: 0,
+            screen_height: 0,
             drag_start: None,
             current_drag: None,
+            coords_file_path: String::new(),
+            import_message: None,
+            area_search: String::new(),
+            monitors: Vec::new(),
+            preview_monitor: 0,
+            switch_target_monitor: 0,
+            click_events,
+            heatmap_points: VecDeque::new(),
+            show_heatmap: false,
+            list_has_focus: false,
+            scroll_to_selected: false,
+            pending_delete: None,
+            editing_area: None,
+            weight_drag: None,
+            enigo_screen: Enigo::new().main_display_size(),
+            gdi_screen: monitors::gdi_screen_size(),
+            preview_window_title: String::new(),
+            window_preview: None,
+            window_preview_error: None,
+        };
+        view.refresh_monitors();
+        view
+    }
+
+    /// Open the edit form for an area, or close it if that area is already being edited
+    fn toggle_edit_form(&mut self, index: usize, config: &Config) {
+        if self.editing_area.as_ref().is_some_and(|(editing, _, _)| *editing == index) {
+            self.editing_area = None;
+        } else if let Some((area, weight)) = config.multi_area.areas.get(index) {
+            self.editing_area = Some((index, area.clone(), *weight));
         }
     }
 
-    pub fn ui(&mut self, ui: &mut Ui) {
-        ui.heading("Click Area Configuration");
+    fn edit_area_ui(&mut self, ui: &mut Ui, selection_mode: AreaSelectionMode) {
+        let Some((index, area, weight)) = &mut self.editing_area else { return };
+        let index = *index;
+        let mut close = false;
+
+        ui.add_space(10.0);
+        ui.separator();
+        ui.heading(t!("area.edit_area", name = area.display_name(index)));
+
+        ui.horizontal(|ui| {
+            ui.label(t!("area.name"));
+            ui.add(egui::TextEdit::singleline(&mut area.name).hint_text(t!("area.optional")));
+        });
+
+        ui.horizontal(|ui| {
+            ui.label(t!("area.width"));
+            ui.add(egui::DragValue::new(&mut area.width).speed(1.0).range(10..=2000));
+
+            ui.label(t!("area.height"));
+            ui.add(egui::DragValue::new(&mut area.height).speed(1.0).range(10..=2000));
+        });
+
+        if area.anchor_area_name.is_none() {
+            ui.checkbox(&mut area.centered, t!("area.centered"));
+        }
+        if !area.centered || area.anchor_area_name.is_some() {
+            ui.horizontal(|ui| {
+                ui.label(t!("area.x_offset"));
+                ui.add(egui::DragValue::new(&mut area.x_offset).speed(1.0));
+
+                ui.label(t!("area.y_offset"));
+                ui.add(egui::DragValue::new(&mut area.y_offset).speed(1.0));
+            });
+        }
+
+        if selection_mode == AreaSelectionMode::Weighted {
+            ui.horizontal(|ui| {
+                ui.label(t!("area.weight"));
+                ui.add(egui::Slider::new(weight, 0.01..=1.0).text(t!("area.weight_2")));
+            });
+        }
 
         ui.horizontal(|ui| {
+            if ui.button(t!("area.save")).clicked() {
+                let mut state = self.state.lock().unwrap();
+                if let Some(entry) = state.current_config.multi_area.areas.get_mut(index) {
+                    *entry = (area.clone(), *weight);
+                }
+                close = true;
+            }
+
+            if ui.button(t!("area.cancel")).clicked() || ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+                close = true;
+            }
+        });
+
+        if close {
+            self.editing_area = None;
+        }
+    }
+
+    /// The area list with weights being edited or dragged but not yet saved, so the
+    /// chart and percentages follow the slider or bar
+    fn displayed_areas(&self, config: &Config) -> MultiAreaConfig {
+        let mut multi_area = config.multi_area.clone();
+        let pending = self.editing_area.as_ref().map(|(index, _, weight)| (*index, *weight))
+            .into_iter()
+            .chain(self.weight_drag);
+        for (index, weight) in pending {
+            if let Some(entry) = multi_area.areas.get_mut(index) {
+                entry.1 = weight;
+            }
+        }
+        multi_area
+    }
+
+    /// Thin bar showing an area's share of the weighted picks. Dragging it sideways changes
+    /// the area's weight, which is saved when the drag ends.
+    fn weight_bar_ui(&mut self, ui: &mut Ui, theme: &AppTheme, index: usize, weight: f32, share: f32, total_weight: f32) {
+        let (rect, response) = ui.allocate_exact_size(
+            Vec2::new(ui.available_width(), WEIGHT_BAR_HEIGHT),
+            egui::Sense::drag(),
+        );
+        let response = response.on_hover_cursor(egui::CursorIcon::ResizeHorizontal);
+
+        let painter = ui.painter();
+        painter.rect_filled(rect, 2.0, theme.border);
+        let filled = Rect::from_min_size(rect.min, Vec2::new(rect.width() * share.clamp(0.0, 1.0), rect.height()));
+        painter.rect_filled(filled, 2.0, slice_color(index));
+
+        if response.dragged() {
+            // Dragging the full width of the bar adds or removes the whole current total
+            let current = self.weight_drag.filter(|(i, _)| *i == index).map_or(weight, |(_, w)| w);
+            let delta = response.drag_delta().x / rect.width().max(1.0) * total_weight.max(1.0);
+            self.weight_drag = Some((index, (current + delta).max(0.0)));
+        }
+
+        if response.drag_stopped() {
+            if let Some((dragged, new_weight)) = self.weight_drag.take() {
+                let mut state = self.state.lock().unwrap();
+                if let Some(entry) = state.current_config.multi_area.areas.get_mut(dragged) {
+                    entry.1 = new_weight;
+                }
+                if let Some((editing, _, weight)) = &mut self.editing_area {
+                    if *editing == dragged {
+                        *weight = new_weight;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Turn an area on or off in the current config, keeping an open edit form in step
+    fn set_area_enabled(&mut self, index: usize, enabled: bool) {
+        let mut state = self.state.lock().unwrap();
+        if let Some((area, _)) = state.current_config.multi_area.areas.get_mut(index) {
+            area.enabled = enabled;
+        }
+        if let Some((editing, area, _)) = &mut self.editing_area {
+            if *editing == index {
+                area.enabled = enabled;
+            }
+        }
+    }
+
+    /// Pie chart of each area's chance of being picked in weighted mode, with a legend.
+    /// Clicking a slice selects its area in the list.
+    fn weight_pie_ui(&mut self, ui: &mut Ui, config: &Config) {
+        let multi_area = self.displayed_areas(config);
+        let shares = multi_area.normalize_weights();
+
+        ui.horizontal(|ui| {
+            let (response, painter) = ui.allocate_painter(Vec2::splat(PIE_SIZE), egui::Sense::click());
+            let center = response.rect.center();
+            let radius = PIE_SIZE / 2.0 - 2.0;
+
+            // Slices run clockwise from 12 o'clock
+            let start_angle = -std::f32::consts::FRAC_PI_2;
+            let mut angle = start_angle;
+            for (i, share) in shares.iter().enumerate() {
+                let sweep = share * std::f32::consts::TAU;
+                if sweep <= 0.0 {
+                    continue;
+                }
+
+                let mut color = slice_color(i);
+                if self.selected_area_index.is_some_and(|selected| selected != i) {
+                    color = color.gamma_multiply(0.5);
+                }
+
+                // A fan of thin triangles, fine enough that the edge looks round
+                let steps = ((sweep / 0.05).ceil() as usize).max(1);
+                let mut mesh = egui::Mesh::default();
+                mesh.colored_vertex(center, color);
+                for step in 0..=steps {
+                    let a = angle + sweep * step as f32 / steps as f32;
+                    mesh.colored_vertex(center + radius * Vec2::angled(a), color);
+                }
+                for step in 0..steps as u32 {
+                    mesh.add_triangle(0, step + 1, step + 2);
+                }
+                painter.add(egui::Shape::mesh(mesh));
+
+                angle += sweep;
+            }
+            painter.circle_stroke(center, radius, Stroke::new(1.0, Color32::from_gray(60)));
+
+            if response.clicked() {
+                if let Some(pos) = response.interact_pointer_pos() {
+                    let offset = pos - center;
+                    if offset.length() <= radius {
+                        let clicked_share = (offset.angle() - start_angle).rem_euclid(std::f32::consts::TAU) / std::f32::consts::TAU;
+                        let mut total = 0.0;
+                        let slice = shares.iter().position(|share| {
+                            total += share;
+                            clicked_share < total
+                        });
+                        if let Some(index) = slice {
+                            self.selected_area_index = Some(index);
+                            self.scroll_to_selected = true;
+                        }
+                    }
+                }
+            }
+
             ui.vertical(|ui| {
-                ui.heading("Areas");
+                for (i, ((area, _), share)) in multi_area.areas.iter().zip(&shares).enumerate() {
+                    ui.horizontal(|ui| {
+                        let (rect, _) = ui.allocate_exact_size(Vec2::splat(12.0), egui::Sense::hover());
+                        ui.painter().rect_filled(rect, 2.0, slice_color(i));
+                        let text = egui::RichText::new(format!("{} — {:.1}%", area.display_name(i), share * 100.0));
+                        if self.selected_area_index == Some(i) {
+                            ui.label(text.strong());
+                        } else {
+                            ui.label(text);
+                        }
+                    });
+                }
+            });
+        });
+    }
+
+    /// Ask before removing the area in `pending_delete`
+    fn delete_confirmation_ui(&mut self, ctx: &egui::Context, config: &Config) {
+        let Some(index) = self.pending_delete else { return };
+        let Some((area, _)) = config.multi_area.areas.get(index) else {
+            self.pending_delete = None;
+            return;
+        };
+        let mut confirmed = false;
+        let mut cancelled = false;
+
+        egui::Window::new(t!("area.remove_area"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(t!("area.remove_area_confirm", name = area.display_name(index)));
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    confirmed = ui.button(t!("area.remove")).clicked();
+                    cancelled = ui.button(t!("area.cancel")).clicked() || ui.input(|i| i.key_pressed(egui::Key::Escape));
+                });
+            });
+
+        if confirmed {
+            let mut state = self.state.lock().unwrap();
+            if state.current_config.remove_click_area(index) {
+                self.selected_area_index = None;
+                self.editing_area = None;
+            }
+        }
+
+        if confirmed || cancelled {
+            self.pending_delete = None;
+        }
+    }
+
+    /// Pull in clicks broadcast by the clicker thread. Called every frame so the queue never backs up.
+    pub fn receive_click_events(&mut self) {
+        self.heatmap_points.extend(self.click_events.try_iter().map(|event| (event.x, event.y, event.click_area_index)));
+
+        while self.heatmap_points.len() > HEATMAP_MAX_POINTS {
+            self.heatmap_points.pop_front();
+        }
+    }
+
+    /// Density of recent clicks over the preview. In multi-area mode a click counts in proportion to
+    /// its area's chance of being picked, so a heavily weighted area stands out from a rarely used one.
+    fn draw_heatmap(&self, painter: &egui::Painter, preview: Rect, config: &Config, theme: &AppTheme) {
+        let columns = (preview.width() / HEATMAP_CELL_PX).ceil() as usize;
+        let rows = (preview.height() / HEATMAP_CELL_PX).ceil() as usize;
+        if columns == 0 || rows == 0 || self.screen_width <= 0 || self.screen_height <= 0 {
+            return;
+        }
+
+        // Clicks are in desktop coordinates, the preview shows a single monitor
+        let (origin_x, origin_y) = self.monitors.get(self.preview_monitor as usize)
+            .map_or((0, 0), |monitor| (monitor.x, monitor.y));
+        let scale_x = preview.width() / self.screen_width as f32;
+        let scale_y = preview.height() / self.screen_height as f32;
+        let weights = config.multi_area.normalize_weights();
+
+        let mut density = vec![0.0f32; columns * rows];
+        for &(x, y, area_index) in &self.heatmap_points {
+            let column = ((x - origin_x) as f32 * scale_x / HEATMAP_CELL_PX).floor() as isize;
+            let row = ((y - origin_y) as f32 * scale_y / HEATMAP_CELL_PX).floor() as isize;
+            if column < 0 || row < 0 || column as usize >= columns || row as usize >= rows {
+                continue;
+            }
+
+            // Areas removed since the click no longer have a weight to scale by
+            let weight = match area_index {
+                Some(index) => weights.get(index).copied().unwrap_or(0.0),
+                None => 1.0,
+            };
+
+            for (dy, kernel_row) in HEATMAP_KERNEL.iter().enumerate() {
+                for (dx, kernel) in kernel_row.iter().enumerate() {
+                    let (c, r) = (column + dx as isize - 1, row + dy as isize - 1);
+                    if c >= 0 && r >= 0 && (c as usize) < columns && (r as usize) < rows {
+                        density[r as usize * columns + c as usize] += weight * kernel;
+                    }
+                }
+            }
+        }
+
+        let max = density.iter().copied().fold(0.0, f32::max);
+        if max <= 0.0 {
+            return;
+        }
+
+        for (i, &value) in density.iter().enumerate() {
+            if value <= 0.0 {
+                continue;
+            }
+
+            let t = value / max;
+            let cell = Rect::from_min_size(
+                preview.min + Vec2::new((i % columns) as f32, (i / columns) as f32) * HEATMAP_CELL_PX,
+                Vec2::splat(HEATMAP_CELL_PX),
+            ).intersect(preview);
+            painter.rect_filled(cell, 0.0, lerp_color(theme.warning, theme.error, t).gamma_multiply(0.15 + 0.6 * t));
+        }
+    }
+
+    /// Move the session's areas to another monitor without stopping it.
+    /// The clicker reads the config every cycle, so the next click lands on the new monitor.
+    fn switch_monitor_ui(&mut self, ui: &mut Ui, theme: &AppTheme) {
+        if self.monitors.len() < 2 {
+            return;
+        }
+
+        let current = self.state.lock().unwrap().current_config.click_area.monitor_index;
+
+        let switched = ui.horizontal(|ui| {
+            monitor_selector(ui, "switch_monitor", &self.monitors, &mut self.switch_target_monitor);
+
+            let switch_enabled = self.switch_target_monitor != current;
+            ui.add_enabled(switch_enabled, egui::Button::new(t!("area.switch_monitor"))).clicked()
+        }).inner;
+
+        if switched {
+            let target = self.switch_target_monitor;
+            {
+                let mut state = self.state.lock().unwrap();
+                let config = &mut state.current_config;
+
+                // Areas on other monitors stay where they are
+                for (area, _) in config.multi_area.areas.iter_mut().filter(|(area, _)| area.monitor_index == current) {
+                    area.monitor_index = target;
+                }
+                config.click_area.monitor_index = target;
+            }
+            self.preview_monitor = target;
+            self.update_screen_size();
+        }
+
+        let from = self.monitors.get(current as usize);
+        let to = self.monitors.get(self.switch_target_monitor as usize);
+        if let (Some(from), Some(to)) = (from, to) {
+            let (dx, dy) = (to.x - from.x, to.y - from.y);
+            if dx != 0 || dy != 0 {
+                let offset = if dy == 0 { format!("{}", dx) } else { format!("({}, {})", dx, dy) };
+                ui.colored_label(theme.warning, t!("area.switching_monitors_will_offset", offset = offset));
+            }
+        }
+        ui.add_space(theme.spacing_small());
+    }
+
+    /// Physical size of each monitor, primary first, for checking areas against
+    fn screen_sizes(&self) -> Vec<(i32, i32)> {
+        if self.monitors.is_empty() {
+            vec![(self.screen_width, self.screen_height)]
+        } else {
+            self.monitors.iter().map(|monitor| (monitor.width, monitor.height)).collect()
+        }
+    }
+
+    /// Re-read the connected monitors and the size of the one being previewed
+    fn refresh_monitors(&mut self) {
+        self.monitors = monitors::list_monitors();
+        if self.preview_monitor as usize >= self.monitors.len() {
+            self.preview_monitor = 0;
+        }
+        self.update_screen_size();
+    }
+
+    /// Both primary screen sizes when enigo and GDI disagree, as they do when Windows scales
+    /// the display for a process that isn't DPI aware
+    fn screen_size_mismatch_ui(&self, ui: &mut Ui, theme: &AppTheme) {
+        let (enigo, gdi) = (self.enigo_screen, self.gdi_screen);
+        if enigo == gdi || enigo.0 <= 0 || gdi.0 <= 0 {
+            return;
+        }
+
+        ui.label(egui::RichText::new(format!("Enigo: {}×{} | GDI: {}×{}", enigo.0, enigo.1, gdi.0, gdi.1)).small().monospace());
+
+        let differs = |a: i32, b: i32| (a - b).abs() as f32 / b as f32 > 0.01;
+        if differs(enigo.0, gdi.0) || differs(enigo.1, gdi.1) {
+            ui.colored_label(theme.warning, t!("area.dpi_scaling_detected_coordinates_may_be"));
+        }
+    }
+
+    /// Use the previewed monitor's size for the canvas, falling back to the primary display from enigo
+    fn update_screen_size(&mut self) {
+        if let Some(monitor) = self.monitors.get(self.preview_monitor as usize) {
+            self.screen_width = monitor.width;
+            self.screen_height = monitor.height;
+        } else {
+            let enigo = enigo::Enigo::new();
+            let screen_size = enigo.main_display_size();
+            self.screen_width = screen_size.0 as i32;
+            self.screen_height = screen_size.1 as i32;
+        }
+    }
+
+    // Whether an area matches the current search filter
+    /// Pick a window by title and capture it as the preview background
+    fn window_preview_ui(&mut self, ui: &mut Ui, theme: &AppTheme) {
+        ui.horizontal(|ui| {
+            ui.label(t!("area.window"));
+            ui.add(egui::TextEdit::singleline(&mut self.preview_window_title)
+                .hint_text(t!("area.exact_window_title"))
+                .desired_width(160.0));
+
+            let has_title = !self.preview_window_title.trim().is_empty();
+            if ui.add_enabled(has_title, egui::Button::new(t!("area.preview_window")))
+                .on_hover_text(t!("area.show_the_window_behind_the_areas"))
+                .clicked()
+            {
+                match capture_window(self.preview_window_title.trim()) {
+                    Ok((image, origin)) => {
+                        let size = [image.width() as usize, image.height() as usize];
+                        let color_image = egui::ColorImage::from_rgba_unmultiplied(size, image.as_flat_samples().as_slice());
+                        let texture = ui.ctx().load_texture("area_window_preview", color_image, TextureOptions::LINEAR);
+                        self.window_preview = Some((texture, origin));
+                        self.window_preview_error = None;
+                    },
+                    Err(e) => self.window_preview_error = Some(e.to_string()),
+                }
+            }
+
+            if self.window_preview.is_some() && ui.button(t!("area.clear")).clicked() {
+                self.window_preview = None;
+            }
+        });
+
+        if let Some(error) = &self.window_preview_error {
+            ui.colored_label(theme.error, error);
+        }
+    }
+
+    /// Draw the captured window where it sits on the previewed monitor
+    fn draw_window_preview(&self, painter: &egui::Painter, preview: Rect) {
+        let Some((texture, (window_x, window_y))) = &self.window_preview else { return };
+        if self.screen_width <= 0 || self.screen_height <= 0 {
+            return;
+        }
+
+        let (origin_x, origin_y) = self.monitors.get(self.preview_monitor as usize)
+            .map_or((0, 0), |monitor| (monitor.x, monitor.y));
+        let scale = Vec2::new(
+            preview.width() / self.screen_width as f32,
+            preview.height() / self.screen_height as f32,
+        );
+
+        let rect = Rect::from_min_size(
+            preview.min + Vec2::new((window_x - origin_x) as f32, (window_y - origin_y) as f32) * scale,
+            texture.size_vec2() * scale,
+        );
+        painter.with_clip_rect(preview).image(
+            texture.id(),
+            rect,
+            Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0)),
+            Color32::WHITE,
+        );
+    }
+
+    fn matches_search(&self, area: &ClickArea, index: usize) -> bool {
+        let query = self.area_search.trim().to_lowercase();
+        query.is_empty() || area.display_name(index).to_lowercase().contains(&query)
+    }
+
+    /// Extract the click positions from the `Click` and `MouseClick` commands of an AutoHotkey v1 script
+    pub fn import_from_ahk(script_content: &str) -> Vec<ClickArea> {
+        // Matches `Click, 100, 200`, `Click 100 200` and `MouseClick, left, 100, 200`,
+        // on a line of their own or after a hotkey such as `F1::`
+        let re = Regex::new(r"(?im)^\s*(?:\S+::\s*)?(?:Click|MouseClick\s*,\s*[a-z]*)[\s,]+(\d+)[\s,]+(\d+)")
+            .expect("AHK click pattern is valid");
+
+        re.captures_iter(script_content)
+            .filter_map(|caps| {
+                let x = caps[1].parse().ok()?;
+                let y = caps[2].parse().ok()?;
+                Some(ClickArea {
+                    width: 1,
+                    height: 1,
+                    centered: false,
+                    x_offset: x,
+                    y_offset: y,
+                    ..Default::default()
+                })
+            })
+            .collect()
+    }
+
+    /// Read click positions from a coordinate file, one click per line:
+    ///
+    /// ```text
+    /// # timestamp_ms,x,y
+    /// 0,640,360
+    /// 1250,700,412
+    /// ```
+    ///
+    /// `timestamp_ms` is the time of the click since the recording started and `x`, `y` are
+    /// absolute screen coordinates. Blank lines, lines starting with `#` and lines that don't
+    /// parse (such as a header) are skipped. Each click becomes a 1×1 area with weight 1.0.
+    pub fn import_from_coords_file(path: &Path) -> Result<Vec<(ClickArea, f32)>> {
+        let content = std::fs::read_to_string(path)?;
+
+        Ok(content.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let mut fields = line.split(',').map(str::trim);
+                let _timestamp_ms: u64 = fields.next()?.parse().ok()?;
+                let x = fields.next()?.parse().ok()?;
+                let y = fields.next()?.parse().ok()?;
+                Some((
+                    ClickArea {
+                        width: 1,
+                        height: 1,
+                        centered: false,
+                        x_offset: x,
+                        y_offset: y,
+                        ..Default::default()
+                    },
+                    1.0,
+                ))
+            })
+            .collect())
+    }
+
+    fn coords_import_ui(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.add(egui::TextEdit::singleline(&mut self.coords_file_path).hint_text(t!("area.path_to_a_coordinate_file")));
+
+            let has_path = !self.coords_file_path.trim().is_empty();
+            if ui.add_enabled(has_path, egui::Button::new(t!("area.import_coord_file")))
+                .on_hover_text(t!("area.a_text_file_of_timestamp_ms"))
+                .clicked()
+            {
+                self.import_message = Some(match Self::import_from_coords_file(Path::new(self.coords_file_path.trim())) {
+                    Ok(areas) => {
+                        let count = areas.len();
+
+                        let mut state = self.state.lock().unwrap();
+                        for (area, weight) in areas {
+                            state.current_config.add_click_area(area, weight);
+                        }
+
+                        Ok(t!("area.imported_areas", count = count))
+                    },
+                    Err(e) => Err(AppError::user_facing(
+                        "Failed to read the coordinate file. Check the path and that each line is timestamp_ms,x,y.",
+                        e,
+                    )),
+                });
+            }
+        });
+    }
+
+    fn ahk_import_ui(&mut self, ui: &mut Ui, theme: &AppTheme) {
+        ui.horizontal(|ui| {
+            if ui.button(t!("area.import_from_ahk_script")).clicked() {
+                let Some(path) = dialogs::pick_file("Import clicks from", &[("AutoHotkey scripts", "*.ahk"), ("All files", "*.*")]) else {
+                    return;
+                };
+                self.import_message = Some(match std::fs::read_to_string(&path) {
+                    Ok(script) => {
+                        let areas = Self::import_from_ahk(&script);
+                        let count = areas.len();
+
+                        let mut state = self.state.lock().unwrap();
+                        for area in areas {
+                            state.current_config.add_click_area(area, 1.0);
+                        }
+
+                        Ok(t!("area.imported_areas", count = count))
+                    },
+                    Err(e) => Err(AppError::user_facing(
+                        "Failed to read the AutoHotkey script. Check that the path points to a readable file.",
+                        AppError::from(e),
+                    )),
+                });
+            }
+        });
+
+        match &self.import_message {
+            Some(Ok(message)) => {
+                ui.label(message);
+            },
+            Some(Err(error)) => components::error_message(ui, theme, error),
+            None => {},
+        }
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        ui.heading(t!("area.click_area_configuration"));
+
+        let (theme, locked) = {
+            let state = self.state.lock().unwrap();
+            (state.theme.clone(), state.is_config_locked())
+        };
+
+        self.screen_size_mismatch_ui(ui, &theme);
+
+        if locked {
+            components::config_locked_banner(ui, &theme);
+            self.switch_monitor_ui(ui, &theme);
+        }
+
+        ui.add_enabled_ui(!locked, |ui| ui.horizontal(|ui| {
+            ui.vertical(|ui| {
+                ui.heading(t!("area.areas"));
 
                 let config = {
                     let state = self.state.lock().unwrap();
                     state.current_config.clone()
                 };
 
+                let warnings = config.validate(&self.screen_sizes());
+                let area_warnings = |name: &str| -> Vec<String> {
+                    warnings.iter()
+                        .filter(|warning| matches!(warning, ValidationWarning::AreaOutOfBounds { area_name, .. } if area_name == name))
+                        .map(|warning| warning.to_string())
+                        .collect()
+                };
+
                 // Primary area
-                ui.collapsing("Primary Click Area", |ui| {
+                ui.collapsing(t!("area.primary_click_area"), |ui| {
                     let mut area = config.click_area.clone();
                     let mut changed = false;
 
                     ui.horizontal(|ui| {
-                        ui.label("Width:");
+                        ui.label(t!("area.width"));
                         if ui.add(egui::DragValue::new(&mut area.width).speed(1.0).range(10..=2000)).changed() {
                             changed = true;
                         }
 
-                        ui.label("Height:");
+                        ui.label(t!("area.height"));
                         if ui.add(egui::DragValue::new(&mut area.height).speed(1.0).range(10..=2000)).changed() {
                             changed = true;
                         }
                     });
 
+                    if monitor_selector(ui, "primary_area_monitor", &self.monitors, &mut area.monitor_index) {
+                        changed = true;
+                    }
+
                     let mut centered = area.centered;
-                    if ui.checkbox(&mut centered, "Centered").changed() {
+                    if ui.checkbox(&mut centered, t!("area.centered")).changed() {
                         area.centered = centered;
                         changed = true;
                     }
 
+                    // Place the area at a fixed spot, stored as offsets so it can be fine-tuned
+                    let screens = self.screen_sizes();
+                    let (screen_width, screen_height) = screens.get(area.monitor_index as usize).copied().unwrap_or(screens[0]);
+                    ui.horizontal_wrapped(|ui| {
+                        for (label, halves_x, halves_y) in SNAP_POSITIONS {
+                            if ui.small_button(label).clicked() {
+                                area.x_offset = ((screen_width - area.width) * halves_x / 2).max(0);
+                                area.y_offset = ((screen_height - area.height) * halves_y / 2).max(0);
+                                area.centered = false;
+                                changed = true;
+                            }
+                        }
+                    });
+
                     if !area.centered {
                         ui.horizontal(|ui| {
-                            ui.label("X Offset:");
+                            ui.label(t!("area.x_offset"));
                             if ui.add(egui::DragValue::new(&mut area.x_offset).speed(1.0).range(0..=self.screen_width)).changed() {
                                 changed = true;
                             }
 
-                            ui.label("Y Offset:");
+                            ui.label(t!("area.y_offset"));
                             if ui.add(egui::DragValue::new(&mut area.y_offset).speed(1.0).range(0..=self.screen_height)).changed() {
                                 changed = true;
                             }
                         });
                     }
 
+                    for warning in area_warnings(PRIMARY_AREA_NAME) {
+                        ui.colored_label(theme.warning, warning);
+                    }
+
                     if changed {
                         let mut state = self.state.lock().unwrap();
                         state.current_config.click_area = area;
@@ -94,31 +812,35 @@ impl AreaView {
                 ui.add_space(10.0);
 
                 // Multiple areas
-                ui.collapsing("Multiple Click Areas", |ui| {
+                ui.collapsing(t!("area.multiple_click_areas"), |ui| {
                     let mut multi_enabled = config.multi_area.enabled;
-                    if ui.checkbox(&mut multi_enabled, "Enable Multiple Areas").changed() {
+                    if ui.checkbox(&mut multi_enabled, t!("area.enable_multiple_areas")).changed() {
                         let mut state = self.state.lock().unwrap();
                         state.current_config.multi_area.enabled = multi_enabled;
                     }
 
+                    self.coords_import_ui(ui);
+                    self.ahk_import_ui(ui, &theme);
+                    ui.add_space(10.0);
+
                     if multi_enabled {
                         ui.horizontal(|ui| {
-                            ui.label("Selection Mode:");
+                            ui.label(t!("area.selection_mode"));
                             egui::ComboBox::new("area_selection_mode", "Selection Mode")
                                 .selected_text(format!("{:?}", config.multi_area.selection_mode))
                                 .show_ui(ui, |ui| {
                                     let mut changed = false;
                                     let mut mode = config.multi_area.selection_mode;
 
-                                    if ui.selectable_value(&mut mode, AreaSelectionMode::Sequential, "Sequential").changed() {
+                                    if ui.selectable_value(&mut mode, AreaSelectionMode::Sequential, t!("area.sequential")).changed() {
                                         changed = true;
                                     }
 
-                                    if ui.selectable_value(&mut mode, AreaSelectionMode::Random, "Random").changed() {
+                                    if ui.selectable_value(&mut mode, AreaSelectionMode::Random, t!("area.random")).changed() {
                                         changed = true;
                                     }
 
-                                    if ui.selectable_value(&mut mode, AreaSelectionMode::Weighted, "Weighted").changed() {
+                                    if ui.selectable_value(&mut mode, AreaSelectionMode::Weighted, t!("area.weighted")).changed() {
                                         changed = true;
                                     }
 
@@ -132,89 +854,237 @@ impl AreaView {
                         ui.add_space(10.0);
 
                         // Area list
-                        ui.heading("Defined Areas");
+                        ui.heading(t!("area.defined_areas"));
+
+                        for warning in warnings.iter().filter(|warning| matches!(warning, ValidationWarning::CircularAreaAnchor { .. })) {
+                            ui.colored_label(theme.warning, warning.to_string());
+                        }
+
+                        if !config.multi_area.areas.is_empty() {
+                            ui.horizontal(|ui| {
+                                ui.add(egui::TextEdit::singleline(&mut self.area_search).hint_text(t!("area.filter_areas")));
+                                if !self.area_search.is_empty() && ui.small_button("×").clicked() {
+                                    self.area_search.clear();
+                                }
+                            });
+                        }
+
+                        // Indices into the full area list of the areas that match the filter
+                        let visible: Vec<usize> = (0..config.multi_area.areas.len())
+                            .filter(|&i| self.matches_search(&config.multi_area.areas[i].0, i))
+                            .collect();
+
+                        if visible.len() != config.multi_area.areas.len() {
+                            ui.label(t!("area.areas_shown", shown = visible.len(), total = config.multi_area.areas.len()));
+                        }
+
+                        let list_id = ui.make_persistent_id("area_list");
+                        let mut list_clicked = false;
+
+                        let weighted = config.multi_area.selection_mode == AreaSelectionMode::Weighted;
+                        let displayed = self.displayed_areas(&config);
+                        let shares = displayed.normalize_weights();
+                        let total_weight: f32 = displayed.areas.iter().map(|(_, weight)| weight.max(0.0)).sum();
 
-                        ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
-                            for (i, (area, weight)) in config.multi_area.areas.iter().enumerate() {
+                        let list = ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                            for &i in &visible {
+                                let area = &config.multi_area.areas[i].0;
+                                let weight = displayed.areas[i].1;
                                 let is_selected = self.selected_area_index == Some(i);
-                                let area_text = if area.centered {
-                                    format!("Area {}: {}x{} (centered) - Weight: {:.2}", i + 1, area.width, area.height, weight)
+                                let weight_text = if weighted {
+                                    format!("{:.2} ({:.0}%)", weight, shares[i] * 100.0)
                                 } else {
-                                    format!("Area {}: {}x{} at ({}, {}) - Weight: {:.2}",
-                                        i + 1, area.width, area.height, area.x_offset, area.y_offset, weight)
+                                    format!("{:.2}", weight)
                                 };
+                                let area_text = if let Some(anchor) = &area.anchor_area_name {
+                                    t!("area.anchored_area_summary", name = area.display_name(i), width = area.width, height = area.height,
+                                        x = area.x_offset, y = area.y_offset, anchor = anchor, weight = weight_text)
+                                } else if area.centered {
+                                    t!("area.centered_area_summary", name = area.display_name(i), width = area.width, height = area.height, weight = weight_text)
+                                } else {
+                                    t!("area.area_summary", name = area.display_name(i), width = area.width, height = area.height,
+                                        x = area.x_offset, y = area.y_offset, weight = weight_text)
+                                };
+
+                                let label = ui.horizontal(|ui| {
+                                    let mut enabled = area.enabled;
+                                    if ui.checkbox(&mut enabled, "").on_hover_text(t!("area.include_this_area_in_the_session")).changed() {
+                                        self.set_area_enabled(i, enabled);
+                                    }
 
-                                if ui.selectable_label(is_selected, area_text).clicked() {
+                                    let text = if area.enabled {
+                                        egui::RichText::new(area_text)
+                                    } else {
+                                        egui::RichText::new(area_text).weak()
+                                    };
+                                    ui.selectable_label(is_selected, text)
+                                }).inner;
+                                if is_selected && self.scroll_to_selected {
+                                    label.scroll_to_me(None);
+                                    self.scroll_to_selected = false;
+                                }
+                                if is_selected && self.list_has_focus {
+                                    ui.painter().rect_stroke(label.rect.expand(1.0), 2.0, Stroke::new(1.0, FOCUS_RING_COLOR), egui::epaint::StrokeKind::Outside);
+                                }
+                                if label.clicked() {
                                     self.selected_area_index = Some(i);
+                                    list_clicked = true;
+                                }
+
+                                if weighted {
+                                    self.weight_bar_ui(ui, &theme, i, weight, shares[i], total_weight);
+                                }
+
+                                for warning in area_warnings(&area.display_name(i)) {
+                                    ui.colored_label(theme.warning, warning);
                                 }
                             }
                         });
 
+                        // Clicking an area gives the list keyboard focus
+                        let list_response = ui.interact(list.inner_rect, list_id, egui::Sense::focusable_noninteractive());
+                        if list_clicked {
+                            ui.memory_mut(|m| m.request_focus(list_id));
+                        }
+                        self.list_has_focus = list_response.has_focus();
+
+                        if self.list_has_focus && self.pending_delete.is_none() {
+                            // Keep the arrow keys from moving focus to the next widget
+                            ui.memory_mut(|m| m.set_focus_lock_filter(list_id, egui::EventFilter { vertical_arrows: true, ..Default::default() }));
+
+                            let (up, down, enter, delete, insert) = ui.input(|i| (
+                                i.key_pressed(egui::Key::ArrowUp),
+                                i.key_pressed(egui::Key::ArrowDown),
+                                i.key_pressed(egui::Key::Enter),
+                                i.key_pressed(egui::Key::Delete),
+                                i.key_pressed(egui::Key::Insert),
+                            ));
+
+                            // Only the areas shown by the filter can be moved between
+                            if (up || down) && !visible.is_empty() {
+                                let position = self.selected_area_index.and_then(|index| visible.iter().position(|&i| i == index));
+                                let last = visible.len() - 1;
+                                let next = match position {
+                                    Some(p) if up => p.saturating_sub(1),
+                                    Some(p) => (p + 1).min(last),
+                                    None if up => last,
+                                    None => 0,
+                                };
+                                self.selected_area_index = Some(visible[next]);
+                                self.scroll_to_selected = true;
+                            }
+                            if enter {
+                                if let Some(index) = self.selected_area_index {
+                                    self.toggle_edit_form(index, &config);
+                                }
+                            }
+                            if delete {
+                                self.pending_delete = self.selected_area_index;
+                            }
+                            if insert {
+                                self.is_adding_area = true;
+                                self.new_area = ClickArea::default();
+                            }
+                        }
+
+                        if !config.multi_area.areas.is_empty() {
+                            ui.label(egui::RichText::new(t!("area.navigate_enter_edit_del_remove_ins")).small().weak());
+                        }
+
+                        if config.multi_area.selection_mode == AreaSelectionMode::Weighted && !config.multi_area.areas.is_empty() {
+                            ui.add_space(8.0);
+                            self.weight_pie_ui(ui, &config);
+                        }
+
                         ui.horizontal(|ui| {
-                            if ui.button("Add Area").clicked() {
+                            if ui.button(t!("area.add_area")).clicked() {
                                 self.is_adding_area = true;
                                 self.new_area = ClickArea::default();
                             }
 
-                            let delete_enabled = self.selected_area_index.is_some();
-                            if ui.add_enabled(delete_enabled, egui::Button::new("Remove")).clicked() {
+                            let has_selection = self.selected_area_index.is_some();
+                            if ui.add_enabled(has_selection, egui::Button::new(t!("area.edit"))).clicked() {
                                 if let Some(index) = self.selected_area_index {
-                                    let mut state = self.state.lock().unwrap();
-                                    if state.current_config.remove_click_area(index) {
-                                        self.selected_area_index = None;
-                                    }
+                                    self.toggle_edit_form(index, &config);
                                 }
                             }
 
-                            if ui.button("Clear All").clicked() {
+                            if ui.add_enabled(has_selection, egui::Button::new(t!("area.remove"))).clicked() {
+                                self.pending_delete = self.selected_area_index;
+                            }
+
+                            if ui.button(t!("area.clear_all")).clicked() {
                                 let mut state = self.state.lock().unwrap();
                                 state.current_config.clear_click_areas();
                                 self.selected_area_index = None;
+                                self.editing_area = None;
                             }
                         });
 
+                        self.edit_area_ui(ui, config.multi_area.selection_mode);
+                        self.delete_confirmation_ui(ui.ctx(), &config);
+
                         // Add new area dialog
                         if self.is_adding_area {
                             ui.add_space(10.0);
                             ui.separator();
-                            ui.heading("Add New Area");
+                            ui.heading(t!("area.add_new_area"));
+
+                            ui.horizontal(|ui| {
+                                ui.label(t!("area.name"));
+                                ui.add(egui::TextEdit::singleline(&mut self.new_area.name).hint_text(t!("area.optional")));
+                            });
 
                             ui.horizontal(|ui| {
-                                ui.label("Width:");
+                                ui.label(t!("area.width"));
                                 ui.add(egui::DragValue::new(&mut self.new_area.width).speed(1.0).range(10..=2000));
 
-                                ui.label("Height:");
+                                ui.label(t!("area.height"));
                                 ui.add(egui::DragValue::new(&mut self.new_area.height).speed(1.0).range(10..=2000));
                             });
 
-                            ui.checkbox(&mut self.new_area.centered, "Centered");
+                            anchor_selector(ui, &config.multi_area.areas, &mut self.new_area.anchor_area_name);
 
-                            if !self.new_area.centered {
+                            if self.new_area.anchor_area_name.is_some() {
+                                // Offsets from the anchor's last click can point either way
                                 ui.horizontal(|ui| {
-                                    ui.label("X Offset:");
-                                    ui.add(egui::DragValue::new(&mut self.new_area.x_offset).speed(1.0).range(0..=self.screen_width));
+                                    ui.label(t!("area.x_offset"));
+                                    ui.add(egui::DragValue::new(&mut self.new_area.x_offset).speed(1.0).range(-self.screen_width..=self.screen_width));
 
-                                    ui.label("Y Offset:");
-                                    ui.add(egui::DragValue::new(&mut self.new_area.y_offset).speed(1.0).range(0..=self.screen_height));
+                                    ui.label(t!("area.y_offset"));
+                                    ui.add(egui::DragValue::new(&mut self.new_area.y_offset).speed(1.0).range(-self.screen_height..=self.screen_height));
                                 });
+                            } else {
+                                monitor_selector(ui, "new_area_monitor", &self.monitors, &mut self.new_area.monitor_index);
+                                ui.checkbox(&mut self.new_area.centered, t!("area.centered"));
+
+                                if !self.new_area.centered {
+                                    ui.horizontal(|ui| {
+                                        ui.label(t!("area.x_offset"));
+                                        ui.add(egui::DragValue::new(&mut self.new_area.x_offset).speed(1.0).range(0..=self.screen_width));
+
+                                        ui.label(t!("area.y_offset"));
+                                        ui.add(egui::DragValue::new(&mut self.new_area.y_offset).speed(1.0).range(0..=self.screen_height));
+                                    });
+                                }
                             }
 
                             let mut weight = 1.0;
                             if config.multi_area.selection_mode == AreaSelectionMode::Weighted {
                                 ui.horizontal(|ui| {
-                                    ui.label("Weight:");
-                                    ui.add(egui::Slider::new(&mut weight, 0.01..=1.0).text("Weight"));
+                                    ui.label(t!("area.weight"));
+                                    ui.add(egui::Slider::new(&mut weight, 0.01..=1.0).text(t!("area.weight_2")));
                                 });
                             }
 
                             ui.horizontal(|ui| {
-                                if ui.button("Add").clicked() {
+                                if ui.button(t!("area.add")).clicked() {
                                     let mut state = self.state.lock().unwrap();
                                     state.current_config.add_click_area(self.new_area.clone(), weight);
                                     self.is_adding_area = false;
                                 }
 
-                                if ui.button("Cancel").clicked() {
+                                if ui.button(t!("area.cancel")).clicked() {
                                     self.is_adding_area = false;
                                 }
                             });
@@ -227,7 +1097,16 @@ impl AreaView {
 
             // Screen preview
             ui.vertical(|ui| {
-                ui.heading("Screen Preview");
+                ui.heading(t!("area.screen_preview"));
+
+                // Offsets are per monitor, so the canvas shows one monitor at a time
+                if monitor_selector(ui, "preview_monitor", &self.monitors, &mut self.preview_monitor) {
+                    self.update_screen_size();
+                    self.drag_start = None;
+                    self.current_drag = None;
+                }
+
+                self.window_preview_ui(ui, &theme);
 
                 let config = {
                     let state = self.state.lock().unwrap();
@@ -249,13 +1128,18 @@ impl AreaView {
                     0.0,
                     Color32::from_rgb(30, 30, 30)
                 );
+                self.draw_window_preview(&painter, response.rect);
 
                 // Scale factor for converting between screen and preview coordinates
                 let scale_x = preview_width / self.screen_width as f32;
                 let scale_y = preview_height / self.screen_height as f32;
 
+                // Areas are colored by the action they perform
+                let area_color = click_type_color(config.click_options.dominant_click_type());
+
                 // Draw primary area
                 let primary_area = &config.click_area;
+                let primary_visible = primary_area.monitor_index == self.preview_monitor;
                 let (x, y) = if primary_area.centered {
                     let center_x = self.screen_width / 2;
                     let center_y = self.screen_height / 2;
@@ -275,14 +1159,34 @@ impl AreaView {
                     )
                 );
                 // Draw the rectangle outline
-                painter.rect_filled(rect, 0.0, Color32::TRANSPARENT);
-                painter.rect_stroke(rect, 0.0, Stroke::new(2.0, Color32::GREEN), egui::epaint::StrokeKind::Middle);
+                if primary_visible {
+                    painter.rect_filled(rect, 0.0, Color32::TRANSPARENT);
+                    painter.rect_stroke(rect, 0.0, Stroke::new(2.0, area_color), egui::epaint::StrokeKind::Middle);
+                }
 
                 // Draw multiple areas if enabled
                 if config.multi_area.enabled {
                     for (i, (area, _)) in config.multi_area.areas.iter().enumerate() {
+                        if area.monitor_index != self.preview_monitor {
+                            continue;
+                        }
+
                         let is_selected = self.selected_area_index == Some(i);
-                        let color = if is_selected { Color32::YELLOW } else { Color32::BLUE };
+                        // The outline shows the click type; the fill tells areas apart, matching their slice of the weight chart
+                        let mut color = area_color;
+                        let mut fill = slice_color(i).gamma_multiply(0.25);
+                        let stroke_width = if is_selected { 4.0 } else { 2.0 };
+
+                        if !area.enabled {
+                            color = color.gamma_multiply(0.5);
+                            fill = fill.gamma_multiply(0.5);
+                        }
+
+                        // Fade out areas hidden by the list filter
+                        if !self.matches_search(area, i) {
+                            color = color.gamma_multiply(0.3);
+                            fill = fill.gamma_multiply(0.3);
+                        }
 
                         let (x, y) = if area.centered {
                             let center_x = self.screen_width / 2;
@@ -303,11 +1207,18 @@ impl AreaView {
                             )
                         );
                         // Draw the rectangle outline
-                        painter.rect_filled(rect, 0.0, Color32::TRANSPARENT);
-                        painter.rect_stroke(rect, 0.0, Stroke::new(2.0, color), egui::epaint::StrokeKind::Middle);
+                        painter.rect_filled(rect, 0.0, fill);
+                        if !area.enabled {
+                            hatch_rect(&painter, rect, color);
+                        }
+                        painter.rect_stroke(rect, 0.0, Stroke::new(stroke_width, color), egui::epaint::StrokeKind::Middle);
                     }
                 }
 
+                if self.show_heatmap {
+                    self.draw_heatmap(&painter, response.rect, &config, &theme);
+                }
+
                 // Handle drag to create new area
                 if response.dragged() {
                     if self.drag_start.is_none() {
@@ -339,6 +1250,8 @@ impl AreaView {
                             centered: false,
                             x_offset: min_x,
                             y_offset: min_y,
+                            monitor_index: self.preview_monitor,
+                            ..Default::default()
                         };
 
                         // Add the area
@@ -354,18 +1267,236 @@ impl AreaView {
                     self.current_drag = None;
                 }
 
+                ui.add_space(6.0);
+                click_type_legend(ui);
+
                 ui.add_space(10.0);
-                ui.label("Drag on the preview to create a new area");
-                ui.label(format!("Screen size: {}x{}", self.screen_width, self.screen_height));
-
-                if ui.button("Update Screen Size").clicked() {
-                    // Get screen size from enigo
-                    let enigo = enigo::Enigo::new();
-                    let screen_size = enigo.main_display_size();
-                    self.screen_width = screen_size.0 as i32;
-                    self.screen_height = screen_size.1 as i32;
+                ui.label(t!("area.drag_on_the_preview_to_create"));
+                ui.label(t!("area.screen_size", width = self.screen_width, height = self.screen_height));
+
+                if ui.button(t!("area.refresh_monitors")).clicked() {
+                    self.refresh_monitors();
+                }
+
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut self.show_heatmap, t!("area.show_click_heatmap"));
+                    if ui.add_enabled(!self.heatmap_points.is_empty(), egui::Button::new(t!("area.clear"))).clicked() {
+                        self.heatmap_points.clear();
+                    }
+                });
+                if self.show_heatmap && config.multi_area.enabled && config.multi_area.selection_mode == AreaSelectionMode::Weighted {
+                    ui.label(egui::RichText::new(t!("area.clicks_are_scaled_by_their_area")).small().weak());
                 }
             });
-        });
+        }));
+    }
+}
+
+/// Snap buttons for the primary area: label and position as halves of the free space on each axis
+const SNAP_POSITIONS: [(&str, i32, i32); 5] = [
+    ("Snap to Center", 1, 1),
+    ("Snap to Top-Left", 0, 0),
+    ("Snap to Top-Right", 2, 0),
+    ("Snap to Bottom-Left", 0, 2),
+    ("Snap to Bottom-Right", 2, 2),
+];
+
+/// Combo box for choosing a monitor; returns true when the selection changed
+/// Diagonal lines across a preview rectangle, marking a disabled area
+fn hatch_rect(painter: &egui::Painter, rect: Rect, color: Color32) {
+    const SPACING: f32 = 8.0;
+    let painter = painter.with_clip_rect(rect.intersect(painter.clip_rect()));
+    let stroke = Stroke::new(1.0, color);
+
+    let mut offset = -rect.height();
+    while offset < rect.width() {
+        let start = Pos2::new(rect.left() + offset, rect.bottom());
+        let end = Pos2::new(rect.left() + offset + rect.height(), rect.top());
+        painter.line_segment([start, end], stroke);
+        offset += SPACING;
+    }
+}
+
+fn monitor_selector(ui: &mut Ui, id: &str, monitors: &[MonitorInfo], monitor_index: &mut u32) -> bool {
+    // Nothing to choose between on a single-monitor setup
+    if monitors.len() < 2 && *monitor_index == 0 {
+        return false;
+    }
+
+    let selected_text = monitors
+        .get(*monitor_index as usize)
+        .map(|monitor| monitor.display_name(*monitor_index))
+        .unwrap_or_else(|| t!("area.monitor_disconnected", number = *monitor_index + 1));
+
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        ui.label(t!("area.monitor"));
+        egui::ComboBox::from_id_salt(id)
+            .selected_text(selected_text)
+            .show_ui(ui, |ui| {
+                for (index, monitor) in monitors.iter().enumerate() {
+                    let index = index as u32;
+                    if ui.selectable_value(monitor_index, index, monitor.display_name(index)).changed() {
+                        changed = true;
+                    }
+                }
+            });
+    });
+    changed
+}
+
+/// Combo box for choosing the named area a new area is placed relative to
+fn anchor_selector(ui: &mut Ui, areas: &[(ClickArea, f32)], anchor: &mut Option<String>) {
+    // Anchors are looked up by name, so unnamed areas can't be one
+    let names: Vec<&str> = areas.iter().map(|(area, _)| area.name.as_str()).filter(|name| !name.is_empty()).collect();
+    if names.is_empty() && anchor.is_none() {
+        return;
+    }
+
+    ui.horizontal(|ui| {
+        ui.label(t!("area.anchor"));
+        egui::ComboBox::from_id_salt("new_area_anchor")
+            .selected_text(anchor.as_deref().unwrap_or("None (screen position)"))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(anchor, None, t!("area.none_screen_position"));
+                for name in names {
+                    ui.selectable_value(anchor, Some(name.to_string()), name);
+                }
+            });
+    })
+    .response
+    .on_hover_text(t!("area.place_this_area_relative_to_wherever"));
+}
+
+/// Preview color for the areas performing a given click type
+fn click_type_color(click_type: ClickType) -> Color32 {
+    match click_type {
+        ClickType::Single => Color32::from_rgb(76, 175, 80),
+        ClickType::Double => Color32::from_rgb(255, 214, 0),
+        ClickType::Triple => Color32::from_rgb(244, 67, 54),
+        ClickType::Right => Color32::from_rgb(255, 152, 0),
+        ClickType::Middle => Color32::from_rgb(156, 39, 176),
+    }
+}
+
+/// Color of an area's slice in the weight pie chart, cycling through the click type colors
+fn slice_color(index: usize) -> Color32 {
+    click_type_color(ClickType::ALL[index % ClickType::ALL.len()])
+}
+
+/// Row of swatches explaining the preview colors
+fn click_type_legend(ui: &mut Ui) {
+    ui.horizontal_wrapped(|ui| {
+        for click_type in ClickType::ALL {
+            let (rect, _) = ui.allocate_exact_size(Vec2::splat(12.0), egui::Sense::hover());
+            ui.painter().rect_filled(rect, 2.0, click_type_color(click_type));
+            ui.label(format!("{:?}", click_type));
+            ui.add_space(6.0);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The 1×1 absolute area an imported click becomes
+    fn point(x: i32, y: i32) -> ClickArea {
+        ClickArea {
+            width: 1,
+            height: 1,
+            centered: false,
+            x_offset: x,
+            y_offset: y,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn neighbouring_areas_get_different_fills_and_click_types_different_outlines() {
+        for i in 0..ClickType::ALL.len() * 2 {
+            assert_ne!(slice_color(i), slice_color(i + 1));
+        }
+        for (i, a) in ClickType::ALL.iter().enumerate() {
+            for b in &ClickType::ALL[i + 1..] {
+                assert_ne!(click_type_color(*a), click_type_color(*b));
+            }
+        }
+    }
+
+    #[test]
+    fn ahk_click_with_commas() {
+        assert_eq!(AreaView::import_from_ahk("Click, 100, 200"), vec![point(100, 200)]);
+    }
+
+    #[test]
+    fn ahk_coordinates_need_a_separator() {
+        assert!(AreaView::import_from_ahk("Click 1005").is_empty());
+        assert!(AreaView::import_from_ahk("Click, 12").is_empty());
+        assert!(AreaView::import_from_ahk("Click100, 200").is_empty());
+    }
+
+    #[test]
+    fn ahk_click_after_a_hotkey() {
+        assert_eq!(AreaView::import_from_ahk("F1::Click, 100, 200"), vec![point(100, 200)]);
+        assert_eq!(AreaView::import_from_ahk("^!x:: MouseClick, left, 5, 6"), vec![point(5, 6)]);
+    }
+
+    #[test]
+    fn ahk_click_with_spaces() {
+        assert_eq!(AreaView::import_from_ahk("Click 100 200"), vec![point(100, 200)]);
+    }
+
+    #[test]
+    fn ahk_mouse_click_with_each_button() {
+        let script = "MouseClick, left, 10, 20\nMouseClick, right, 30, 40\nMouseClick, middle, 50, 60\nMouseClick,, 70, 80";
+        assert_eq!(
+            AreaView::import_from_ahk(script),
+            vec![point(10, 20), point(30, 40), point(50, 60), point(70, 80)]
+        );
+    }
+
+    #[test]
+    fn ahk_commands_are_case_insensitive_and_may_be_indented() {
+        let script = "  click, 1, 2\n\tCLICK 3 4\n    mouseclick, LEFT, 5, 6";
+        assert_eq!(AreaView::import_from_ahk(script), vec![point(1, 2), point(3, 4), point(5, 6)]);
+    }
+
+    #[test]
+    fn ahk_script_keeps_clicks_in_order_and_skips_everything_else() {
+        let script = "\
+#NoEnv
+F1::
+    MouseMove, 300, 400
+    Click, 100, 200
+    ; Click, 999, 999
+    Sleep, 500
+    Click
+    MouseClick, left, 640, 360
+    Send, {Click 5 5}
+return";
+        assert_eq!(AreaView::import_from_ahk(script), vec![point(100, 200), point(640, 360)]);
+    }
+
+    #[test]
+    fn coords_file_lines_become_areas_and_other_lines_are_skipped() {
+        let path = std::env::temp_dir().join(format!("mouse_coords_{}.csv", std::process::id()));
+        std::fs::write(&path, "# timestamp_ms,x,y\ntimestamp_ms,x,y\n0,640,360\n\n 1250 , 700 , 412 \n1300,oops,5\n1400,-20,30\n").unwrap();
+
+        let imported = AreaView::import_from_coords_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(imported, vec![(point(640, 360), 1.0), (point(700, 412), 1.0), (point(-20, 30), 1.0)]);
+    }
+
+    #[test]
+    fn missing_coords_file_is_an_error() {
+        assert!(AreaView::import_from_coords_file(Path::new("/no/such/dir/clicks.csv")).is_err());
+    }
+
+    #[test]
+    fn ahk_script_without_clicks_imports_nothing() {
+        assert!(AreaView::import_from_ahk("").is_empty());
+        assert!(AreaView::import_from_ahk("MouseMove, 300, 400\nSleep, 100").is_empty());
     }
 }
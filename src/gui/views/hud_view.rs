@@ -0,0 +1,137 @@
+use eframe::egui::{self, Context, RichText, Vec2, Pos2};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::Ordering;
+use std::time::Instant;
+
+use crate::gui::app::{AppState, ClickerStatus};
+use crate::modules::preferences::HudCorner;
+use crate::t;
+
+const HUD_SIZE: Vec2 = Vec2::new(220.0, 56.0);
+const HUD_MARGIN: f32 = 16.0;
+
+/// Small always-on-top status window shown while the main window is minimized
+pub struct HudView {
+    state: Arc<Mutex<AppState>>,
+}
+
+impl HudView {
+    pub fn new(state: Arc<Mutex<AppState>>) -> Self {
+        Self { state }
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        let (hud, repositioning, status, click_count, next_click_at, theme) = {
+            let state = self.state.lock().unwrap();
+            (
+                state.preferences.hud.clone(),
+                state.hud_repositioning,
+                state.clicker_status,
+                state.click_count.load(Ordering::Relaxed),
+                *state.next_click_at.load(),
+                state.theme.clone(),
+            )
+        };
+
+        if !hud.enabled {
+            return;
+        }
+
+        if hud.hide_when_stopped && status == ClickerStatus::Stopped && !repositioning {
+            return;
+        }
+
+        // Only show the HUD while the main window is out of the way, unless the user is placing it
+        let minimized = ctx.input(|i| i.viewport().minimized.unwrap_or(false));
+        if !minimized && !repositioning {
+            return;
+        }
+
+        let position = match hud.position {
+            Some((x, y)) => Pos2::new(x, y),
+            None => {
+                let monitor = ctx.input(|i| i.viewport().monitor_size).unwrap_or(Vec2::new(1920.0, 1080.0));
+                corner_position(hud.corner, monitor)
+            },
+        };
+
+        let builder = egui::ViewportBuilder::default()
+            .with_title("Mouse Clicker HUD")
+            .with_decorations(false)
+            .with_always_on_top()
+            .with_transparent(true)
+            .with_resizable(false)
+            .with_taskbar(false)
+            .with_active(false)  // Never take focus from the application being automated
+            .with_inner_size(HUD_SIZE)
+            .with_position(position)
+            .with_mouse_passthrough(!repositioning);
+
+        let moved_to = ctx.show_viewport_immediate(
+            egui::ViewportId::from_hash_of("hud_overlay"),
+            builder,
+            |ctx, _class| {
+                let frame = egui::Frame::new()
+                    .fill(theme.card_background.gamma_multiply(hud.opacity))
+                    .corner_radius(8.0)
+                    .inner_margin(8.0);
+
+                egui::CentralPanel::default().frame(frame).show(ctx, |ui| {
+                    ui.horizontal(|ui| {
+                        let dot_color = match status {
+                            ClickerStatus::Running => theme.success,
+                            ClickerStatus::Paused => theme.warning,
+                            ClickerStatus::Stopped => theme.muted_text,
+                        };
+                        let (dot_rect, _) = ui.allocate_exact_size(Vec2::splat(12.0), egui::Sense::hover());
+                        ui.painter().circle_filled(dot_rect.center(), 6.0, dot_color);
+
+                        ui.vertical(|ui| {
+                            ui.label(RichText::new(t!("hud.status_clicks", status = format!("{:?}", status), count = click_count)).strong());
+
+                            let countdown = match next_click_at {
+                                Some(at) if status == ClickerStatus::Running => {
+                                    let remaining = at.saturating_duration_since(Instant::now());
+                                    t!("hud.next_click_in", seconds = format!("{:.1}", remaining.as_secs_f32()))
+                                },
+                                _ => t!("hud.no_click_scheduled"),
+                            };
+                            ui.label(RichText::new(countdown).color(theme.muted_text).small());
+                        });
+                    });
+
+                    if repositioning {
+                        let response = ui.interact(ui.max_rect(), egui::Id::new("hud_drag"), egui::Sense::drag());
+                        if response.drag_started() {
+                            ctx.send_viewport_cmd(egui::ViewportCommand::StartDrag);
+                        }
+                    }
+                });
+
+                ctx.input(|i| i.viewport().outer_rect).map(|rect| rect.min)
+            },
+        );
+
+        // Remember where the user dragged the HUD to
+        if repositioning {
+            if let Some(pos) = moved_to {
+                if (pos - position).length() > 0.5 {
+                    let mut state = self.state.lock().unwrap();
+                    state.preferences.hud.position = Some((pos.x, pos.y));
+                }
+            }
+        }
+    }
+}
+
+fn corner_position(corner: HudCorner, monitor: Vec2) -> Pos2 {
+    let right = monitor.x - HUD_SIZE.x - HUD_MARGIN;
+    let bottom = monitor.y - HUD_SIZE.y - HUD_MARGIN;
+
+    match corner {
+        HudCorner::TopLeft => Pos2::new(HUD_MARGIN, HUD_MARGIN),
+        HudCorner::TopRight => Pos2::new(right, HUD_MARGIN),
+        HudCorner::BottomLeft => Pos2::new(HUD_MARGIN, bottom),
+        HudCorner::BottomRight => Pos2::new(right, bottom),
+    }
+}
@@ -1,13 +1,212 @@
-use eframe::egui::{self, RichText, Ui, TextureHandle, TextureOptions, Stroke};
+use eframe::egui::{self, Color32, RichText, Ui, TextureHandle, TextureOptions, Stroke};
 use eframe::epaint::CornerRadius;
 use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::thread::{self, JoinHandle};
 use std::path::PathBuf;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use std::collections::HashMap;
-use chrono;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
 
 use crate::gui::app::AppState;
-use crate::modules::image_recognition::{ImageLibrary, base64_to_image};
+use crate::gui::animations::{lerp_color, Animation};
+use crate::gui::components::{self, StatusMessageType};
+use crate::gui::theme::AppTheme;
+use crate::modules::clipboard;
+use crate::modules::paths;
+use crate::modules::dialogs;
+use crate::modules::error::AppError;
+use crate::modules::event_log::EventKind;
+use crate::modules::config::ClickType;
+use crate::modules::image_recognition::{ImageLibrary, base64_to_image, capture_screen, capture_screen_area, has_transparency, image_to_base64, new_target_id, ClickAlignment, TargetImage, DEFAULT_THRESHOLD};
+use image::{DynamicImage, RgbaImage};
+use crate::t;
+
+/// Number of screenshots used when auto-tuning a threshold, taken one second apart
+const AUTO_TUNE_SCREENSHOTS: u8 = 5;
+
+/// Preset thresholds offered as chips: label, threshold, chip color and the tradeoff it makes
+const THRESHOLD_PRESETS: [(&str, f32, Color32, &str); 3] = [
+    ("Strict", 0.92, Color32::from_rgb(76, 175, 80),
+        "Strict: fewer false positives, may miss the target if the UI renders slightly differently."),
+    ("Balanced", 0.80, Color32::from_rgb(255, 214, 0),
+        "Balanced: tolerates small rendering differences while rarely matching the wrong thing."),
+    ("Relaxed", 0.65, Color32::from_rgb(255, 152, 0),
+        "Relaxed: finds the target even when it looks noticeably different, but may match similar-looking areas."),
+];
+
+/// Preset searches reuse a screenshot this recent instead of capturing a new one
+const PRESET_SCREEN_MAX_AGE: Duration = Duration::from_secs(30);
+
+/// Order of the targets within each category of the target list
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TargetSortMode {
+    Name,
+    DateAdded,
+    LastFound,
+    ImageSize,
+}
+
+impl TargetSortMode {
+    const ALL: [TargetSortMode; 4] = [
+        TargetSortMode::Name,
+        TargetSortMode::DateAdded,
+        TargetSortMode::LastFound,
+        TargetSortMode::ImageSize,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            TargetSortMode::Name => "Name",
+            TargetSortMode::DateAdded => "Date Added",
+            TargetSortMode::LastFound => "Last Found",
+            TargetSortMode::ImageSize => "Image Size",
+        }
+    }
+}
+
+/// How the target list is laid out
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ImageViewMode {
+    /// One large card per target
+    List,
+    /// Fixed-size thumbnails with the name below, for large libraries
+    Grid,
+}
+
+/// Side length of a thumbnail in the grid view
+const THUMBNAIL_SIZE: f32 = 120.0;
+
+/// Horizontal space each grid cell takes, thumbnail plus spacing
+const GRID_CELL_WIDTH: f32 = 136.0;
+
+/// Height of the name under a thumbnail
+const THUMBNAIL_LABEL_HEIGHT: f32 = 18.0;
+
+/// What the view remembers about a target between frames
+#[derive(Default)]
+struct TargetState {
+    last_found: Option<Instant>,
+    /// Whether the image has transparent pixels, worked out the first time it's needed
+    has_transparency: Option<bool>,
+}
+
+/// When a target was created, from the timestamp in its `target_<millis>` id
+fn target_added_at(target: &TargetImage) -> i64 {
+    target.id
+        .strip_prefix("target_")
+        .and_then(|millis| millis.parse().ok())
+        .unwrap_or(0)
+}
+
+/// Buttons offered for clicking a search result
+const RESULT_CLICK_TYPES: [ClickType; 4] = [ClickType::Single, ClickType::Right, ClickType::Double, ClickType::Middle];
+
+fn result_click_label(click_type: ClickType) -> &'static str {
+    match click_type {
+        ClickType::Single => "Left Click",
+        ClickType::Right => "Right Click",
+        ClickType::Double => "Double Click",
+        ClickType::Middle => "Middle Click",
+        ClickType::Triple => "Triple Click",
+    }
+}
+
+/// Tooltip for a target in the list saying how long ago it was last searched for
+fn last_used_text(target: &TargetImage) -> String {
+    match target.last_used {
+        Some(used) => match (chrono::Utc::now() - used).num_days() {
+            0 => t!("image.last_used_today"),
+            1 => t!("image.last_used_1_day_ago"),
+            days => t!("image.last_used_days_ago", days = days),
+        },
+        None => t!("image.never_used"),
+    }
+}
+
+/// A threshold auto-tune running in the background
+struct AutoTuneJob {
+    target_id: String,
+    started: Instant,
+    receiver: Receiver<crate::modules::error::Result<f32>>,
+}
+
+/// Widest frame the live view sends; larger screens are scaled down in the capture thread
+const LIVE_FRAME_MAX_WIDTH: u32 = 960;
+
+/// Match rectangles pulse between full and faint color once per this many seconds
+const MATCH_PULSE_SECS: f32 = 1.0;
+
+/// Color of the rectangle drawn around a match
+const MATCH_BOX_COLOR: Color32 = Color32::from_rgb(255, 165, 0);
+
+/// Screen pixels kept around a match in the still capture shown without the live view
+const MATCH_CAPTURE_MARGIN: i32 = 48;
+
+/// A downscaled screenshot from the live view thread
+struct LiveFrame {
+    image: RgbaImage,
+    /// Size of the screen it was taken from, for mapping screen coordinates onto it
+    screen_size: (u32, u32),
+}
+
+/// Background thread capturing the screen for the live view
+struct LiveCapture {
+    should_stop: Arc<AtomicBool>,
+    fps: Arc<AtomicU8>,
+    receiver: Receiver<LiveFrame>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl LiveCapture {
+    fn start(fps: u8) -> Self {
+        let should_stop = Arc::new(AtomicBool::new(false));
+        let fps = Arc::new(AtomicU8::new(fps));
+        let (sender, receiver) = mpsc::sync_channel(1);
+
+        let stop_flag = Arc::clone(&should_stop);
+        let thread_fps = Arc::clone(&fps);
+        let handle = thread::spawn(move || {
+            while !stop_flag.load(Ordering::SeqCst) {
+                let started = Instant::now();
+
+                match capture_screen() {
+                    Ok(screen) => {
+                        let screen_size = screen.dimensions();
+                        let image = if screen_size.0 > LIVE_FRAME_MAX_WIDTH {
+                            let height = screen_size.1 * LIVE_FRAME_MAX_WIDTH / screen_size.0;
+                            image::imageops::resize(&screen, LIVE_FRAME_MAX_WIDTH, height, image::imageops::FilterType::Triangle)
+                        } else {
+                            screen
+                        };
+
+                        // Drop the frame if the last one hasn't been shown yet
+                        if let Err(mpsc::TrySendError::Disconnected(_)) = sender.try_send(LiveFrame { image, screen_size }) {
+                            break;
+                        }
+                    },
+                    Err(e) => eprintln!("Live view capture failed: {}", e),
+                }
+
+                let interval = Duration::from_secs_f32(1.0 / thread_fps.load(Ordering::Relaxed).max(1) as f32);
+                if let Some(remaining) = interval.checked_sub(started.elapsed()) {
+                    thread::sleep(remaining);
+                }
+            }
+        });
+
+        Self { should_stop, fps, receiver, handle: Some(handle) }
+    }
+}
+
+impl Drop for LiveCapture {
+    fn drop(&mut self) {
+        self.should_stop.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
 
 pub struct ImageView {
     state: Arc<Mutex<AppState>>,
@@ -21,17 +220,49 @@ pub struct ImageView {
     target_textures: HashMap<String, TextureHandle>,  // Store textures for each target
     last_search_result: Option<(i32, i32)>,
     last_search_time: Option<Instant>,
+    transfer_path: String,
+    transfer_status: Option<(String, StatusMessageType)>,
+    png_export_path: String,
+    png_export_status: Option<(String, StatusMessageType)>,
+    auto_tune: Option<AutoTuneJob>,
+    auto_tune_status: Option<(String, StatusMessageType)>,
+    target_name: String,
+    target_name_error: Option<String>,
+    /// Why the last Save Target failed, other than the name being invalid
+    target_save_error: Option<AppError>,
+    new_category: String,
+    cached_screen: Option<(Instant, DynamicImage)>,
+    preset_status: Option<(String, StatusMessageType)>,
+    aspect_lock: bool,
+    aspect_ratio: f32, // width / height
+    sort_mode: TargetSortMode,
+    sort_reversed: bool,
+    per_target_state: HashMap<String, TargetState>,
+    jpeg_quality: u8,
+    compress_status: Option<(String, StatusMessageType)>,
+    clipboard_status: Option<(String, StatusMessageType)>,
+    original_capture: Option<DynamicImage>,  // Selected area as first captured, restored by Reset Crop
+    captured_image: Option<DynamicImage>,  // Selected area as it will be saved
+    crop_rect: Option<egui::Rect>,  // Pending crop, in captured image pixels
+    prune_days: u32,
+    result_click_type: ClickType,  // Button used by "Click at this position"
+    prune_status: Option<(String, StatusMessageType)>,
+    view_mode: ImageViewMode,
+    live_capture: Option<LiveCapture>,  // Running while Live View is on
+    live_texture: Option<TextureHandle>,
+    live_screen_size: (u32, u32),
+    preview_fps: u8,
+    match_pulse: Animation,
+    /// Still capture around the last match and its top-left screen position, for when the live view is off
+    match_capture: Option<(TextureHandle, (i32, i32))>,
+    /// Whether a run was going when the view was last drawn
+    run_active: bool,
 }
 
 impl ImageView {
     pub fn new(state: Arc<Mutex<AppState>>) -> Self {
         // Create the image library
-        let targets_dir = PathBuf::from("targets");
-        if !targets_dir.exists() {
-            std::fs::create_dir_all(&targets_dir).expect("Failed to create targets directory");
-        }
-
-        let mut image_library = ImageLibrary::new("targets");
+        let mut image_library = ImageLibrary::new(paths::targets_dir());
         let _ = image_library.load_targets(); // Ignore errors on initial load
 
         Self {
@@ -46,10 +277,73 @@ impl ImageView {
             target_textures: HashMap::new(),
             last_search_result: None,
             last_search_time: None,
+            transfer_path: String::new(),
+            transfer_status: None,
+            png_export_path: String::new(),
+            png_export_status: None,
+            auto_tune: None,
+            auto_tune_status: None,
+            target_name: String::new(),
+            target_name_error: None,
+            target_save_error: None,
+            new_category: String::new(),
+            cached_screen: None,
+            preset_status: None,
+            aspect_lock: false,
+            aspect_ratio: 1.0,
+            sort_mode: TargetSortMode::Name,
+            sort_reversed: false,
+            per_target_state: HashMap::new(),
+            jpeg_quality: 85,
+            compress_status: None,
+            clipboard_status: None,
+            original_capture: None,
+            captured_image: None,
+            crop_rect: None,
+            prune_days: 30,
+            result_click_type: ClickType::Single,
+            prune_status: None,
+            view_mode: ImageViewMode::List,
+            live_capture: None,
+            live_texture: None,
+            live_screen_size: (0, 0),
+            preview_fps: 2,
+            match_pulse: Animation::new(MATCH_PULSE_SECS),
+            match_capture: None,
+            run_active: false,
         }
     }
 
+    /// Selection rectangle from the drag start to the pointer, shrunk along one side when the aspect ratio is locked
+    fn selection_rect(&self, start: egui::Pos2, current: egui::Pos2) -> egui::Rect {
+        if !self.aspect_lock {
+            return egui::Rect::from_two_pos(start, current);
+        }
+
+        let delta = current - start;
+        let mut width = delta.x.abs();
+        let mut height = delta.y.abs();
+
+        // Clamp whichever dimension is too long for the ratio
+        if height > 0.0 && width / height > self.aspect_ratio {
+            width = height * self.aspect_ratio;
+        } else {
+            height = width / self.aspect_ratio;
+        }
+
+        // Keep the rectangle growing in the direction of the drag
+        let end = start + egui::vec2(width * delta.x.signum(), height * delta.y.signum());
+        egui::Rect::from_two_pos(start, end)
+    }
+
     pub fn ui(&mut self, ui: &mut Ui) {
+        // A run searches with its own copy of the library, so its scores are read back once it ends
+        let run_active = self.state.lock().unwrap().is_config_locked();
+        if self.run_active && !run_active {
+            self.image_library.lock().unwrap().reload_match_logs();
+        }
+        self.run_active = run_active;
+
         // Handle area selection if active
         if self.is_selecting_area {
             let input = ui.ctx().input(|i| i.clone());
@@ -87,15 +381,17 @@ impl ImageView {
                     if let Some(pos) = input.pointer.interact_pos() {
                         if let Some(start) = self.selection_start {
                             // Calculate the rectangle
-                            let min_x = start.x.min(pos.x) as i32;
-                            let min_y = start.y.min(pos.y) as i32;
-                            let width = (start.x - pos.x).abs() as u32;
-                            let height = (start.y - pos.y).abs() as u32;
+                            let rect = self.selection_rect(start, pos);
+                            let min_x = rect.min.x as i32;
+                            let min_y = rect.min.y as i32;
+                            let width = rect.width() as u32;
+                            let height = rect.height() as u32;
 
                             // Only create a selection if it has some size
                             if width > 5 && height > 5 {
                                 // Store the selection coordinates relative to the screen
                                 self.screenshot_area = Some((min_x, min_y, width, height));
+                                self.clear_capture();
                                 println!("Selection completed: {:?}", self.screenshot_area);
 
                                 // Show message in console
@@ -120,7 +416,7 @@ impl ImageView {
                 // Draw the current selection rectangle if we're in the process of selecting
                 if let Some(start) = self.selection_start {
                     if let Some(current_pos) = input.pointer.hover_pos() {
-                        let rect = egui::Rect::from_two_pos(start, current_pos);
+                        let rect = self.selection_rect(start, current_pos);
 
                         // Paint the selection rectangle on screen
                         let painter = ui.painter();
@@ -128,14 +424,15 @@ impl ImageView {
                         painter.rect_filled(rect, 0.0, egui::Color32::from_rgba_premultiplied(255, 165, 0, 30));
 
                         // Show the dimensions as text
-                        let width = (current_pos.x - start.x).abs() as u32;
-                        let height = (current_pos.y - start.y).abs() as u32;
-                        let text = format!("{} x {}", width, height);
+                        let width = rect.width() as u32;
+                        let height = rect.height() as u32;
+                        let text = if self.aspect_lock {
+                            t!("image.selection_size_locked", width = width, height = height, ratio = format!("{:.2}", self.aspect_ratio))
+                        } else {
+                            format!("{} x {}", width, height)
+                        };
 
-                        let text_pos = egui::pos2(
-                            (start.x + current_pos.x) / 2.0,
-                            (start.y + current_pos.y) / 2.0
-                        );
+                        let text_pos = rect.center();
 
                         painter.text(
                             text_pos,
@@ -168,11 +465,11 @@ impl ImageView {
         };
 
         // Main header
-        ui.heading(RichText::new("Image Recognition").size(24.0));
+        ui.heading(RichText::new(t!("image.image_recognition")).size(24.0));
 
         // Add a brief explanation with better styling
         ui.add_space(4.0);
-        ui.label(RichText::new("This feature allows you to capture and recognize images on your screen.").italics());
+        ui.label(RichText::new(t!("image.this_feature_allows_you_to_capture")).italics());
         ui.add_space(12.0);
 
         // Use a split layout with proper spacing
@@ -187,7 +484,11 @@ impl ImageView {
                 .default_width(250.0)
                 .max_width(350.0)
                 .show_inside(ui, |ui| {
-                    ui.heading(RichText::new("Target Images").strong());
+                    egui::TopBottomPanel::bottom("live_screen_panel")
+                        .resizable(false)
+                        .show_inside(ui, |ui| self.live_view_ui(ui));
+
+                    ui.heading(RichText::new(t!("image.target_images")).strong());
                     ui.add_space(8.0);
                     self.target_list_ui(ui);
                 });
@@ -199,13 +500,13 @@ impl ImageView {
                     .min_height(150.0)
                     .max_height(panel_height * 0.4)
                     .show_inside(ui, |ui| {
-                        ui.heading(RichText::new("Target Details").strong());
+                        ui.heading(RichText::new(t!("image.target_details")).strong());
                         ui.add_space(8.0);
                         self.target_details_ui(ui);
                     });
 
                 egui::CentralPanel::default().show_inside(ui, |ui| {
-                    ui.heading(RichText::new("Preview").strong());
+                    ui.heading(RichText::new(t!("image.preview")).strong());
                     ui.add_space(8.0);
                     self.preview_ui(ui);
                 });
@@ -222,33 +523,78 @@ impl ImageView {
                 .show(ui, |ui| {
                     // Capture Area column
                     ui.vertical(|ui| {
-                        ui.heading(RichText::new("Capture Area").strong());
+                        ui.heading(RichText::new(t!("image.capture_area")).strong());
                         ui.add_space(8.0);
                         self.capture_area_ui(ui);
                     });
 
                     // Search Results column
                     ui.vertical(|ui| {
-                        ui.heading(RichText::new("Search Results").strong());
+                        ui.heading(RichText::new(t!("image.search_results")).strong());
                         ui.add_space(8.0);
 
                         if let Some(time) = self.last_search_time {
-                            ui.label(RichText::new(format!("Last search: {:.2}s ago", time.elapsed().as_secs_f32())).monospace());
+                            ui.label(RichText::new(t!("image.last_search_ago", seconds = format!("{:.2}", time.elapsed().as_secs_f32()))).monospace());
                             ui.add_space(4.0);
                         }
 
                         if let Some((x, y)) = self.last_search_result {
-                            ui.label(RichText::new(format!("Found at: ({}, {})", x, y)).strong());
+                            ui.label(RichText::new(t!("image.found_at", x = x, y = y)).strong());
                             ui.add_space(8.0);
 
-                            // Make the button green to stand out
-                            if theme.secondary_button(ui, "Click at this position") {
-                                // Use the mouse module to click at this position
+                            // The live view already outlines the match
+                            if self.live_capture.is_none() {
+                                self.match_capture_ui(ui);
+                                ui.add_space(8.0);
+                            }
+
+                            let mut clicked = false;
+                            let mut go_to = false;
+                            ui.horizontal(|ui| {
+                                go_to = ui.button(t!("image.go_to"))
+                                    .on_hover_text(t!("image.move_the_cursor_to_the_match"))
+                                    .clicked();
+
+                                // Make the button green to stand out
+                                clicked = theme.secondary_button(ui, &t!("image.click_at_this_position"));
+
+                                egui::ComboBox::from_id_salt("result_click_type")
+                                    .selected_text(result_click_label(self.result_click_type))
+                                    .width(110.0)
+                                    .show_ui(ui, |ui| {
+                                        for click_type in RESULT_CLICK_TYPES {
+                                            ui.selectable_value(&mut self.result_click_type, click_type, result_click_label(click_type));
+                                        }
+                                    });
+                            });
+
+                            if go_to {
+                                let config = self.state.lock().unwrap().session_config();
+                                if let Err(e) = crate::modules::mouse::simulate_human_movement(
+                                    &mut enigo::Enigo::new(),
+                                    x,
+                                    y,
+                                    &config.mouse_movement,
+                                    config.dry_run,
+                                    &mut rand::thread_rng()
+                                ) {
+                                    eprintln!("Failed to move mouse: {}", e);
+                                }
+                            }
+
+                            if clicked {
+                                // Use the profile's movement and press durations, but the button picked here
+                                let mut config = self.state.lock().unwrap().session_config();
+                                config.click_options.click_type = self.result_click_type;
+                                config.click_options.randomize_click_type = false;
+
                                 let mut enigo = enigo::Enigo::new();
                                 if let Err(e) = crate::modules::mouse::simulate_human_movement(
                                     &mut enigo,
                                     x,
                                     y,
+                                    &config.mouse_movement,
+                                    config.dry_run,
                                     &mut rand::thread_rng()
                                 ) {
                                     eprintln!("Failed to move mouse: {}", e);
@@ -257,16 +603,16 @@ impl ImageView {
                                     if let Err(e) = crate::modules::mouse::human_like_click(
                                         &mut enigo,
                                         &mut rand::thread_rng(),
-                                        &self.state.lock().unwrap().current_config
+                                        &config
                                     ) {
                                         eprintln!("Failed to click: {}", e);
                                     }
                                 }
                             }
                         } else if self.selected_target_id.is_some() {
-                            ui.label(RichText::new("Target not found on screen").italics());
+                            ui.label(RichText::new(t!("image.target_not_found_on_screen")).italics());
                         } else {
-                            ui.label(RichText::new("Select a target and click 'Find on Screen'").italics());
+                            ui.label(RichText::new(t!("image.select_a_target_and_click_find")).italics());
                         }
                     });
 
@@ -275,6 +621,240 @@ impl ImageView {
         });
     }
 
+    /// Sort targets for display according to the chosen mode and direction
+    fn sort_targets(&self, targets: &mut [&TargetImage]) {
+        match self.sort_mode {
+            TargetSortMode::Name => targets.sort_by_key(|target| target.name.to_lowercase()),
+            TargetSortMode::DateAdded => targets.sort_by_key(|target| target_added_at(target)),
+            // Most recently found first, targets never found last
+            TargetSortMode::LastFound => targets.sort_by_key(|target| {
+                std::cmp::Reverse(self.per_target_state.get(&target.id).and_then(|state| state.last_found))
+            }),
+            TargetSortMode::ImageSize => targets.sort_by_key(|target| target.image_data.len()),
+        }
+
+        if self.sort_reversed {
+            targets.reverse();
+        }
+    }
+
+    fn sort_controls_ui(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(t!("image.sort_by"));
+            egui::ComboBox::from_id_salt("target_sort_mode")
+                .selected_text(self.sort_mode.label())
+                .show_ui(ui, |ui| {
+                    for mode in TargetSortMode::ALL {
+                        ui.selectable_value(&mut self.sort_mode, mode, mode.label());
+                    }
+                });
+
+            let arrow = if self.sort_reversed { "⬆" } else { "⬇" };
+            if ui.small_button(arrow).on_hover_text(t!("image.reverse_order")).clicked() {
+                self.sort_reversed = !self.sort_reversed;
+            }
+
+            ui.separator();
+            ui.selectable_value(&mut self.view_mode, ImageViewMode::List, t!("image.list_view"));
+            ui.selectable_value(&mut self.view_mode, ImageViewMode::Grid, t!("image.grid_view"));
+        });
+    }
+
+    /// Live View toggle and the latest frame, with the last match outlined
+    fn live_view_ui(&mut self, ui: &mut Ui) {
+        let theme = self.state.lock().unwrap().theme.clone();
+
+        ui.add_space(8.0);
+        ui.label(RichText::new(t!("image.live_screen")).strong());
+
+        ui.horizontal(|ui| {
+            let mut live = self.live_capture.is_some();
+            if ui.checkbox(&mut live, t!("image.live_view")).changed() {
+                if live {
+                    self.live_capture = Some(LiveCapture::start(self.preview_fps));
+                } else {
+                    // Dropping the capture stops its thread
+                    self.live_capture = None;
+                    self.live_texture = None;
+                }
+            }
+
+            if ui.add(egui::DragValue::new(&mut self.preview_fps).range(1..=10).suffix(" fps")).changed() {
+                if let Some(capture) = &self.live_capture {
+                    capture.fps.store(self.preview_fps, Ordering::Relaxed);
+                }
+            }
+        });
+
+        let Some(capture) = &self.live_capture else { return };
+
+        if let Some(frame) = capture.receiver.try_iter().last() {
+            let size = [frame.image.width() as usize, frame.image.height() as usize];
+            let color_image = egui::ColorImage::from_rgba_unmultiplied(size, frame.image.as_flat_samples().as_slice());
+            match &mut self.live_texture {
+                Some(texture) => texture.set(color_image, TextureOptions::LINEAR),
+                None => self.live_texture = Some(ui.ctx().load_texture("live_screen", color_image, TextureOptions::LINEAR)),
+            }
+            self.live_screen_size = frame.screen_size;
+        }
+
+        // Keep polling for frames
+        ui.ctx().request_repaint_after(Duration::from_secs_f32(1.0 / self.preview_fps.max(1) as f32));
+
+        let Some(texture) = &self.live_texture else {
+            ui.label(RichText::new(t!("image.waiting_for_the_first_frame")).small().weak());
+            return;
+        };
+
+        let image_size = texture.size_vec2();
+        let width = ui.available_width();
+        let response = ui.add(egui::Image::new(texture).fit_to_exact_size(egui::vec2(width, width * image_size.y / image_size.x)));
+
+        let last_match = self.image_library.lock().unwrap().last_match();
+        if let Some(found) = last_match.filter(|_| self.live_screen_size.0 > 0) {
+            let scale = response.rect.width() / self.live_screen_size.0 as f32;
+            self.draw_match_box(ui, response.rect, (0, 0), scale, found);
+        }
+
+        ui.label(RichText::new(t!("image.live_view_size_fps", width = self.live_screen_size.0, height = self.live_screen_size.1, fps = self.preview_fps))
+            .small()
+            .color(theme.muted_text));
+    }
+
+    /// Outline a match of (x, y, width, height) screen pixels on an image of the screen that starts
+    /// at `origin` and is drawn at `scale` points per pixel. The outline pulses while it's shown.
+    fn draw_match_box(&mut self, ui: &Ui, image_rect: egui::Rect, origin: (i32, i32), scale: f32, found: (i32, i32, u32, u32)) {
+        if self.match_pulse.is_completed() {
+            self.match_pulse.reset();
+        }
+        ui.ctx().request_repaint_after(Duration::from_millis(16));
+
+        let (x, y, width, height) = found;
+        let rect = egui::Rect::from_min_size(
+            image_rect.min + egui::vec2((x - origin.0) as f32, (y - origin.1) as f32) * scale,
+            egui::vec2(width as f32, height as f32) * scale,
+        );
+
+        // Fade down and back up once per cycle
+        let t = 1.0 - (2.0 * self.match_pulse.progress() - 1.0).abs();
+        let color = lerp_color(MATCH_BOX_COLOR.gamma_multiply(0.25), MATCH_BOX_COLOR, t);
+        ui.painter().with_clip_rect(image_rect)
+            .rect_stroke(rect, 0.0, Stroke::new(2.0, color), egui::StrokeKind::Outside);
+    }
+
+    /// Capture the screen around a match, so it can be shown when the live view is off
+    fn capture_match(&mut self, ctx: &egui::Context, found: (i32, i32, u32, u32)) {
+        let (x, y, width, height) = found;
+        let origin = ((x - MATCH_CAPTURE_MARGIN).max(0), (y - MATCH_CAPTURE_MARGIN).max(0));
+        let capture_width = (x - origin.0) as u32 + width + MATCH_CAPTURE_MARGIN as u32;
+        let capture_height = (y - origin.1) as u32 + height + MATCH_CAPTURE_MARGIN as u32;
+
+        self.match_capture = match capture_screen_area(origin.0, origin.1, capture_width, capture_height) {
+            Ok(image) => {
+                let size = [image.width() as usize, image.height() as usize];
+                let color_image = egui::ColorImage::from_rgba_unmultiplied(size, image.as_flat_samples().as_slice());
+                Some((ctx.load_texture("match_capture", color_image, TextureOptions::LINEAR), origin))
+            },
+            Err(e) => {
+                eprintln!("Failed to capture the match area: {}", e);
+                None
+            },
+        };
+    }
+
+    /// The still capture of the last match with the match outlined
+    fn match_capture_ui(&mut self, ui: &mut Ui) {
+        let Some((texture, origin)) = self.match_capture.clone() else { return };
+        let Some(found) = self.image_library.lock().unwrap().last_match() else { return };
+
+        let image_size = texture.size_vec2();
+        let width = ui.available_width().min(image_size.x);
+        let response = ui.add(egui::Image::new(&texture).fit_to_exact_size(egui::vec2(width, width * image_size.y / image_size.x)));
+        self.draw_match_box(ui, response.rect, origin, response.rect.width() / image_size.x, found);
+    }
+
+    /// The cached texture for a target's image, loading it on first use
+    fn target_texture(&mut self, ctx: &egui::Context, target: &TargetImage) -> Option<TextureHandle> {
+        if let Some(texture) = self.target_textures.get(&target.id) {
+            return Some(texture.clone());
+        }
+
+        let image = match base64_to_image(&target.image_data) {
+            Ok(image) => image.to_rgba8(),
+            Err(e) => {
+                eprintln!("Failed to load image for target {}: {}", target.id, e);
+                return None;
+            },
+        };
+        let size = [image.width() as usize, image.height() as usize];
+        let color_image = egui::ColorImage::from_rgba_unmultiplied(size, image.as_flat_samples().as_slice());
+        let texture = ctx.load_texture(format!("target_{}", target.id), color_image, TextureOptions::default());
+        self.target_textures.insert(target.id.clone(), texture.clone());
+        Some(texture)
+    }
+
+    /// Targets of one category as a grid of thumbnails; clicking one selects it
+    fn target_grid_ui(&mut self, ui: &mut Ui, theme: &AppTheme, category: &str, targets: &[&TargetImage]) {
+        let columns = ((ui.available_width() / GRID_CELL_WIDTH) as usize).max(1);
+
+        egui::Grid::new(("target_grid", category))
+            .num_columns(columns)
+            .spacing([GRID_CELL_WIDTH - THUMBNAIL_SIZE, 8.0])
+            .show(ui, |ui| {
+                for (i, target) in targets.iter().enumerate() {
+                    let is_selected = self.selected_target_id.as_deref() == Some(target.id.as_str());
+                    let texture = self.target_texture(ui.ctx(), target);
+
+                    let (rect, response) = ui.allocate_exact_size(
+                        egui::vec2(THUMBNAIL_SIZE, THUMBNAIL_SIZE + THUMBNAIL_LABEL_HEIGHT),
+                        egui::Sense::click(),
+                    );
+                    let thumbnail_rect = egui::Rect::from_min_size(rect.min, egui::Vec2::splat(THUMBNAIL_SIZE));
+                    let painter = ui.painter();
+
+                    let fill = if is_selected { theme.selected } else if response.hovered() { theme.card_background } else { theme.panel_background };
+                    let stroke = Stroke::new(1.0, if is_selected { theme.primary } else { theme.border });
+                    painter.rect_filled(thumbnail_rect, 4.0, fill);
+                    painter.rect_stroke(thumbnail_rect, 4.0, stroke, egui::StrokeKind::Inside);
+
+                    match &texture {
+                        Some(texture) => {
+                            // Scale down to fit, but never blow small targets up
+                            let size = texture.size_vec2();
+                            let scale = ((THUMBNAIL_SIZE - 8.0) / size.x.max(size.y)).min(1.0);
+                            let image_rect = egui::Rect::from_center_size(thumbnail_rect.center(), size * scale);
+                            let uv = egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0));
+                            painter.image(texture.id(), image_rect, uv, Color32::WHITE);
+                        },
+                        None => {
+                            painter.text(thumbnail_rect.center(), egui::Align2::CENTER_CENTER, "?", egui::FontId::proportional(24.0), theme.warning);
+                        },
+                    }
+
+                    let name_galley = painter.layout(
+                        target.name.clone(),
+                        egui::FontId::proportional(12.0),
+                        if is_selected { theme.primary } else { theme.text },
+                        THUMBNAIL_SIZE,
+                    );
+                    let name_pos = egui::pos2(rect.center().x - name_galley.size().x / 2.0, thumbnail_rect.bottom() + 2.0);
+                    painter.with_clip_rect(rect).galley(name_pos, name_galley, theme.text);
+
+                    if is_selected {
+                        self.preview_texture = texture;
+                    }
+
+                    if response.on_hover_text(format!("{}\n{}", target.name, last_used_text(target))).clicked() {
+                        self.selected_target_id = Some(target.id.clone());
+                    }
+
+                    if (i + 1) % columns == 0 {
+                        ui.end_row();
+                    }
+                }
+            });
+    }
+
     fn target_list_ui(&mut self, ui: &mut Ui) {
         // Get the theme for consistent styling
         let theme = {
@@ -282,112 +862,135 @@ impl ImageView {
             state.theme.clone()
         };
 
-        let image_library = self.image_library.lock().unwrap();
+        // Held through a local handle so the list can call back into the view while it's locked
+        let library = Arc::clone(&self.image_library);
+        let image_library = library.lock().unwrap();
         let targets = image_library.get_targets();
 
         // Show a message if no targets are available
         if targets.is_empty() {
             ui.vertical_centered(|ui| {
                 ui.add_space(20.0);
-                ui.label(RichText::new("No target images available.").strong());
+                ui.label(RichText::new(t!("image.no_target_images_available")).strong());
                 ui.add_space(4.0);
-                ui.label(RichText::new("Click 'New Target' to create one.").italics());
+                ui.label(RichText::new(t!("image.click_new_target_to_create_one")).italics());
                 ui.add_space(20.0);
             });
         } else {
+            self.sort_controls_ui(ui);
+            ui.add_space(4.0);
+
             // Create a scrollable area with fixed height
             egui::ScrollArea::vertical()
                 .auto_shrink([false, false])
                 .show(ui, |ui| {
-                    for target in targets {
-                        let is_selected = self.selected_target_id.as_ref().map_or(false, |id| id == &target.id);
-
-                        // Create a frame for each target for better visual separation
-                        let frame = egui::Frame::new()
-                            .fill(if is_selected { theme.selected } else { theme.panel_background })
-                            .stroke(Stroke::new(1.0, if is_selected { theme.primary } else { theme.border }))
-                            .corner_radius(4.0)
-                            .inner_margin(8.0)
-                            .outer_margin(4.0);
-
-                        frame.show(ui, |ui| {
-                            // Use a vertical layout for better organization
-                            ui.vertical(|ui| {
-                                // Target name as header
-                                let text_color = if is_selected { theme.primary } else { theme.text };
-                                ui.label(RichText::new(&target.name).color(text_color).strong().size(16.0));
-
-                                ui.add_space(4.0);
-
-                                // Show a preview for each target
-                                ui.label(RichText::new("Image data length: ").italics().size(10.0).color(theme.muted_text));
-                                ui.label(RichText::new(format!("{} bytes", target.image_data.len())).italics().size(10.0).color(theme.muted_text));
+                    let groups = image_library.get_targets_by_category();
+                    for category in image_library.categories() {
+                        let Some(targets) = groups.get(&category) else { continue };
+                        let mut targets = targets.clone();
+                        self.sort_targets(&mut targets);
+                        egui::CollapsingHeader::new(format!("{} ({})", category, targets.len()))
+                            .id_salt(("target_category", &category))
+                            .default_open(true)
+                            .show(ui, |ui| {
+                                if self.view_mode == ImageViewMode::Grid {
+                                    self.target_grid_ui(ui, &theme, &category, &targets);
+                                    return;
+                                }
 
-                                // Check if the image data is valid
-                                if target.image_data.is_empty() {
-                                    ui.label(RichText::new("No image data available").color(theme.warning));
-                                } else {
-                                    println!("Loading image for target {}, data length: {}", target.id, target.image_data.len());
-
-                                    // Load the target image from base64
-                                    match base64_to_image(&target.image_data) {
-                                        Ok(img) => {
-                                            println!("Successfully loaded image for target {}, dimensions: {}x{}",
-                                                target.id, img.width(), img.height());
-
-                                            let rgba_image = img.to_rgba8();
-                                            let pixels = rgba_image.as_flat_samples();
-                                            let size = [rgba_image.width() as usize, rgba_image.height() as usize];
-
-                                            // Create a texture for this target if we don't have it already
-                                            let target_texture = if !self.target_textures.contains_key(&target.id) {
-                                                let color_image = egui::ColorImage::from_rgba_unmultiplied(size, pixels.as_slice());
-                                                let texture = ui.ctx().load_texture(
-                                                    format!("target_{}", target.id),
-                                                    color_image,
-                                                    TextureOptions::default()
-                                                );
-
-                                                // Store the texture in our HashMap
-                                                self.target_textures.insert(target.id.clone(), texture.clone());
-                                                println!("Created and stored texture for target {}", target.id);
-                                                texture
+                                for target in targets.iter().copied() {
+                                    let is_selected = self.selected_target_id.as_ref().map_or(false, |id| id == &target.id);
+
+                                    // Create a frame for each target for better visual separation
+                                    let frame = egui::Frame::new()
+                                        .fill(if is_selected { theme.selected } else { theme.panel_background })
+                                        .stroke(Stroke::new(1.0, if is_selected { theme.primary } else { theme.border }))
+                                        .corner_radius(4.0)
+                                        .inner_margin(8.0)
+                                        .outer_margin(4.0);
+
+                                    let shown = frame.show(ui, |ui| {
+                                        // Use a vertical layout for better organization
+                                        ui.vertical(|ui| {
+                                            // Target name as header
+                                            let text_color = if is_selected { theme.primary } else { theme.text };
+                                            ui.label(RichText::new(&target.name).color(text_color).strong().size(16.0));
+
+                                            ui.add_space(4.0);
+
+                                            // Show a preview for each target
+                                            ui.label(RichText::new(t!("image.image_data_length")).italics().size(10.0).color(theme.muted_text));
+                                            ui.label(RichText::new(t!("image.bytes", count = target.image_data.len())).italics().size(10.0).color(theme.muted_text));
+
+                                            // Check if the image data is valid
+                                            if target.image_data.is_empty() {
+                                                ui.label(RichText::new(t!("image.no_image_data_available")).color(theme.warning));
                                             } else {
-                                                // Use the existing texture
-                                                self.target_textures.get(&target.id).unwrap().clone()
-                                            };
-
-                                            // Display the image with automatic sizing
-                                            ui.horizontal(|ui| {
-                                                ui.add_space(8.0); // Indent the image
-                                                ui.image(&target_texture);
-                                            });
-
-                                            // If this is the selected target, update the preview texture
-                                            if is_selected {
-                                                println!("Setting preview texture for selected target {}", target.id);
-                                                self.preview_texture = Some(target_texture);
+                                                println!("Loading image for target {}, data length: {}", target.id, target.image_data.len());
+
+                                                // Load the target image from base64
+                                                match base64_to_image(&target.image_data) {
+                                                    Ok(img) => {
+                                                        println!("Successfully loaded image for target {}, dimensions: {}x{}",
+                                                            target.id, img.width(), img.height());
+
+                                                        let rgba_image = img.to_rgba8();
+                                                        let pixels = rgba_image.as_flat_samples();
+                                                        let size = [rgba_image.width() as usize, rgba_image.height() as usize];
+
+                                                        // Create a texture for this target if we don't have it already
+                                                        let target_texture = if !self.target_textures.contains_key(&target.id) {
+                                                            let color_image = egui::ColorImage::from_rgba_unmultiplied(size, pixels.as_slice());
+                                                            let texture = ui.ctx().load_texture(
+                                                                format!("target_{}", target.id),
+                                                                color_image,
+                                                                TextureOptions::default()
+                                                            );
+
+                                                            // Store the texture in our HashMap
+                                                            self.target_textures.insert(target.id.clone(), texture.clone());
+                                                            println!("Created and stored texture for target {}", target.id);
+                                                            texture
+                                                        } else {
+                                                            // Use the existing texture
+                                                            self.target_textures.get(&target.id).unwrap().clone()
+                                                        };
+
+                                                        // Display the image with automatic sizing
+                                                        ui.horizontal(|ui| {
+                                                            ui.add_space(8.0); // Indent the image
+                                                            ui.image(&target_texture);
+                                                        });
+
+                                                        // If this is the selected target, update the preview texture
+                                                        if is_selected {
+                                                            println!("Setting preview texture for selected target {}", target.id);
+                                                            self.preview_texture = Some(target_texture);
+                                                        }
+                                                    },
+                                                    Err(e) => {
+                                                        ui.label(RichText::new(t!("image.failed_to_load_image", error = e)).color(theme.warning));
+                                                        eprintln!("Failed to load image for target {}: {}", target.id, e);
+                                                    }
+                                                }
                                             }
-                                        },
-                                        Err(e) => {
-                                            ui.label(RichText::new(format!("Failed to load image: {}", e)).color(theme.warning));
-                                            eprintln!("Failed to load image for target {}: {}", target.id, e);
-                                        }
-                                    }
-                                }
 
-                                // Add a selectable area for the entire frame
-                                if ui.selectable_label(is_selected, "").clicked() {
-                                    self.selected_target_id = Some(target.id.clone());
+                                            // Add a selectable area for the entire frame
+                                            if ui.selectable_label(is_selected, "").clicked() {
+                                                self.selected_target_id = Some(target.id.clone());
 
-                                    // The preview texture is already set above when we display the image
-                                    // No need to reload it here
+                                                // The preview texture is already set above when we display the image
+                                                // No need to reload it here
+                                            }
+                                        });
+                                    });
+                                    shown.response.on_hover_text(last_used_text(target));
                                 }
                             });
-                        });
                     }
                 });
         }
+        drop(image_library);
 
         ui.add_space(12.0);
         ui.separator();
@@ -395,7 +998,7 @@ impl ImageView {
 
         ui.horizontal(|ui| {
             // Use themed buttons for consistency
-            if theme.secondary_button(ui, "New Target") {
+            if theme.secondary_button(ui, &t!("image.new_target")) {
                 // Instead of minimizing, we'll take a screenshot first and then allow selection
                 match crate::modules::image_recognition::capture_screen() {
                     Ok(screenshot) => {
@@ -427,8 +1030,30 @@ impl ImageView {
 
             ui.add_space(8.0);
 
+            let paste = ui.add_enabled(clipboard::has_image(), theme.secondary_button_widget(&t!("image.paste_from_clipboard")))
+                .on_disabled_hover_text(t!("image.no_image_on_clipboard"));
+            if paste.clicked() {
+                let pasted = clipboard::read_image().and_then(|image| {
+                    let Some(image) = image else { return Ok(None) };
+                    let name = t!("image.clipboard_image_name", time = chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+                    let mut image_library = self.image_library.lock().unwrap();
+                    image_library.add_image_target(&name, &DynamicImage::ImageRgba8(image)).map(Some)
+                });
+
+                self.transfer_status = Some(match pasted {
+                    Ok(Some(target)) => {
+                        self.selected_target_id = Some(target.id.clone());
+                        (t!("image.added_from_the_clipboard", name = target.name), StatusMessageType::Success)
+                    },
+                    Ok(None) => (t!("image.no_image_on_clipboard"), StatusMessageType::Warning),
+                    Err(e) => (t!("image.failed_to_paste_image", error = e), StatusMessageType::Error),
+                });
+            }
+
+            ui.add_space(8.0);
+
             if let Some(target_id) = &self.selected_target_id {
-                if theme.accent_button(ui, "Delete") {
+                if theme.accent_button(ui, &t!("image.delete")) {
                     let mut image_library = self.image_library.lock().unwrap();
                     if let Err(e) = image_library.delete_target(target_id) {
                         eprintln!("Failed to delete target: {}", e);
@@ -443,6 +1068,140 @@ impl ImageView {
                 }
             }
         });
+
+        ui.add_space(12.0);
+        self.transfer_ui(ui);
+
+        ui.add_space(12.0);
+        self.prune_ui(ui);
+    }
+
+    fn prune_ui(&mut self, ui: &mut Ui) {
+        let theme = {
+            let state = self.state.lock().unwrap();
+            state.theme.clone()
+        };
+
+        ui.collapsing(t!("image.library_settings"), |ui| {
+            ui.horizontal(|ui| {
+                ui.label(t!("image.remove_targets_unused_for"));
+                ui.add(egui::DragValue::new(&mut self.prune_days).range(1..=365).suffix(" days"));
+            });
+            ui.label(RichText::new(t!("image.targets_that_have_never_been_searched")).small().weak());
+            ui.add_space(4.0);
+
+            if ui.button(t!("image.auto_prune")).clicked() {
+                let mut image_library = self.image_library.lock().unwrap();
+                self.prune_status = Some(match image_library.prune_stale(self.prune_days) {
+                    Ok(removed) => {
+                        // Drop everything cached for the deleted targets
+                        let remaining: Vec<String> = image_library.get_targets().iter().map(|t| t.id.clone()).collect();
+                        self.target_textures.retain(|id, _| remaining.contains(id));
+                        self.per_target_state.retain(|id, _| remaining.contains(id));
+                        if self.selected_target_id.as_ref().is_some_and(|id| !remaining.contains(id)) {
+                            self.selected_target_id = None;
+                            self.preview_texture = None;
+                        }
+
+                        let message = match removed.len() {
+                            1 => t!("image.removed_1_stale_target"),
+                            n => t!("image.removed_stale_targets", count = n),
+                        };
+                        (message, StatusMessageType::Success)
+                    },
+                    Err(e) => (t!("image.failed_to_prune_targets", error = e), StatusMessageType::Error),
+                });
+            }
+
+            if let Some((message, message_type)) = &self.prune_status {
+                ui.add_space(4.0);
+                components::status_message(ui, &theme, message, *message_type);
+            }
+        });
+    }
+
+    fn transfer_ui(&mut self, ui: &mut Ui) {
+        // Get the theme for consistent styling
+        let theme = {
+            let state = self.state.lock().unwrap();
+            state.theme.clone()
+        };
+
+        ui.label(RichText::new(t!("image.import_export")).strong());
+        ui.add_space(4.0);
+        ui.add(egui::TextEdit::singleline(&mut self.transfer_path).hint_text(t!("image.path_to_a_png_file")));
+        ui.add_space(4.0);
+
+        ui.horizontal(|ui| {
+            let has_path = !self.transfer_path.trim().is_empty();
+
+            if ui.add_enabled(has_path, egui::Button::new(t!("image.import_png"))).clicked() {
+                let path = PathBuf::from(self.transfer_path.trim());
+                let mut image_library = self.image_library.lock().unwrap();
+                self.transfer_status = Some(match image_library.import_target(&path) {
+                    Ok(target) => {
+                        self.selected_target_id = Some(target.id.clone());
+                        self.preview_texture = None;
+                        (t!("image.imported_target", name = target.name), StatusMessageType::Success)
+                    },
+                    Err(e) => (t!("image.failed_to_import_target", error = e), StatusMessageType::Error),
+                });
+            }
+
+            if ui.button(t!("image.import_folder")).clicked() {
+                if let Some(dir) = dialogs::pick_folder("Import targets from") {
+                    let mut image_library = self.image_library.lock().unwrap();
+                    self.transfer_status = Some(match image_library.import_from_directory(&dir) {
+                        Ok((imported, 0)) => (t!("image.imported_targets", count = imported.len()), StatusMessageType::Success),
+                        Ok((imported, skipped)) => (
+                            t!("image.imported_targets_skipped", count = imported.len(), skipped = skipped),
+                            StatusMessageType::Warning,
+                        ),
+                        Err(e) => (t!("image.failed_to_import_targets", error = e), StatusMessageType::Error),
+                    });
+                }
+            }
+
+            let can_export = has_path && self.selected_target_id.is_some();
+            if ui.add_enabled(can_export, egui::Button::new(t!("image.export_selected"))).clicked() {
+                if let Some(target_id) = &self.selected_target_id {
+                    let path = PathBuf::from(self.transfer_path.trim()).with_extension("png");
+                    let image_library = self.image_library.lock().unwrap();
+                    self.transfer_status = Some(match image_library.export_target(target_id, &path) {
+                        Ok(()) => (t!("image.exported_to", path = path.display()), StatusMessageType::Success),
+                        Err(e) => (t!("image.failed_to_export_target", error = e), StatusMessageType::Error),
+                    });
+                }
+            }
+        });
+
+        if let Some((message, message_type)) = &self.transfer_status {
+            ui.add_space(4.0);
+            components::status_message(ui, &theme, message, *message_type);
+        }
+    }
+
+    /// Apply the result of a finished auto-tune to the library
+    fn poll_auto_tune(&mut self) {
+        let Some(job) = &self.auto_tune else { return };
+
+        let result = match job.receiver.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => Err(AppError::ParseError("Auto-tune stopped unexpectedly".to_string())),
+        };
+
+        self.auto_tune_status = Some(match result {
+            Ok(threshold) => {
+                let mut image_library = self.image_library.lock().unwrap();
+                match image_library.set_threshold(&job.target_id, threshold) {
+                    Ok(()) => (t!("image.threshold_set_to", threshold = format!("{:.2}", threshold)), StatusMessageType::Success),
+                    Err(e) => (t!("image.failed_to_save_threshold", error = e), StatusMessageType::Error),
+                }
+            },
+            Err(e) => (t!("image.auto_tune_failed", error = e), StatusMessageType::Error),
+        });
+        self.auto_tune = None;
     }
 
     fn target_details_ui(&mut self, ui: &mut Ui) {
@@ -452,6 +1211,18 @@ impl ImageView {
             state.theme.clone()
         };
 
+        self.poll_auto_tune();
+
+        // Applied once the library lock below has been released
+        let mut category_move: Option<(String, String)> = None;
+        let mut preset_choice: Option<(String, f32)> = None;
+        let mut alignment_change: Option<(String, ClickAlignment)> = None;
+        let mut scroll_change: Option<(String, bool, u8)> = None;
+        let mut auto_adjust_change: Option<(String, bool, u32)> = None;
+        let mut match_to_capture: Option<(i32, i32, u32, u32)> = None;
+        let mut compress_request: Option<(String, u8)> = None;
+        let mut clipboard_update: Option<String> = None;
+
         if let Some(target_id) = &self.selected_target_id {
             let image_library = self.image_library.lock().unwrap();
             if let Some(target) = image_library.get_targets().iter().find(|t| &t.id == target_id) {
@@ -464,29 +1235,220 @@ impl ImageView {
                         .striped(true)
                         .show(ui, |ui| {
                             // ID row
-                            ui.label(RichText::new("ID:").strong());
+                            ui.label(RichText::new(t!("image.id")).strong());
                             ui.label(RichText::new(&target.id).monospace().size(14.0));
                             ui.end_row();
 
                             // Name row
-                            ui.label(RichText::new("Name:").strong());
+                            ui.label(RichText::new(t!("image.name")).strong());
                             ui.label(RichText::new(&target.name).size(14.0));
                             ui.end_row();
 
+                            // Category row
+                            ui.label(RichText::new(t!("image.category")).strong());
+                            ui.label(RichText::new(&target.category).size(14.0));
+                            ui.end_row();
+
                             // Threshold row
-                            ui.label(RichText::new("Threshold:").strong());
-                            ui.label(RichText::new(format!("{:.2}", target.threshold)).size(14.0));
+                            ui.label(RichText::new(t!("image.threshold")).strong());
+                            ui.vertical(|ui| {
+                                ui.label(RichText::new(format!("{:.2}", target.threshold)).size(14.0));
+                                if target.threshold_adjusted() {
+                                    ui.label(RichText::new(t!("image.auto_adjusted_from", threshold = format!("{:.2}", target.original_threshold)))
+                                        .small()
+                                        .color(theme.warning));
+                                }
+                            });
                             ui.end_row();
 
-                            // Click offset row
-                            ui.label(RichText::new("Click offset:").strong());
-                            if let Some((x, y)) = target.click_offset {
-                                ui.label(RichText::new(format!("({}, {})", x, y)).size(14.0));
-                            } else {
-                                ui.label(RichText::new("Center").size(14.0));
+                            // Last match confidence row
+                            ui.label(RichText::new(t!("image.match_log")).strong());
+                            match image_library.last_match_log(&target.id) {
+                                Some(entry) => {
+                                    let color = if entry.found { theme.success } else { theme.muted_text };
+                                    ui.label(RichText::new(t!("image.last_match_confidence", score = format!("{:.2}", entry.score))).size(14.0).color(color))
+                                        .on_hover_text(format!(
+                                            "{} on {}",
+                                            if entry.found { "Found" } else { "Not found" },
+                                            entry.timestamp.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S"),
+                                        ));
+                                },
+                                None => {
+                                    ui.label(RichText::new(t!("image.not_searched_yet")).size(14.0).color(theme.muted_text));
+                                },
                             }
                             ui.end_row();
+
+                            // Threshold auto-adjust row
+                            ui.label(RichText::new(t!("image.auto_adjust")).strong());
+                            let mut auto_adjust = target.auto_adjust_threshold;
+                            let mut miss_tolerance = target.miss_tolerance;
+                            ui.horizontal(|ui| {
+                                ui.checkbox(&mut auto_adjust, t!("image.lower_threshold_after"))
+                                    .on_hover_text(t!("image.lower_the_threshold_a_little_each"));
+                                ui.add_enabled(auto_adjust, egui::DragValue::new(&mut miss_tolerance).range(1..=50).suffix(" misses"));
+                            });
+                            if auto_adjust != target.auto_adjust_threshold || miss_tolerance != target.miss_tolerance {
+                                auto_adjust_change = Some((target.id.clone(), auto_adjust, miss_tolerance));
+                            }
+                            ui.end_row();
+
+                            // File size row
+                            ui.label(RichText::new(t!("image.file_size")).strong());
+                            ui.label(RichText::new(format!("{:.1} KB", target.encoded_size() as f32 / 1024.0)).size(14.0));
+                            ui.end_row();
+
+                            // Click alignment row; targets saved before alignments existed map their offset to Custom
+                            ui.label(RichText::new(t!("image.click_at")).strong());
+                            let current = target.click_alignment.unwrap_or(match target.click_offset {
+                                Some((x, y)) => ClickAlignment::Custom(x, y),
+                                None => ClickAlignment::Center,
+                            });
+                            let mut alignment = current;
+                            ui.vertical(|ui| {
+                                egui::ComboBox::from_id_salt("target_click_alignment")
+                                    .selected_text(alignment.label())
+                                    .show_ui(ui, |ui| {
+                                        for option in ClickAlignment::ALL {
+                                            let selected = std::mem::discriminant(&alignment) == std::mem::discriminant(&option);
+                                            if ui.selectable_label(selected, option.label()).clicked() && !selected {
+                                                alignment = option;
+                                            }
+                                        }
+                                    });
+
+                                if let ClickAlignment::Custom(offset_x, offset_y) = &mut alignment {
+                                    ui.horizontal(|ui| {
+                                        ui.label(t!("image.x"));
+                                        ui.add(egui::DragValue::new(offset_x).speed(1.0));
+                                        ui.label(t!("image.y"));
+                                        ui.add(egui::DragValue::new(offset_y).speed(1.0));
+                                    });
+                                }
+                            });
+                            if alignment != current {
+                                alignment_change = Some((target.id.clone(), alignment));
+                            }
+                            ui.end_row();
+
+                            // Auto-scroll row
+                            ui.label(RichText::new(t!("image.auto_scroll")).strong());
+                            let mut auto_scroll = target.auto_scroll_to_target;
+                            let mut max_attempts = target.max_scroll_attempts;
+                            ui.horizontal(|ui| {
+                                ui.checkbox(&mut auto_scroll, t!("image.scroll_to_find"))
+                                    .on_hover_text(t!("image.scroll_down_a_page_at_a"));
+                                ui.add_enabled(auto_scroll, egui::DragValue::new(&mut max_attempts).range(1..=20).suffix(" pages"));
+                            });
+                            if auto_scroll != target.auto_scroll_to_target || max_attempts != target.max_scroll_attempts {
+                                scroll_change = Some((target.id.clone(), auto_scroll, max_attempts));
+                            }
+                            ui.end_row();
+                        });
+                });
+
+                ui.add_space(8.0);
+
+                ui.horizontal(|ui| {
+                    ui.label(t!("image.threshold_presets"));
+                    for (label, threshold, color, tradeoff) in THRESHOLD_PRESETS {
+                        let chip = egui::Button::new(RichText::new(format!("{} ({:.2})", label, threshold)).color(Color32::BLACK).size(12.0))
+                            .fill(color)
+                            .corner_radius(CornerRadius::same(10));
+                        let response = ui.add(chip);
+                        components::AnimatedTooltip::attach(ui, &theme, &response, Some(tradeoff));
+                        if response.clicked() {
+                            preset_choice = Some((target.id.clone(), threshold));
+                        }
+                    }
+                });
+
+                if let Some((message, message_type)) = &self.preset_status {
+                    components::status_message(ui, &theme, message, *message_type);
+                }
+
+                ui.add_space(8.0);
+
+                let transparent = *self.per_target_state
+                    .entry(target.id.clone())
+                    .or_default()
+                    .has_transparency
+                    .get_or_insert_with(|| base64_to_image(&target.image_data).is_ok_and(|image| has_transparency(&image)));
+
+                ui.horizontal(|ui| {
+                    ui.label(t!("image.jpeg_quality"));
+                    ui.add(egui::Slider::new(&mut self.jpeg_quality, 50..=95));
+                    if ui.add_enabled(!transparent, egui::Button::new(t!("image.compress_jpeg"))).clicked() {
+                        compress_request = Some((target.id.clone(), self.jpeg_quality));
+                    }
+                });
+
+                if transparent {
+                    ui.colored_label(theme.warning, "This target has transparent pixels used for masked matching; JPEG would lose them.");
+                }
+
+                if let Some((message, message_type)) = &self.compress_status {
+                    components::status_message(ui, &theme, message, *message_type);
+                }
+
+                ui.add_space(8.0);
+
+                // Replaces just the image, for templates touched up in a paint program and copied back
+                let update = ui.add_enabled(clipboard::has_image(), egui::Button::new(t!("image.update_from_clipboard")))
+                    .on_disabled_hover_text(t!("image.no_image_on_clipboard"));
+                if update.clicked() {
+                    clipboard_update = Some(target.id.clone());
+                }
+
+                if let Some((message, message_type)) = &self.clipboard_status {
+                    components::status_message(ui, &theme, message, *message_type);
+                }
+
+                ui.add_space(8.0);
+
+                // Categories are free-form, so existing ones are offered alongside a field for a new one
+                ui.horizontal(|ui| {
+                    ui.label(t!("image.move_to_category"));
+                    egui::ComboBox::from_id_salt("move_to_category")
+                        .selected_text(&target.category)
+                        .show_ui(ui, |ui| {
+                            for category in image_library.categories() {
+                                if ui.selectable_label(category == target.category, &category).clicked() && category != target.category {
+                                    category_move = Some((target.id.clone(), category));
+                                }
+                            }
                         });
+
+                    ui.add(egui::TextEdit::singleline(&mut self.new_category).hint_text(t!("image.new_category")).desired_width(120.0));
+                    if ui.add_enabled(!self.new_category.trim().is_empty(), egui::Button::new(t!("image.move"))).clicked() {
+                        category_move = Some((target.id.clone(), self.new_category.trim().to_string()));
+                        self.new_category.clear();
+                    }
+                });
+
+                ui.add_space(8.0);
+
+                // Runs with image clicking on click the best match among the included targets instead of the click areas
+                let (mut image_click, locked) = {
+                    let state = self.state.lock().unwrap();
+                    (state.current_config.image_click.clone(), state.is_config_locked())
+                };
+                ui.add_enabled_ui(!locked, |ui| {
+                    let mut changed = ui.checkbox(&mut image_click.enabled, t!("image.click_targets_while_running")).changed();
+
+                    let mut included = image_click.targets.contains(&target.id);
+                    if ui.checkbox(&mut included, t!("image.include_this_target")).changed() {
+                        if included {
+                            image_click.targets.push(target.id.clone());
+                        } else {
+                            image_click.targets.retain(|id| id != &target.id);
+                        }
+                        changed = true;
+                    }
+
+                    if changed {
+                        self.state.lock().unwrap().current_config.image_click = image_click;
+                    }
                 });
 
                 ui.add_space(12.0);
@@ -494,21 +1456,32 @@ impl ImageView {
                 // Add a search button with theme styling
                 ui.horizontal(|ui| {
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                        if theme.primary_button(ui, "Find on Screen") {
-                            let image_library = self.image_library.lock().unwrap();
-                            match image_library.find_on_screen(&target.id) {
+                        if theme.primary_button(ui, &t!("image.find_on_screen")) {
+                            let mut image_library = self.image_library.lock().unwrap();
+                            let search = image_library.find_on_screen(&target.id, &mut enigo::Enigo::new());
+                            let mut state = self.state.lock().unwrap();
+                            for adjustment in image_library.take_threshold_adjustments() {
+                                state.log_event(EventKind::ThresholdAdjusted, Some(adjustment));
+                            }
+                            let found = image_library.last_match();
+                            match search {
                                 Ok(Some((x, y))) => {
+                                    match_to_capture = found.filter(|_| self.live_capture.is_none());
                                     self.last_search_result = Some((x, y));
                                     self.last_search_time = Some(Instant::now());
+                                    self.per_target_state.entry(target.id.clone()).or_default().last_found = Some(Instant::now());
+                                    state.log_event(EventKind::TargetFound, Some(format!("'{}' at ({}, {})", target.name, x, y)));
                                 },
                                 Ok(None) => {
                                     self.last_search_result = None;
                                     self.last_search_time = Some(Instant::now());
+                                    state.log_event(EventKind::TargetNotFound, Some(format!("'{}'", target.name)));
                                 },
                                 Err(e) => {
                                     eprintln!("Failed to search for target: {}", e);
                                     self.last_search_result = None;
                                     self.last_search_time = Some(Instant::now());
+                                    state.log_event(EventKind::Error, Some(format!("Search for '{}' failed: {}", target.name, e)));
                                 }
                             }
                         }
@@ -517,23 +1490,286 @@ impl ImageView {
 
                 // Add a help text
                 ui.add_space(8.0);
-                ui.label(RichText::new("Click 'Find on Screen' to locate this image on your screen.").italics().size(12.0));
+                ui.label(RichText::new(t!("image.click_find_on_screen_to_locate")).italics().size(12.0));
+
+                ui.add_space(8.0);
+                match &self.auto_tune {
+                    Some(job) => {
+                        let progress = job.started.elapsed().as_secs_f32() / AUTO_TUNE_SCREENSHOTS as f32;
+                        components::progress_bar(ui, &theme, progress.min(0.95), Some("Measuring match scores..."));
+                    },
+                    None => {
+                        if theme.secondary_button(ui, &t!("image.auto_tune_threshold")) {
+                            // Work on a copy so the library stays usable while screenshots are taken
+                            let mut library = image_library.clone();
+                            let target_id = target.id.clone();
+                            let (sender, receiver) = mpsc::channel();
+
+                            std::thread::spawn(move || {
+                                let _ = sender.send(library.optimize_threshold(&target_id, AUTO_TUNE_SCREENSHOTS));
+                            });
+
+                            self.auto_tune = Some(AutoTuneJob {
+                                target_id: target.id.clone(),
+                                started: Instant::now(),
+                                receiver,
+                            });
+                            self.auto_tune_status = None;
+                        }
+                        ui.label(RichText::new(t!("image.keep_the_target_visible_on_screen")).italics().size(12.0));
+                    },
+                }
+
+                if let Some((message, message_type)) = &self.auto_tune_status {
+                    components::status_message(ui, &theme, message, *message_type);
+                }
+
+                // Export just the template image, e.g. to compare it with what's on screen now
+                if !target.image_data.is_empty() {
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        let default_path = format!("{}.png", target.name);
+                        ui.add(egui::TextEdit::singleline(&mut self.png_export_path).hint_text(&default_path));
+
+                        if ui.button(t!("image.export_as_png")).clicked() {
+                            let path = if self.png_export_path.trim().is_empty() {
+                                PathBuf::from(default_path)
+                            } else {
+                                PathBuf::from(self.png_export_path.trim()).with_extension("png")
+                            };
+
+                            let result = base64_to_image(&target.image_data)
+                                .and_then(|image| image.save_with_format(&path, image::ImageFormat::Png).map_err(Into::into));
+
+                            self.png_export_status = Some(match result {
+                                Ok(()) => (t!("image.saved_template_to", path = path.display()), StatusMessageType::Success),
+                                Err(e) => (t!("image.failed_to_export_png", error = e), StatusMessageType::Error),
+                            });
+                        }
+                    });
+
+                    if let Some((message, message_type)) = &self.png_export_status {
+                        components::status_message(ui, &theme, message, *message_type);
+                    }
+                }
             } else {
                 ui.vertical_centered(|ui| {
                     ui.add_space(20.0);
-                    ui.label(RichText::new("Target not found in library").color(theme.warning));
+                    ui.label(RichText::new(t!("image.target_not_found_in_library")).color(theme.warning));
                     ui.add_space(20.0);
                 });
             }
         } else {
             ui.vertical_centered(|ui| {
                 ui.add_space(20.0);
-                ui.label(RichText::new("No target selected").strong());
+                ui.label(RichText::new(t!("image.no_target_selected")).strong());
                 ui.add_space(4.0);
-                ui.label(RichText::new("Select a target from the list or create a new one.").italics());
+                ui.label(RichText::new(t!("image.select_a_target_from_the_list")).italics());
                 ui.add_space(20.0);
             });
         }
+
+        if let Some((target_id, threshold)) = preset_choice {
+            self.apply_threshold_preset(&target_id, threshold);
+        }
+
+        if let Some((target_id, quality)) = compress_request {
+            let mut image_library = self.image_library.lock().unwrap();
+            let size_of = |library: &ImageLibrary| {
+                library.get_targets().iter().find(|t| t.id == target_id).map_or(0, |t| t.encoded_size())
+            };
+
+            let before = size_of(&image_library);
+            self.compress_status = Some(match image_library.compress_target(&target_id, quality) {
+                Ok(()) => {
+                    // The preview is rebuilt from the new data
+                    self.target_textures.remove(&target_id);
+                    let after = size_of(&image_library);
+                    (t!("image.compressed_from_to", before = format!("{:.1}", before as f32 / 1024.0), after = format!("{:.1}", after as f32 / 1024.0)), StatusMessageType::Success)
+                },
+                Err(e) => (t!("image.failed_to_compress_target", error = e), StatusMessageType::Error),
+            });
+        }
+
+        if let Some(found) = match_to_capture {
+            self.capture_match(ui.ctx(), found);
+        }
+
+        if let Some(target_id) = clipboard_update {
+            let updated = clipboard::read_image().and_then(|image| {
+                let Some(image) = image else { return Ok(false) };
+                let image_data = image_to_base64(&DynamicImage::ImageRgba8(image))?;
+                self.image_library.lock().unwrap().update_target_image(&target_id, image_data)?;
+                Ok(true)
+            });
+
+            self.clipboard_status = Some(match updated {
+                Ok(true) => {
+                    // Rebuilt from the new image the next time they're needed
+                    self.target_textures.remove(&target_id);
+                    self.per_target_state.remove(&target_id);
+                    self.preview_texture = None;
+                    (t!("image.updated_from_clipboard"), StatusMessageType::Success)
+                },
+                Ok(false) => (t!("image.no_image_on_clipboard"), StatusMessageType::Warning),
+                Err(e) => (t!("image.failed_to_update_from_clipboard", error = e), StatusMessageType::Error),
+            });
+        }
+
+        if let Some((target_id, enabled, max_attempts)) = scroll_change {
+            if let Err(e) = self.image_library.lock().unwrap().set_auto_scroll(&target_id, enabled, max_attempts) {
+                eprintln!("Failed to set auto-scroll: {}", e);
+            }
+        }
+
+        if let Some((target_id, enabled, miss_tolerance)) = auto_adjust_change {
+            if let Err(e) = self.image_library.lock().unwrap().set_auto_adjust_threshold(&target_id, enabled, miss_tolerance) {
+                eprintln!("Failed to set threshold auto-adjust: {}", e);
+            }
+        }
+
+        if let Some((target_id, alignment)) = alignment_change {
+            if let Err(e) = self.image_library.lock().unwrap().set_click_alignment(&target_id, alignment) {
+                eprintln!("Failed to set click alignment: {}", e);
+            }
+        }
+
+        if let Some((target_id, category)) = category_move {
+            if let Err(e) = self.image_library.lock().unwrap().set_category(&target_id, &category) {
+                eprintln!("Failed to move target to category: {}", e);
+            }
+        }
+    }
+
+    /// Set a preset threshold and report how the target scores against a recent screenshot
+    fn apply_threshold_preset(&mut self, target_id: &str, threshold: f32) {
+        let stale = self.cached_screen.as_ref().is_none_or(|(taken, _)| taken.elapsed() > PRESET_SCREEN_MAX_AGE);
+        if stale {
+            match capture_screen() {
+                Ok(screen) => self.cached_screen = Some((Instant::now(), DynamicImage::ImageRgba8(screen))),
+                Err(e) => {
+                    self.preset_status = Some((t!("image.failed_to_capture_screen", error = e), StatusMessageType::Error));
+                    return;
+                },
+            }
+        }
+
+        let mut image_library = self.image_library.lock().unwrap();
+        if let Err(e) = image_library.set_threshold(target_id, threshold) {
+            self.preset_status = Some((t!("image.failed_to_set_threshold", error = e), StatusMessageType::Error));
+            return;
+        }
+
+        let Some((_, screen)) = &self.cached_screen else { return };
+        self.preset_status = Some(match image_library.match_score(target_id, screen) {
+            Ok(Some(score)) if score >= threshold => (
+                t!("image.best_match_would_be_found", score = format!("{:.2}", score), threshold = format!("{:.2}", threshold)),
+                StatusMessageType::Success,
+            ),
+            Ok(Some(score)) => (
+                t!("image.best_match_would_not_be_found", score = format!("{:.2}", score), threshold = format!("{:.2}", threshold)),
+                StatusMessageType::Warning,
+            ),
+            Ok(None) => (t!("image.target_larger_than_screen"), StatusMessageType::Warning),
+            Err(e) => (t!("image.search_failed", error = e), StatusMessageType::Error),
+        });
+    }
+
+    /// Forget the captured area along with any crop made to it
+    fn clear_capture(&mut self) {
+        self.original_capture = None;
+        self.captured_image = None;
+        self.crop_rect = None;
+    }
+
+    /// Use `image` as the area to save and show it in the preview
+    fn set_captured_image(&mut self, ctx: &egui::Context, image: DynamicImage) {
+        let rgba = image.to_rgba8();
+        let size = [rgba.width() as usize, rgba.height() as usize];
+        let color_image = egui::ColorImage::from_rgba_unmultiplied(size, rgba.as_flat_samples().as_slice());
+        self.preview_texture = Some(ctx.load_texture("area_preview", color_image, TextureOptions::default()));
+        self.captured_image = Some(image);
+    }
+
+    /// Captured area with draggable corner handles, for trimming window borders and shadows before saving
+    fn crop_editor_ui(&mut self, ui: &mut Ui, theme: &AppTheme) {
+        let (Some(image), Some(texture)) = (&self.captured_image, &self.preview_texture) else { return };
+        let image_size = egui::vec2(image.width() as f32, image.height() as f32);
+        let texture_id = texture.id();
+        let full = egui::Rect::from_min_size(egui::Pos2::ZERO, image_size);
+        let is_cropped = self.original_capture.as_ref()
+            .is_some_and(|original| original.width() != image.width() || original.height() != image.height());
+
+        let scale = ui.available_width() / image_size.x;
+        let (rect, _) = ui.allocate_exact_size(image_size * scale, egui::Sense::hover());
+        let to_screen = |p: egui::Pos2| rect.min + p.to_vec2() * scale;
+
+        let mut crop = self.crop_rect.unwrap_or(full);
+
+        // Corners in the order top-left, top-right, bottom-left, bottom-right
+        for corner in 0..4 {
+            let left = corner % 2 == 0;
+            let top = corner < 2;
+            let position = egui::pos2(
+                if left { crop.min.x } else { crop.max.x },
+                if top { crop.min.y } else { crop.max.y },
+            );
+
+            let handle = egui::Rect::from_center_size(to_screen(position), egui::vec2(12.0, 12.0));
+            let response = ui.interact(handle, ui.id().with(("crop_handle", corner)), egui::Sense::drag())
+                .on_hover_cursor(egui::CursorIcon::Crosshair);
+
+            if let Some(pointer) = response.interact_pointer_pos().filter(|_| response.dragged()) {
+                // Whole pixels only, and never narrower than one
+                let p = ((pointer - rect.min) / scale).round();
+                if left {
+                    crop.min.x = p.x.clamp(0.0, crop.max.x - 1.0);
+                } else {
+                    crop.max.x = p.x.clamp(crop.min.x + 1.0, image_size.x);
+                }
+                if top {
+                    crop.min.y = p.y.clamp(0.0, crop.max.y - 1.0);
+                } else {
+                    crop.max.y = p.y.clamp(crop.min.y + 1.0, image_size.y);
+                }
+            }
+        }
+
+        let painter = ui.painter_at(rect);
+        let uv = egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0));
+        painter.image(texture_id, rect, uv, Color32::WHITE);
+
+        // Dim everything the crop would remove
+        let crop_screen = egui::Rect::from_min_max(to_screen(crop.min), to_screen(crop.max));
+        let shade = Color32::from_black_alpha(140);
+        painter.rect_filled(egui::Rect::from_min_max(rect.min, egui::pos2(rect.max.x, crop_screen.min.y)), 0.0, shade);
+        painter.rect_filled(egui::Rect::from_min_max(egui::pos2(rect.min.x, crop_screen.max.y), rect.max), 0.0, shade);
+        painter.rect_filled(egui::Rect::from_min_max(egui::pos2(rect.min.x, crop_screen.min.y), egui::pos2(crop_screen.min.x, crop_screen.max.y)), 0.0, shade);
+        painter.rect_filled(egui::Rect::from_min_max(egui::pos2(crop_screen.max.x, crop_screen.min.y), egui::pos2(rect.max.x, crop_screen.max.y)), 0.0, shade);
+        painter.rect_stroke(crop_screen, 0.0, Stroke::new(1.5, theme.warning), egui::epaint::StrokeKind::Middle);
+        for corner in [crop_screen.left_top(), crop_screen.right_top(), crop_screen.left_bottom(), crop_screen.right_bottom()] {
+            painter.rect_filled(egui::Rect::from_center_size(corner, egui::vec2(8.0, 8.0)), 2.0, theme.warning);
+        }
+
+        self.crop_rect = (crop != full).then_some(crop);
+
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(t!("image.crop_size", width = crop.width(), height = crop.height())).monospace());
+
+            if ui.add_enabled(self.crop_rect.is_some(), egui::Button::new(t!("image.apply_crop"))).clicked() {
+                if let (Some(crop), Some(mut image)) = (self.crop_rect.take(), self.captured_image.take()) {
+                    let cropped = image.crop(crop.min.x as u32, crop.min.y as u32, crop.width() as u32, crop.height() as u32);
+                    self.set_captured_image(ui.ctx(), cropped);
+                }
+            }
+
+            if ui.add_enabled(self.crop_rect.is_some() || is_cropped, egui::Button::new(t!("image.reset_crop"))).clicked() {
+                self.crop_rect = None;
+                if let Some(original) = self.original_capture.clone() {
+                    self.set_captured_image(ui.ctx(), original);
+                }
+            }
+        });
     }
 
     fn capture_area_ui(&mut self, ui: &mut Ui) {
@@ -548,7 +1784,7 @@ impl ImageView {
             theme.card_frame().show(ui, |ui| {
                 ui.vertical_centered(|ui| {
                     ui.add_space(8.0);
-                    let text = RichText::new("Click and drag to select an area on the screen")
+                    let text = RichText::new(t!("image.click_and_drag_to_select_an"))
                         .color(theme.warning)
                         .strong();
                     ui.label(text);
@@ -560,34 +1796,25 @@ impl ImageView {
 
             // Cancel button with theme styling
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                if theme.accent_button(ui, "Cancel") {
+                if theme.accent_button(ui, &t!("image.cancel")) {
                     self.is_selecting_area = false;
                     self.selection_start = None;
                     self.screenshot_area = None;
                     self.screen_texture = None; // Clear the screen texture
+                    self.clear_capture();
 
                     println!("Selection cancelled");
                 }
             });
         } else if let Some((x, y, width, height)) = self.screenshot_area {
-            // Create a preview of the selected area if we don't have one yet
-            if self.preview_texture.is_none() {
+            // Capture the selected area once, so cropping works on what was on screen at selection time
+            if self.captured_image.is_none() {
                 // Take a screenshot of the specified region
                 match crate::modules::image_recognition::capture_screen_area(x, y, width, height) {
                     Ok(screenshot) => {
-                        // Convert to a texture we can display
-                        let pixels = screenshot.as_flat_samples();
-                        let size = [screenshot.width() as usize, screenshot.height() as usize];
-
-                        let color_image = egui::ColorImage::from_rgba_unmultiplied(size, pixels.as_slice());
-                        let texture = ui.ctx().load_texture(
-                            "area_preview",
-                            color_image,
-                            TextureOptions::default()
-                        );
-
-                        // Store the preview texture
-                        self.preview_texture = Some(texture);
+                        let image = DynamicImage::ImageRgba8(screenshot);
+                        self.original_capture = Some(image.clone());
+                        self.set_captured_image(ui.ctx(), image);
 
                         println!("Created preview of selected area: {}x{}", width, height);
                     },
@@ -601,29 +1828,38 @@ impl ImageView {
             theme.card_frame().show(ui, |ui| {
                 ui.vertical(|ui| {
                     // Area details
-                    ui.label(RichText::new("Selected Area").strong().size(16.0));
+                    ui.label(RichText::new(t!("image.selected_area")).strong().size(16.0));
                     ui.add_space(4.0);
-                    ui.label(RichText::new(format!("Size: {}x{} pixels", width, height)).monospace());
-                    ui.label(RichText::new(format!("Position: ({}, {})", x, y)).monospace());
+                    let (saved_width, saved_height) = self.captured_image.as_ref()
+                        .map_or((width, height), |image| (image.width(), image.height()));
+                    ui.label(RichText::new(t!("image.size_pixels", width = saved_width, height = saved_height)).monospace());
+                    ui.label(RichText::new(t!("image.position", x = x, y = y)).monospace());
+                    ui.add_space(8.0);
+
+                    self.crop_editor_ui(ui, &theme);
                     ui.add_space(12.0);
 
                     // Form for saving the target
-                    let mut name = String::new();
                     ui.horizontal(|ui| {
-                        ui.label(RichText::new("Name:").strong());
+                        ui.label(RichText::new(t!("image.name")).strong());
                         ui.add_space(8.0);
-                        ui.add(egui::TextEdit::singleline(&mut name).hint_text("Enter target name"));
+                        if components::validated_text_edit(ui, &theme, &mut self.target_name, self.target_name_error.as_deref()).changed() {
+                            self.target_name_error = None;
+                        }
                     });
+                    if let Some(error) = &self.target_name_error {
+                        ui.colored_label(theme.error, error);
+                    }
 
                     ui.add_space(8.0);
 
                     // Add threshold slider with better styling
-                    let mut threshold = 0.8; // Default threshold
+                    let mut threshold = DEFAULT_THRESHOLD;
                     ui.horizontal(|ui| {
-                        ui.label(RichText::new("Threshold:").strong());
+                        ui.label(RichText::new(t!("image.threshold")).strong());
                         ui.add_space(8.0);
                         ui.add(egui::Slider::new(&mut threshold, 0.5..=0.95)
-                            .text("Match Precision")
+                            .text(t!("image.match_precision"))
                             .trailing_fill(true));
                     });
 
@@ -634,23 +1870,23 @@ impl ImageView {
                     let mut offset_x = 0;
                     let mut offset_y = 0;
 
-                    ui.label(RichText::new("Click Position").strong());
+                    ui.label(RichText::new(t!("image.click_position")).strong());
                     ui.add_space(4.0);
 
                     ui.horizontal(|ui| {
-                        ui.radio_value(&mut use_center, true, "Center of image");
+                        ui.radio_value(&mut use_center, true, t!("image.center_of_image"));
                         ui.add_space(16.0);
-                        ui.radio_value(&mut use_center, false, "Custom offset");
+                        ui.radio_value(&mut use_center, false, t!("image.custom_offset"));
                     });
 
                     if !use_center {
                         ui.add_space(4.0);
                         ui.horizontal(|ui| {
                             ui.add_space(16.0); // Indent
-                            ui.label("X offset:");
+                            ui.label(t!("image.x_offset"));
                             ui.add(egui::DragValue::new(&mut offset_x).speed(1.0));
                             ui.add_space(16.0);
-                            ui.label("Y offset:");
+                            ui.label(t!("image.y_offset"));
                             ui.add(egui::DragValue::new(&mut offset_y).speed(1.0));
                         });
                     }
@@ -659,37 +1895,45 @@ impl ImageView {
 
                     // Save button with theme styling
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                        let button_enabled = !name.is_empty();
+                        let button_enabled = !self.target_name.is_empty();
                         let button_text = if button_enabled { "Save Target" } else { "Enter a name first" };
 
                         if button_enabled && theme.secondary_button(ui, button_text) {
-                            let id = format!("target_{}", chrono::Utc::now().timestamp());
+                            let id = new_target_id();
 
-                            let click_offset = if use_center {
-                                None
+                            let click_alignment = if use_center {
+                                ClickAlignment::Center
                             } else {
-                                Some((offset_x, offset_y))
+                                ClickAlignment::Custom(offset_x, offset_y)
                             };
 
-                            let mut image_library = self.image_library.lock().unwrap();
-                            match image_library.create_target_from_screenshot(
-                                &id,
-                                &name,
-                                x,
-                                y,
-                                width,
-                                height,
-                                threshold,
-                                click_offset,
-                            ) {
+                            let image_library = Arc::clone(&self.image_library);
+                            let mut image_library = image_library.lock().unwrap();
+                            // Falls back to capturing again if the area couldn't be captured for the preview
+                            let created = match &self.captured_image {
+                                Some(image) => image_library.create_target_from_image(&id, &self.target_name, image, threshold, click_alignment),
+                                None => image_library.create_target_from_screenshot(&id, &self.target_name, x, y, width, height, threshold, click_alignment),
+                            };
+
+                            match created {
                                 Ok(target) => {
                                     println!("Created target with ID: {}, name: {}, image data length: {}",
                                         target.id, target.name, target.image_data.len());
 
                                     if let Err(e) = image_library.save_target(&target) {
                                         eprintln!("Failed to save target: {}", e);
+                                        match e {
+                                            AppError::ParseError(_) => self.target_name_error = Some(e.to_string()),
+                                            e => self.target_save_error = Some(AppError::user_facing(
+                                                "Failed to save target image. Check that the targets directory is writable.",
+                                                e,
+                                            )),
+                                        }
                                     } else {
                                         println!("Successfully saved target to disk");
+                                        self.target_name.clear();
+                                        self.target_name_error = None;
+                                        self.target_save_error = None;
 
                                         // Reload the targets
                                         if let Err(e) = image_library.load_targets() {
@@ -706,6 +1950,7 @@ impl ImageView {
 
                                         self.selected_target_id = Some(id.clone());
                                         self.screenshot_area = None;
+                                        self.clear_capture();
                                         // Keep the preview texture as it will be used to display the target
 
                                         println!("Set selected target ID to: {}", id);
@@ -723,6 +1968,11 @@ impl ImageView {
                             ui.add_enabled(false, button);
                         }
                     });
+
+                    if let Some(error) = &self.target_save_error {
+                        ui.add_space(8.0);
+                        components::error_message(ui, &theme, error);
+                    }
                 });
             });
         } else {
@@ -730,10 +1980,27 @@ impl ImageView {
             theme.card_frame().show(ui, |ui| {
                 ui.vertical_centered(|ui| {
                     ui.add_space(8.0);
-                    ui.label(RichText::new("No area selected").strong());
+                    ui.label(RichText::new(t!("image.no_area_selected")).strong());
+                    ui.add_space(8.0);
+
+                    // Very thin selections never match, so the shape can be locked before dragging
+                    ui.checkbox(&mut self.aspect_lock, t!("image.aspect_ratio_lock"));
+                    if self.aspect_lock {
+                        ui.horizontal(|ui| {
+                            for (label, ratio) in [("1:1", 1.0), ("16:9", 16.0 / 9.0), ("4:3", 4.0 / 3.0)] {
+                                if ui.selectable_label((self.aspect_ratio - ratio).abs() < 0.001, label).clicked() {
+                                    self.aspect_ratio = ratio;
+                                }
+                            }
+                            ui.add(egui::DragValue::new(&mut self.aspect_ratio)
+                                .speed(0.01)
+                                .range(0.1..=10.0)
+                                .suffix(" : 1"));
+                        });
+                    }
                     ui.add_space(8.0);
 
-                    if theme.primary_button(ui, "Select Area") {
+                    if theme.primary_button(ui, &t!("image.select_area")) {
                         // Instead of minimizing, we'll take a screenshot first and then allow selection
                         match crate::modules::image_recognition::capture_screen() {
                             Ok(screenshot) => {
@@ -763,7 +2030,7 @@ impl ImageView {
                     }
 
                     ui.add_space(8.0);
-                    ui.label(RichText::new("Click 'Select Area' to capture a region of the screen.").italics().size(12.0));
+                    ui.label(RichText::new(t!("image.click_select_area_to_capture_a")).italics().size(12.0));
                     ui.add_space(8.0);
                 });
             });
@@ -803,7 +2070,7 @@ impl ImageView {
 
                     // Add image dimensions with better styling
                     let size = texture.size();
-                    ui.label(RichText::new(format!("Dimensions: {}x{} pixels", size[0], size[1]))
+                    ui.label(RichText::new(t!("image.dimensions_pixels", width = size[0], height = size[1]))
                         .monospace()
                         .size(14.0));
                 });
@@ -830,9 +2097,9 @@ impl ImageView {
                 // Center the text
                 ui.vertical_centered(|ui| {
                     ui.add_space(rect.height() / 3.0); // Push down to center vertically
-                    ui.label(RichText::new("No preview available").color(theme.muted_text).size(16.0));
+                    ui.label(RichText::new(t!("image.no_preview_available")).color(theme.muted_text).size(16.0));
                     ui.add_space(8.0);
-                    ui.label(RichText::new("Select a target from the list or create a new one.")
+                    ui.label(RichText::new(t!("image.select_a_target_from_the_list"))
                         .color(theme.muted_text)
                         .italics()
                         .size(14.0));
@@ -0,0 +1,181 @@
+use egui::{Ui, RichText, ScrollArea};
+use chrono::NaiveTime;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use crate::gui::app::AppState;
+use crate::gui::components::{self, StatusMessageType};
+use crate::modules::config::ClickType;
+use crate::modules::event_log::{self, ClickEvent};
+use crate::modules::paths;
+use crate::t;
+
+/// Browser for the per-click session logs
+pub struct LogView {
+    state: Arc<Mutex<AppState>>,
+    sessions: Vec<PathBuf>,
+    selected_session: Option<PathBuf>,
+    events: Vec<ClickEvent>,
+    type_filter: Option<ClickType>,
+    from_time: String,
+    to_time: String,
+    load_error: Option<String>,
+}
+
+impl LogView {
+    pub fn new(state: Arc<Mutex<AppState>>) -> Self {
+        Self {
+            state,
+            sessions: event_log::list_session_logs(&paths::logs_dir()),
+            selected_session: None,
+            events: Vec::new(),
+            type_filter: None,
+            from_time: String::new(),
+            to_time: String::new(),
+            load_error: None,
+        }
+    }
+
+    /// Load the events of a session log into the viewer
+    fn load_session(&mut self, path: PathBuf) {
+        match event_log::load_events(&path) {
+            Ok(events) => {
+                self.events = events;
+                self.load_error = None;
+            },
+            Err(e) => {
+                self.events.clear();
+                self.load_error = Some(e.to_string());
+            },
+        }
+        self.selected_session = Some(path);
+    }
+
+    /// Whether an event passes the type and time-of-day filters
+    fn matches_filters(&self, event: &ClickEvent, from: Option<NaiveTime>, to: Option<NaiveTime>) -> bool {
+        if self.type_filter.is_some_and(|click_type| click_type != event.click_type) {
+            return false;
+        }
+
+        let time = event.timestamp.time();
+        from.is_none_or(|from| time >= from) && to.is_none_or(|to| time <= to)
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        let theme = {
+            let state = self.state.lock().unwrap();
+            state.theme.clone()
+        };
+
+        components::card(ui, &theme, &t!("log.session_logs"), |ui| {
+            ui.horizontal(|ui| {
+                if ui.button(t!("log.refresh")).clicked() {
+                    self.sessions = event_log::list_session_logs(&paths::logs_dir());
+                }
+                ui.label(RichText::new(t!("log.log_files_in", count = self.sessions.len(), path = paths::logs_dir().display())).color(theme.muted_text));
+            });
+
+            ui.add_space(4.0);
+
+            let mut to_load = None;
+            ScrollArea::vertical().id_salt("session_list").max_height(150.0).show(ui, |ui| {
+                // Newest sessions first
+                for path in self.sessions.iter().rev() {
+                    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("?");
+                    let selected = self.selected_session.as_ref() == Some(path);
+                    if ui.selectable_label(selected, name).clicked() {
+                        to_load = Some(path.clone());
+                    }
+                }
+
+                if self.sessions.is_empty() {
+                    ui.label(RichText::new(t!("log.no_session_logs_yet_enable_click")).italics());
+                }
+            });
+
+            if let Some(path) = to_load {
+                self.load_session(path);
+            }
+        });
+
+        ui.add_space(16.0);
+
+        components::card(ui, &theme, &t!("log.events"), |ui| {
+            if let Some(error) = &self.load_error {
+                components::status_message(ui, &theme, error, StatusMessageType::Error);
+                return;
+            }
+
+            if self.selected_session.is_none() {
+                ui.label(RichText::new(t!("log.select_a_session_to_view_its")).italics());
+                return;
+            }
+
+            ui.horizontal(|ui| {
+                ui.label(t!("log.type"));
+                let selected_text = match self.type_filter {
+                    Some(click_type) => format!("{:?}", click_type),
+                    None => t!("log.all"),
+                };
+                egui::ComboBox::from_id_salt("log_type_filter")
+                    .selected_text(selected_text)
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.type_filter, None, t!("log.all"));
+                        for click_type in ClickType::ALL {
+                            ui.selectable_value(&mut self.type_filter, Some(click_type), format!("{:?}", click_type));
+                        }
+                    });
+
+                ui.add_space(16.0);
+                ui.label(t!("log.from"));
+                ui.add(egui::TextEdit::singleline(&mut self.from_time).hint_text(t!("log.hh_mm_ss")).desired_width(70.0));
+                ui.label(t!("log.to"));
+                ui.add(egui::TextEdit::singleline(&mut self.to_time).hint_text(t!("log.hh_mm_ss")).desired_width(70.0));
+            });
+
+            let parse_time = |text: &str| {
+                let text = text.trim();
+                NaiveTime::parse_from_str(text, "%H:%M:%S")
+                    .or_else(|_| NaiveTime::parse_from_str(text, "%H:%M"))
+                    .ok()
+            };
+            let from = parse_time(&self.from_time);
+            let to = parse_time(&self.to_time);
+
+            let visible: Vec<&ClickEvent> = self.events.iter()
+                .filter(|event| self.matches_filters(event, from, to))
+                .collect();
+
+            ui.label(RichText::new(t!("log.events_shown", shown = visible.len(), total = self.events.len())).color(theme.muted_text));
+            ui.add_space(4.0);
+
+            ScrollArea::vertical().id_salt("event_list").max_height(320.0).show(ui, |ui| {
+                egui::Grid::new("event_grid")
+                    .num_columns(5)
+                    .spacing([16.0, 4.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.label(RichText::new(t!("log.time")).strong());
+                        ui.label(RichText::new(t!("log.position")).strong());
+                        ui.label(RichText::new(t!("log.type_2")).strong());
+                        ui.label(RichText::new(t!("log.source")).strong());
+                        ui.label(RichText::new(t!("log.verified")).strong());
+                        ui.end_row();
+
+                        for event in visible {
+                            ui.label(event.timestamp.format("%H:%M:%S%.3f").to_string());
+                            ui.label(format!("({}, {})", event.x, event.y));
+                            ui.label(format!("{:?}", event.click_type));
+                            ui.label(&event.source);
+                            ui.label(match event.verified {
+                                Some(true) => "Passed",
+                                Some(false) => "Failed",
+                                None => "-",
+                            });
+                            ui.end_row();
+                        }
+                    });
+            });
+        });
+    }
+}
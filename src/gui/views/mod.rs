@@ -3,6 +3,9 @@ pub mod area_view;
 pub mod settings_view;
 pub mod stats_view;
 pub mod image_view;
+pub mod hud_view;
+pub mod log_view;
+pub mod overlay_view;
 
 // Re-export views for convenience
 pub use profile_view::ProfileView;
@@ -10,3 +13,6 @@ pub use area_view::AreaView;
 pub use settings_view::SettingsView;
 pub use stats_view::StatsView;
 pub use image_view::ImageView;
+pub use hud_view::HudView;
+pub use log_view::LogView;
+pub use overlay_view::OverlayView;
@@ -0,0 +1,167 @@
+use eframe::egui::{self, Context, RichText, Ui, Vec2, Pos2};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
+
+use crate::gui::app::AppState;
+use crate::t;
+
+const OVERLAY_SIZE: Vec2 = Vec2::new(200.0, 60.0);
+const OVERLAY_MARGIN: f32 = 16.0;
+
+/// A dragged overlay's position is written to disk once it has stayed put this long
+const POSITION_SAVE_DELAY: Duration = Duration::from_secs(1);
+
+/// Click count, clicks per minute and session time for the current run
+struct SessionSummary {
+    clicks: u64,
+    clicks_per_minute: f32,
+    elapsed: Duration,
+}
+
+impl SessionSummary {
+    fn read(state: &AppState) -> Self {
+        let clicks = state.click_count.load(Ordering::Relaxed);
+        let elapsed = state.start_time.map(|start| start.elapsed()).unwrap_or_default();
+        let minutes = elapsed.as_secs_f32() / 60.0;
+
+        Self {
+            clicks,
+            clicks_per_minute: if minutes > 0.0 { clicks as f32 / minutes } else { 0.0 },
+            elapsed,
+        }
+    }
+
+    fn elapsed_text(&self) -> String {
+        let secs = self.elapsed.as_secs();
+        format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+    }
+}
+
+/// Always-on-top window with live session info. Unlike the HUD it stays up while the main window is open.
+pub struct OverlayView {
+    state: Arc<Mutex<AppState>>,
+    /// When the overlay was last dragged, until its new position is saved
+    moved_at: Option<Instant>,
+}
+
+impl OverlayView {
+    pub fn new(state: Arc<Mutex<AppState>>) -> Self {
+        Self { state, moved_at: None }
+    }
+
+    /// Overlay settings page in the main window
+    pub fn ui(&mut self, ui: &mut Ui) {
+        let (mut enabled, has_position, summary, theme) = {
+            let state = self.state.lock().unwrap();
+            (
+                state.preferences.overlay_enabled,
+                state.preferences.overlay_position.is_some(),
+                SessionSummary::read(&state),
+                state.theme.clone(),
+            )
+        };
+
+        ui.label(t!("overlay.a_small_transparent_window_kept_above"));
+        ui.add_space(8.0);
+
+        if ui.checkbox(&mut enabled, t!("overlay.show_session_overlay")).changed() {
+            let mut state = self.state.lock().unwrap();
+            state.preferences.overlay_enabled = enabled;
+            state.save_preferences();
+        }
+
+        ui.label(RichText::new(t!("overlay.drag_the_overlay_to_move_it")).small().weak());
+        ui.add_space(8.0);
+
+        if ui.add_enabled(has_position, egui::Button::new(t!("overlay.reset_position"))).clicked() {
+            let mut state = self.state.lock().unwrap();
+            state.preferences.overlay_position = None;
+            state.save_preferences();
+        }
+
+        ui.add_space(16.0);
+
+        theme.card_frame().show(ui, |ui| {
+            ui.label(RichText::new(t!("overlay.preview")).strong());
+            ui.add_space(4.0);
+            summary_ui(ui, &summary, theme.muted_text);
+        });
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        let (enabled, stored_position, summary, theme) = {
+            let state = self.state.lock().unwrap();
+            (
+                state.preferences.overlay_enabled,
+                state.preferences.overlay_position,
+                SessionSummary::read(&state),
+                state.theme.clone(),
+            )
+        };
+
+        if !enabled {
+            return;
+        }
+
+        let position = match stored_position {
+            Some((x, y)) => Pos2::new(x, y),
+            None => {
+                let monitor = ctx.input(|i| i.viewport().monitor_size).unwrap_or(Vec2::new(1920.0, 1080.0));
+                Pos2::new(monitor.x - OVERLAY_SIZE.x - OVERLAY_MARGIN, OVERLAY_MARGIN)
+            },
+        };
+
+        let builder = egui::ViewportBuilder::default()
+            .with_title("Mouse Clicker Overlay")
+            .with_decorations(false)
+            .with_always_on_top()
+            .with_transparent(true)
+            .with_resizable(false)
+            .with_taskbar(false)
+            .with_active(false)  // Never take focus from the application being automated
+            .with_inner_size(OVERLAY_SIZE)
+            .with_position(position);
+
+        let moved_to = ctx.show_viewport_immediate(
+            egui::ViewportId::from_hash_of("session_overlay"),
+            builder,
+            |ctx, _class| {
+                let frame = egui::Frame::new()
+                    .fill(theme.card_background.gamma_multiply(0.8))
+                    .corner_radius(8.0)
+                    .inner_margin(8.0);
+
+                egui::CentralPanel::default().frame(frame).show(ctx, |ui| {
+                    summary_ui(ui, &summary, theme.muted_text);
+
+                    let response = ui.interact(ui.max_rect(), egui::Id::new("overlay_drag"), egui::Sense::drag());
+                    if response.drag_started() {
+                        ctx.send_viewport_cmd(egui::ViewportCommand::StartDrag);
+                    }
+                });
+
+                ctx.input(|i| i.viewport().outer_rect).map(|rect| rect.min)
+            },
+        );
+
+        let mut state = self.state.lock().unwrap();
+        if let Some(pos) = moved_to {
+            if (pos - position).length() > 0.5 {
+                state.preferences.overlay_position = Some((pos.x, pos.y));
+                self.moved_at = Some(Instant::now());
+            }
+        }
+
+        // Saving on every frame of a drag would rewrite the file dozens of times a second
+        if self.moved_at.is_some_and(|at| at.elapsed() >= POSITION_SAVE_DELAY) {
+            state.save_preferences();
+            self.moved_at = None;
+        }
+    }
+}
+
+fn summary_ui(ui: &mut Ui, summary: &SessionSummary, muted: egui::Color32) {
+    ui.label(RichText::new(t!("overlay.clicks_cpm", clicks = summary.clicks, cpm = format!("{:.1}", summary.clicks_per_minute))).strong());
+    ui.label(RichText::new(t!("overlay.session_elapsed", elapsed = summary.elapsed_text())).color(muted).small());
+}
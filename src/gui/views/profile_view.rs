@@ -1,13 +1,52 @@
-use egui::{Ui, ScrollArea};
+use egui::{Color32, Ui, ScrollArea};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use crate::gui::app::AppState;
+use crate::gui::animations::{ease_in_out, Animation};
+use crate::gui::components::{self, StatusMessageType};
+use crate::gui::theme::AppTheme;
 use crate::modules::config::Config;
+use crate::modules::dialogs;
+use crate::modules::event_log::EventKind;
+use crate::modules::profiles::{ProfileFormat, ProfileMetadata, ProfileSummary};
+use crate::modules::paths;
+use crate::modules::error::{AppError, Result};
+use crate::t;
+
+/// How often the clipboard is re-read to decide whether pasting is possible
+const CLIPBOARD_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Length of the fade when the details panel switches to another profile, in seconds
+const PROFILE_SWITCH_FADE_SECS: f32 = 0.2;
 
 pub struct ProfileView {
     state: Arc<Mutex<AppState>>,
     new_profile_name: String,
+    new_profile_error: Option<String>,
+    new_profile_format: ProfileFormat,
     selected_profile_index: Option<usize>,
+    clipboard: Option<arboard::Clipboard>,
+    clipboard_has_text: bool,
+    clipboard_checked_at: Option<Instant>,
+    pending_import: Option<Config>,
+    import_name: String,
+    clipboard_status: Option<(String, StatusMessageType)>,
+    renaming: Option<(String, String)>,  // (original name, edited name)
+    rename_error: Option<String>,
+    comparing: bool,
+    compare_with: Option<String>,
+    compare_config: Option<Result<Config>>,
+    backup_overwrite: bool,
+    backup_status: Option<(String, StatusMessageType)>,
+    backup_error: Option<AppError>,  // Why the last export or import failed, instead of a status
+    pending_delete: Option<String>,  // Profile waiting for the user to confirm its deletion
+    last_shown_pass: u64,  // Used to notice the view being switched to
+    scroll_to_selected: bool,
+    profile_switch_animation: Animation,
+    pending_config: Option<Config>,  // Loaded profile fading in, made current when the fade ends
+    profile_metadata: Option<ProfileMetadata>,  // File details of the selected profile
+    summaries: Vec<ProfileSummary>,  // One per listed profile, re-read when the list changes or the view is opened
 }
 
 impl ProfileView {
@@ -15,120 +54,747 @@ impl ProfileView {
         Self {
             state,
             new_profile_name: String::new(),
+            new_profile_error: None,
+            new_profile_format: ProfileFormat::Json,
             selected_profile_index: None,
+            clipboard: arboard::Clipboard::new().ok(),
+            clipboard_has_text: false,
+            clipboard_checked_at: None,
+            pending_import: None,
+            import_name: String::new(),
+            clipboard_status: None,
+            renaming: None,
+            rename_error: None,
+            comparing: false,
+            compare_with: None,
+            compare_config: None,
+            backup_overwrite: false,
+            backup_status: None,
+            backup_error: None,
+            pending_delete: None,
+            last_shown_pass: 0,
+            scroll_to_selected: false,
+            profile_switch_animation: Animation::new(PROFILE_SWITCH_FADE_SECS),
+            pending_config: None,
+            profile_metadata: None,
+            summaries: Vec::new(),
+        }
+    }
+
+    /// Re-check the clipboard contents, at most once per poll interval
+    fn poll_clipboard(&mut self) {
+        if self.clipboard_checked_at.is_some_and(|at| at.elapsed() < CLIPBOARD_POLL_INTERVAL) {
+            return;
+        }
+
+        self.clipboard_checked_at = Some(Instant::now());
+        self.clipboard_has_text = self.clipboard
+            .as_mut()
+            .and_then(|clipboard| clipboard.get_text().ok())
+            .is_some_and(|text| !text.trim().is_empty());
+    }
+
+    /// Parse the clipboard text as a profile
+    fn read_clipboard_profile(&mut self) -> Result<Config> {
+        let clipboard = self.clipboard
+            .as_mut()
+            .ok_or_else(|| AppError::ParseError("Clipboard is not available".to_string()))?;
+
+        let text = clipboard.get_text()
+            .map_err(|e| AppError::ParseError(format!("Failed to read clipboard: {}", e)))?;
+
+        Ok(serde_json::from_str::<Config>(&text)?)
+    }
+
+    /// Save an imported profile and make it the current one
+    fn save_imported_profile(&mut self, config: Config) {
+        let mut state = self.state.lock().unwrap();
+        match state.profile_manager.save_profile(&config) {
+            Ok(()) => {
+                self.selected_profile_index = state.profile_manager
+                    .list_profiles()
+                    .iter()
+                    .position(|name| *name == config.profile_name);
+                self.clipboard_status = Some((
+                    t!("profile.imported_profile", name = config.profile_name),
+                    StatusMessageType::Success,
+                ));
+                state.current_config = config;
+            },
+            Err(e) => {
+                self.clipboard_status = Some((t!("profile.failed_to_save_profile", error = e), StatusMessageType::Error));
+            },
+        }
+    }
+
+    /// Rename the profile being edited, keeping the current config in sync
+    fn commit_rename(&mut self) {
+        let Some((old_name, new_name)) = self.renaming.clone() else { return };
+        let new_name = new_name.trim().to_string();
+
+        if new_name == old_name {
+            self.renaming = None;
+            self.rename_error = None;
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        match state.profile_manager.rename_profile(&old_name, &new_name) {
+            Ok(()) => {
+                if state.current_config.profile_name == old_name {
+                    state.current_config.profile_name = new_name.clone();
+                }
+                state.preferences.forget_recent_profile(&old_name);
+                state.save_preferences();
+                self.selected_profile_index = state.profile_manager
+                    .list_profiles()
+                    .iter()
+                    .position(|name| *name == new_name);
+                self.renaming = None;
+                self.rename_error = None;
+            },
+            Err(e) => {
+                self.rename_error = Some(e.to_string());
+            },
+        }
+    }
+
+    /// Pick another profile and list how it differs from the current configuration
+    fn compare_ui(&mut self, ui: &mut Ui, theme: &AppTheme, config: &Config) {
+        let profiles: Vec<String> = {
+            let state = self.state.lock().unwrap();
+            state.profile_manager.list_profiles()
+        };
+
+        ui.horizontal(|ui| {
+            ui.label(t!("profile.compare_with"));
+            egui::ComboBox::from_id_salt("compare_profile")
+                .selected_text(self.compare_with.as_deref().unwrap_or("Select a profile"))
+                .show_ui(ui, |ui| {
+                    for name in profiles.iter().filter(|name| **name != config.profile_name) {
+                        if ui.selectable_value(&mut self.compare_with, Some(name.clone()), name).changed() {
+                            self.compare_config = None;
+                        }
+                    }
+                });
+        });
+
+        let Some(other_name) = &self.compare_with else { return };
+
+        // Only read the other profile from disk when the selection changes
+        let other = self.compare_config.get_or_insert_with(|| {
+            let state = self.state.lock().unwrap();
+            state.profile_manager.load_profile(other_name)
+        });
+
+        let other = match other {
+            Ok(other) => other,
+            Err(e) => {
+                components::status_message(ui, theme, &t!("profile.failed_to_load_profile", error = e), StatusMessageType::Error);
+                return;
+            },
+        };
+
+        // The profile name always differs, so leave it out
+        let diffs: Vec<_> = config.diff(other)
+            .into_iter()
+            .filter(|diff| diff.field_path != "profile_name")
+            .collect();
+
+        if diffs.is_empty() {
+            ui.label(egui::RichText::new(t!("profile.the_profiles_have_identical_settings")).italics());
+            return;
+        }
+
+        ScrollArea::vertical().id_salt("profile_diff").max_height(240.0).show(ui, |ui| {
+            egui::Grid::new("profile_diff_grid")
+                .num_columns(3)
+                .spacing([16.0, 4.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label(egui::RichText::new(t!("profile.setting")).strong());
+                    ui.label(egui::RichText::new(&config.profile_name).strong());
+                    ui.label(egui::RichText::new(other_name).strong());
+                    ui.end_row();
+
+                    for diff in &diffs {
+                        ui.label(egui::RichText::new(&diff.field_path).monospace());
+                        ui.label(egui::RichText::new(&diff.old_value).color(theme.error));
+                        ui.label(egui::RichText::new(&diff.new_value).color(theme.success));
+                        ui.end_row();
+                    }
+                });
+        });
+    }
+
+    fn clipboard_ui(&mut self, ui: &mut Ui, theme: &AppTheme, locked: bool) {
+        self.poll_clipboard();
+
+        ui.horizontal(|ui| {
+            if self.clipboard_has_text
+                && ui.add_enabled(!locked, egui::Button::new(t!("profile.paste_profile_json"))).clicked()
+            {
+                match self.read_clipboard_profile() {
+                    Ok(config) => {
+                        let taken = {
+                            let state = self.state.lock().unwrap();
+                            state.profile_manager.list_profiles().contains(&config.profile_name)
+                        };
+
+                        if taken {
+                            self.import_name = t!("profile.imported_name", name = config.profile_name);
+                            self.pending_import = Some(config);
+                        } else {
+                            self.save_imported_profile(config);
+                        }
+                    },
+                    Err(e) => {
+                        self.clipboard_status = None;
+                        self.state.lock().unwrap().error_queue.send(t!("profile.failed_to_paste_profile", error = e));
+                    },
+                }
+            }
+
+            if ui.button(t!("profile.copy_profile_json")).clicked() {
+                let json = {
+                    let state = self.state.lock().unwrap();
+                    serde_json::to_string_pretty(&state.current_config)
+                };
+
+                let result = match (json, self.clipboard.as_mut()) {
+                    (Ok(json), Some(clipboard)) => clipboard.set_text(json).map_err(|e| e.to_string()),
+                    (Err(e), _) => Err(e.to_string()),
+                    (_, None) => Err(t!("profile.clipboard_is_not_available")),
+                };
+
+                self.clipboard_status = Some(match result {
+                    Ok(()) => (t!("profile.profile_copied_to_clipboard"), StatusMessageType::Success),
+                    Err(e) => (t!("profile.failed_to_copy_profile", error = e), StatusMessageType::Error),
+                });
+            }
+        });
+
+        // The pasted profile's name is already in use, so ask for a new one
+        if self.pending_import.is_some() {
+            let mut save = false;
+            let mut cancel = false;
+
+            ui.horizontal(|ui| {
+                ui.label(t!("profile.a_profile_with_that_name_exists"));
+                ui.text_edit_singleline(&mut self.import_name);
+                save = ui.button(t!("profile.save")).clicked();
+                cancel = ui.button(t!("profile.cancel")).clicked();
+            });
+
+            if save {
+                let name = self.import_name.trim().to_string();
+                let taken = {
+                    let state = self.state.lock().unwrap();
+                    state.profile_manager.list_profiles().contains(&name)
+                };
+
+                if name.is_empty() || taken {
+                    self.clipboard_status = Some((t!("profile.choose_an_unused_name"), StatusMessageType::Warning));
+                } else if let Some(mut config) = self.pending_import.take() {
+                    config.profile_name = name;
+                    self.save_imported_profile(config);
+                }
+            } else if cancel {
+                self.pending_import = None;
+            }
+        }
+
+        if let Some((message, message_type)) = &self.clipboard_status {
+            components::status_message(ui, theme, message, *message_type);
         }
     }
     
+    /// Copy all profiles to or from a folder the user picks
+    fn backup_ui(&mut self, ui: &mut Ui, theme: &AppTheme, locked: bool) {
+        ui.horizontal(|ui| {
+            if ui.button(t!("profile.export_all")).clicked() {
+                if let Some(dir) = dialogs::pick_folder("Export all profiles to") {
+                    let mut state = self.state.lock().unwrap();
+                    self.backup_status = None;
+                    self.backup_error = None;
+                    match state.profile_manager.export_all(&dir) {
+                        Ok(names) => {
+                            let message = t!("profile.exported_profiles_to", count = names.len(), path = dir.display());
+                            state.log_event(EventKind::ProfilesExported, Some(message.clone()));
+                            self.backup_status = Some((message, StatusMessageType::Success));
+                        },
+                        Err(e) => {
+                            state.log_event(EventKind::Error, Some(format!("Profile export failed: {}", e)));
+                            self.backup_error = Some(AppError::user_facing(
+                                "Failed to export profiles. Check that the chosen folder is writable.",
+                                e,
+                            ));
+                        },
+                    }
+                }
+            }
+
+            ui.add_enabled_ui(!locked, |ui| {
+                if ui.button(t!("profile.import_all")).clicked() {
+                    if let Some(dir) = dialogs::pick_folder("Import profiles from") {
+                        let mut state = self.state.lock().unwrap();
+                        self.backup_status = None;
+                        self.backup_error = None;
+                        match state.profile_manager.import_all(&dir, self.backup_overwrite) {
+                            Ok((imported, skipped)) => {
+                                let message = t!("profile.imported_profiles_from", count = imported, path = dir.display(), skipped = skipped);
+                                state.log_event(EventKind::ProfilesImported, Some(message.clone()));
+                                self.backup_status = Some((message, StatusMessageType::Success));
+                            },
+                            Err(e) => {
+                                state.log_event(EventKind::Error, Some(format!("Profile import failed: {}", e)));
+                                self.backup_error = Some(AppError::user_facing(
+                                    "Failed to import profiles. Check that the folder exists and its profile files can be read.",
+                                    e,
+                                ));
+                            },
+                        }
+
+                        // New files can shift the list, so reselect the current profile by name
+                        let current = state.current_config.profile_name.clone();
+                        self.selected_profile_index = state.profile_manager
+                            .list_profiles()
+                            .iter()
+                            .position(|name| *name == current);
+                    }
+                }
+
+                ui.checkbox(&mut self.backup_overwrite, t!("profile.overwrite_existing"));
+            });
+        });
+
+        if let Some((message, message_type)) = &self.backup_status {
+            components::status_message(ui, theme, message, *message_type);
+        }
+        if let Some(error) = &self.backup_error {
+            components::error_message(ui, theme, error);
+        }
+    }
+
+    /// Make `index` the selected profile and load it as the current config
+    fn load_profile_at(&mut self, index: usize, profiles: &[String]) {
+        let Some(profile_name) = profiles.get(index) else { return };
+        self.selected_profile_index = Some(index);
+
+        let mut state = self.state.lock().unwrap();
+        if state.is_config_locked() {
+            return;
+        }
+        self.profile_metadata = state.profile_manager.profile_metadata(profile_name)
+            .map_err(|e| eprintln!("Failed to read file details of profile '{}': {}", profile_name, e))
+            .ok();
+        if let Ok(config) = state.profile_manager.load_profile(profile_name) {
+            // The details panel fades the new profile in before it becomes the current config
+            self.pending_config = Some(config);
+            self.profile_switch_animation.reset();
+
+            state.preferences.note_recent_profile(profile_name);
+            state.save_preferences();
+        }
+    }
+
+    /// Make the profile fading in the current config once its fade has ended, or straight away
+    /// if `now`. Called every frame so it doesn't wait for the view to be shown again; dropped
+    /// if a session has started since, as the config is locked then.
+    pub fn finish_profile_switch(&mut self, now: bool) {
+        if self.pending_config.is_none() || !(now || self.profile_switch_animation.is_completed()) {
+            return;
+        }
+
+        let config = self.pending_config.take().unwrap();
+        let mut state = self.state.lock().unwrap();
+        if !state.is_config_locked() {
+            state.current_config = config;
+        }
+    }
+
+    /// Ask before deleting the profile in `pending_delete`
+    fn delete_confirmation_ui(&mut self, ctx: &egui::Context) {
+        let Some(profile_name) = self.pending_delete.clone() else { return };
+        let mut confirmed = false;
+        let mut cancelled = false;
+
+        egui::Window::new(t!("profile.delete_profile"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(t!("profile.delete_profile_confirm", name = profile_name));
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    confirmed = ui.button(t!("profile.delete")).clicked();
+                    cancelled = ui.button(t!("profile.cancel")).clicked() || ui.input(|i| i.key_pressed(egui::Key::Escape));
+                });
+            });
+
+        if confirmed {
+            let mut state = self.state.lock().unwrap();
+            if let Err(e) = state.profile_manager.delete_profile(&profile_name) {
+                eprintln!("Failed to delete profile: {}", e);
+            } else {
+                self.selected_profile_index = None;
+                state.preferences.forget_recent_profile(&profile_name);
+                state.save_preferences();
+            }
+        }
+
+        if confirmed || cancelled {
+            self.pending_delete = None;
+        }
+    }
+
     pub fn ui(&mut self, ui: &mut Ui) {
-        ui.heading("Profile Management");
+        ui.heading(t!("profile.profile_management"));
         
         ui.add_space(10.0);
+
+        let (theme, locked) = {
+            let state = self.state.lock().unwrap();
+            (state.theme.clone(), state.is_config_locked())
+        };
+
+        // Switching or creating profiles replaces the live config, so block it during a session
+        if locked {
+            components::config_locked_banner(ui, &theme);
+        }
         
-        ui.horizontal(|ui| {
-            ui.label("New Profile:");
-            ui.text_edit_singleline(&mut self.new_profile_name);
+        ui.add_enabled_ui(!locked, |ui| ui.horizontal(|ui| {
+            ui.label(t!("profile.new_profile"));
+            if components::validated_text_edit(ui, &theme, &mut self.new_profile_name, self.new_profile_error.as_deref()).changed() {
+                self.new_profile_error = None;
+            }
             
-            if ui.button("Create").clicked() && !self.new_profile_name.is_empty() {
+            if ui.button(t!("profile.create")).clicked() && !self.new_profile_name.is_empty() {
                 let mut state = self.state.lock().unwrap();
                 let mut config = Config::default();
                 config.profile_name = self.new_profile_name.clone();
                 
-                let profile_path = state.profile_manager.get_profile_path(&config.profile_name);
-                if let Err(e) = config.save(profile_path.to_str().unwrap()) {
-                    eprintln!("Failed to save profile: {}", e);
-                } else {
-                    state.current_config = config;
-                    self.new_profile_name.clear();
+                match state.profile_manager.save_profile_as(&config, self.new_profile_format) {
+                    Ok(()) => {
+                        state.current_config = config;
+                        self.new_profile_name.clear();
+                        self.new_profile_error = None;
+                    },
+                    Err(e) => {
+                        self.new_profile_error = Some(e.to_string());
+                    },
                 }
             }
-        });
+
+            if let Some(error) = &self.new_profile_error {
+                ui.colored_label(theme.error, error);
+            }
+        }));
+
+        ui.add_enabled_ui(!locked, |ui| ui.horizontal(|ui| {
+            ui.label(t!("profile.format"));
+            ui.radio_value(&mut self.new_profile_format, ProfileFormat::Json, t!("profile.json"));
+            ui.radio_value(&mut self.new_profile_format, ProfileFormat::Toml, t!("profile.toml"))
+                .on_hover_text(t!("profile.easier_to_edit_by_hand_existing"));
+        }));
         
+        ui.add_space(10.0);
+
+        self.clipboard_ui(ui, &theme, locked);
+
+        ui.add_space(10.0);
+
+        self.backup_ui(ui, &theme, locked);
+
         ui.add_space(20.0);
         
         ui.horizontal(|ui| {
-            ui.vertical(|ui| {
-                ui.heading("Available Profiles");
-                
-                let profiles = {
+            ui.add_enabled_ui(!locked, |ui| ui.vertical(|ui| {
+                let (profiles, recent) = {
                     let state = self.state.lock().unwrap();
-                    state.profile_manager.list_profiles()
+                    let profiles = state.profile_manager.list_profiles();
+
+                    // Summaries read every file, so only refresh them when something may have changed
+                    let just_shown = ui.ctx().cumulative_pass_nr() > self.last_shown_pass + 1;
+                    let listed_changed = !self.summaries.iter().map(|summary| &summary.name).eq(profiles.iter());
+                    if just_shown || listed_changed {
+                        self.summaries = state.profile_manager.list_profiles_with_summary();
+                    }
+
+                    (profiles, state.preferences.recent_profiles.clone())
                 };
-                
-                ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+
+                let list_id = ui.make_persistent_id("profile_list");
+                let mut list_clicked = false;
+
+                // Profiles whose files have gone since they were loaded are left out
+                let recent: Vec<usize> = recent.iter()
+                    .filter_map(|name| profiles.iter().position(|profile| profile == name))
+                    .collect();
+                if !recent.is_empty() {
+                    ui.collapsing(t!("profile.recent_profiles"), |ui| {
+                        ui.horizontal_wrapped(|ui| {
+                            for &index in &recent {
+                                if ui.button(&profiles[index]).clicked() {
+                                    self.load_profile_at(index, &profiles);
+                                    self.scroll_to_selected = true;
+                                    list_clicked = true;
+                                }
+                            }
+                        });
+                    });
+                    ui.add_space(8.0);
+                }
+
+                ui.heading(t!("profile.available_profiles"));
+
+                let list = ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
                     for (i, profile_name) in profiles.iter().enumerate() {
-                        let is_selected = self.selected_profile_index == Some(i);
-                        if ui.selectable_label(is_selected, profile_name).clicked() {
-                            self.selected_profile_index = Some(i);
-                            
-                            // Load the selected profile
-                            let mut state = self.state.lock().unwrap();
-                            if let Ok(config) = state.profile_manager.load_profile(profile_name) {
-                                state.current_config = config;
+                        // The profile being renamed is edited in place
+                        if let Some((original, edited)) = &mut self.renaming {
+                            if original == profile_name {
+                                let response = components::validated_text_edit(ui, &theme, edited, self.rename_error.as_deref());
+                                response.request_focus();
+
+                                if ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                                    self.commit_rename();
+                                } else if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+                                    self.renaming = None;
+                                    self.rename_error = None;
+                                }
+
+                                if let Some(error) = &self.rename_error {
+                                    ui.colored_label(theme.error, error);
+                                }
+                                continue;
                             }
                         }
+
+                        let is_selected = self.selected_profile_index == Some(i);
+                        let label = ui.selectable_label(is_selected, profile_name);
+                        if is_selected && self.scroll_to_selected {
+                            label.scroll_to_me(None);
+                            self.scroll_to_selected = false;
+                        }
+                        if label.clicked() {
+                            self.load_profile_at(i, &profiles);
+                            list_clicked = true;
+                        }
                     }
                 });
+
+                // The list takes keyboard focus when the view is switched to or a profile is clicked
+                let list_response = ui.interact(list.inner_rect, list_id, egui::Sense::focusable_noninteractive());
+                let pass = ui.ctx().cumulative_pass_nr();
+                if list_clicked || pass > self.last_shown_pass + 1 {
+                    ui.memory_mut(|m| m.request_focus(list_id));
+                }
+                self.last_shown_pass = pass;
+
+                if list_response.has_focus() && self.renaming.is_none() && self.pending_delete.is_none() && !profiles.is_empty() {
+                    // Keep the arrow keys from moving focus to the next widget
+                    ui.memory_mut(|m| m.set_focus_lock_filter(list_id, egui::EventFilter { vertical_arrows: true, ..Default::default() }));
+
+                    let (up, down, enter, delete) = ui.input(|i| (
+                        i.key_pressed(egui::Key::ArrowUp),
+                        i.key_pressed(egui::Key::ArrowDown),
+                        i.key_pressed(egui::Key::Enter),
+                        i.key_pressed(egui::Key::Delete),
+                    ));
+
+                    let last = profiles.len() - 1;
+                    if up || down {
+                        self.selected_profile_index = Some(match self.selected_profile_index {
+                            Some(i) if up => i.saturating_sub(1),
+                            Some(i) => (i + 1).min(last),
+                            None if up => last,
+                            None => 0,
+                        });
+                        self.scroll_to_selected = true;
+                    }
+                    if enter {
+                        if let Some(index) = self.selected_profile_index {
+                            self.load_profile_at(index, &profiles);
+                        }
+                    }
+                    if delete {
+                        self.pending_delete = self.selected_profile_index.and_then(|i| profiles.get(i)).cloned();
+                    }
+                }
+
+                ui.label(egui::RichText::new(t!("profile.navigate_enter_load_del_delete")).small().weak());
                 
                 ui.add_space(10.0);
                 
                 ui.horizontal(|ui| {
                     let delete_enabled = self.selected_profile_index.is_some();
-                    if ui.add_enabled(delete_enabled, egui::Button::new("Delete")).clicked() {
-                        if let Some(index) = self.selected_profile_index {
-                            let state = self.state.lock().unwrap();
-                            let profiles = state.profile_manager.list_profiles();
-                            if index < profiles.len() {
-                                let profile_name = &profiles[index];
-                                if let Err(e) = state.profile_manager.delete_profile(profile_name) {
-                                    eprintln!("Failed to delete profile: {}", e);
-                                } else {
-                                    self.selected_profile_index = None;
-                                }
-                            }
-                        }
+                    if ui.add_enabled(delete_enabled, egui::Button::new(t!("profile.delete"))).clicked() {
+                        self.pending_delete = self.selected_profile_index.and_then(|i| profiles.get(i)).cloned();
                     }
                     
-                    if ui.button("Refresh").clicked() {
+                    let rename_enabled = self.selected_profile_index.is_some() && self.renaming.is_none();
+                    if ui.add_enabled(rename_enabled, egui::Button::new(t!("profile.rename"))).clicked() {
+                        if let Some(name) = self.selected_profile_index.and_then(|index| profiles.get(index)) {
+                            self.renaming = Some((name.clone(), name.clone()));
+                            self.rename_error = None;
+                        }
+                    }
+
+                    if ui.button(t!("profile.refresh")).clicked() {
                         // Just refresh the view
                     }
                 });
-            });
+            }));
             
             ui.separator();
             
             ui.vertical(|ui| {
-                ui.heading("Profile Details");
-                
-                let config = {
-                    let state = self.state.lock().unwrap();
-                    state.current_config.clone()
+                ui.heading(t!("profile.profile_details"));
+
+                // A profile picked with the arrow keys isn't loaded until Enter, so show its summary
+                let selected_summary = self.selected_profile_index
+                    .and_then(|index| self.summaries.get(index))
+                    .filter(|_| self.pending_config.is_none())
+                    .cloned();
+                let current_name = self.state.lock().unwrap().current_config.profile_name.clone();
+                if let Some(summary) = selected_summary.filter(|summary| summary.name != current_name) {
+                    summary_details_ui(ui, &summary);
+
+                    ui.add_space(20.0);
+                    if ui.button(t!("profile.load_profile")).clicked() {
+                        let profiles: Vec<String> = self.summaries.iter().map(|summary| summary.name.clone()).collect();
+                        if let Some(index) = self.selected_profile_index {
+                            self.load_profile_at(index, &profiles);
+                        }
+                    }
+                    return;
+                }
+
+                let config = match &self.pending_config {
+                    Some(config) => {
+                        let [r, g, b, _] = theme.text.to_array();
+                        let alpha = (ease_in_out(self.profile_switch_animation.progress()) * 255.0) as u8;
+                        ui.visuals_mut().override_text_color = Some(Color32::from_rgba_unmultiplied(r, g, b, alpha));
+                        ui.ctx().request_repaint_after(Duration::from_millis(16));
+                        config.clone()
+                    },
+                    None => {
+                        let state = self.state.lock().unwrap();
+                        state.current_config.clone()
+                    },
                 };
                 
-                ui.label(format!("Name: {}", config.profile_name));
-                ui.label(format!("Click Area: {}x{}", config.click_area.width, config.click_area.height));
+                ui.label(t!("profile.name_value", name = config.profile_name));
+                ui.label(t!("profile.click_area_size", width = config.click_area.width, height = config.click_area.height));
                 
                 let click_type = if config.click_options.randomize_click_type {
-                    "Random (weighted)".to_string()
+                    t!("profile.random_weighted")
                 } else {
                     format!("{:?}", config.click_options.click_type)
                 };
                 
-                ui.label(format!("Click Type: {}", click_type));
+                ui.label(t!("profile.click_type_value", click_type = click_type));
+
+                if !config.click_options.randomize_click_type && !config.click_options.modifiers.is_empty() {
+                    ui.label(t!("profile.modifiers_value", modifiers = config.click_options.modifiers.label()));
+                }
                 
-                ui.label(format!("Multiple Areas: {}", if config.multi_area.enabled { "Yes" } else { "No" }));
+                ui.label(t!("profile.multiple_areas_value", enabled = if config.multi_area.enabled { t!("profile.yes") } else { t!("profile.no") }));
                 
                 if config.multi_area.enabled {
-                    ui.label(format!("Area Count: {}", config.multi_area.areas.len()));
-                    ui.label(format!("Selection Mode: {:?}", config.multi_area.selection_mode));
+                    ui.label(t!("profile.area_count_value", count = config.multi_area.areas.len()));
+                    ui.label(t!("profile.selection_mode_value", mode = format!("{:?}", config.multi_area.selection_mode)));
+                }
+
+                // Only shown once the metadata matches the profile on display
+                if let Some(metadata) = self.profile_metadata.as_ref()
+                    .filter(|metadata| metadata.path.file_stem().is_some_and(|stem| *stem == *config.profile_name))
+                {
+                    ui.horizontal(|ui| {
+                        ui.label(t!(
+                            "profile.file_size_modified",
+                            size = format!("{:.1}", metadata.file_size_bytes as f32 / 1024.0),
+                            modified = metadata.last_modified.format("%Y-%m-%d %H:%M UTC"),
+                        )).on_hover_text(metadata.path.display().to_string());
+
+                        if ui.small_button(t!("profile.open_in_explorer")).clicked() {
+                            if let Err(e) = paths::reveal_in_file_browser(&metadata.path) {
+                                eprintln!("Failed to open file browser: {}", e);
+                            }
+                        }
+                    });
                 }
                 
                 ui.add_space(20.0);
                 
-                if ui.button("Edit Profile").clicked() {
-                    let mut state = self.state.lock().unwrap();
-                    state.current_view = crate::gui::app::AppView::Areas;
+                ui.horizontal(|ui| {
+                    if ui.button(t!("profile.edit_profile")).clicked() {
+                        let mut state = self.state.lock().unwrap();
+                        state.current_view = crate::gui::app::AppView::Areas;
+                    }
+
+                    let label = if self.comparing { t!("profile.hide_comparison") } else { t!("profile.compare_with_ellipsis") };
+                    if ui.button(label).clicked() {
+                        self.comparing = !self.comparing;
+                    }
+                });
+
+                if self.comparing {
+                    ui.add_space(10.0);
+                    self.compare_ui(ui, &theme, &config);
                 }
             });
         });
+
+        self.delete_confirmation_ui(ui.ctx());
+    }
+}
+
+/// Details of a profile that hasn't been loaded, from its summary
+fn summary_details_ui(ui: &mut Ui, summary: &ProfileSummary) {
+    ui.label(t!("profile.name_value", name = summary.name));
+    ui.label(t!("profile.click_area_size", width = summary.click_area.width, height = summary.click_area.height));
+    ui.label(t!("profile.click_type_value", click_type = summary.click_type_info));
+    ui.label(t!("profile.area_count_value", count = summary.area_count));
+
+    if let Some(modified) = summary.modified {
+        ui.label(t!("profile.modified_value", modified = modified.format("%Y-%m-%d %H:%M UTC")));
+    }
+
+    ui.label(egui::RichText::new(t!("profile.not_loaded_yet_press_enter")).small().weak());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gui::app::ClickerStatus;
+    use crate::modules::preferences::AppPreferences;
+
+    #[test]
+    fn a_profile_switch_never_replaces_the_config_of_a_running_session() {
+        let dir = tempfile::tempdir().unwrap();
+        let state = Arc::new(Mutex::new(AppState::new(dir.path().to_path_buf(), AppPreferences::default())));
+        let mut view = ProfileView::new(Arc::clone(&state));
+        let profiles = vec!["First".to_string(), "Second".to_string()];
+        state.lock().unwrap().profile_manager.save_profile(&Config::new("Second")).unwrap();
+
+        // Still fading in: left alone until the fade ends, unless Start asks for it now
+        view.pending_config = Some(Config::new("First"));
+        view.profile_switch_animation.reset();
+        view.finish_profile_switch(false);
+        assert!(view.pending_config.is_some());
+        view.finish_profile_switch(true);
+        assert_eq!(state.lock().unwrap().current_config.profile_name, "First");
+
+        // Fading in when the session starts: dropped rather than swapped in mid-run
+        view.pending_config = Some(Config::new("Second"));
+        state.lock().unwrap().clicker_status = ClickerStatus::Running;
+        view.finish_profile_switch(true);
+        assert!(view.pending_config.is_none());
+        assert_eq!(state.lock().unwrap().current_config.profile_name, "First");
+
+        // Picked during the session: not loaded at all
+        view.load_profile_at(1, &profiles);
+        assert!(view.pending_config.is_none());
     }
 }
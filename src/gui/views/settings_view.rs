@@ -1,31 +1,173 @@
-use egui::{Ui, ComboBox};
+use egui::{Color32, Ui, ComboBox};
 use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
 
-use crate::gui::app::AppState;
-use crate::modules::config::ClickType;
+use crate::gui::app::{AppState, AppView};
+use crate::gui::components::{self, StatusMessageType};
+use crate::gui::calibration::CalibrationWizard;
+use crate::gui::theme::{AppTheme, OVERRIDABLE_COLORS};
+use crate::modules::config::{ClickType, ClickModifiers, MIN_CLICK_GAP_MS, MAX_CLICK_GAP_MS, MAX_STARTUP_DELAY_SECS};
+use crate::modules::preferences::{HudCorner, ThreadPriority};
+use crate::modules::paths;
+use crate::modules::mouse;
+use crate::modules::dialogs;
+use crate::modules::i18n;
+use crate::modules::sound::{self, SoundAlert};
+use crate::modules::notifications;
+use crate::modules::image_recognition::average_region_color;
+use crate::modules::system_info::SystemInfo;
+use crate::modules::event_log::{EventKind, SessionEvent};
+use crate::modules::webhook::{self, DeliveryResult};
+use crate::t;
 
 pub struct SettingsView {
     state: Arc<Mutex<AppState>>,
+    calibration: CalibrationWizard,
+    preferences_status: Option<(String, StatusMessageType)>,
+    /// Read once when the settings page is first shown
+    system_info: Option<SystemInfo>,
+    /// Result of a "Test Webhook" delivery still in flight
+    webhook_test: Option<Receiver<DeliveryResult>>,
+    webhook_status: Option<(String, StatusMessageType)>,
+    /// The Windows double-click speed, read again each time the settings page is opened
+    system_double_click_ms: Option<u32>,
+    /// Used to notice the page being switched to
+    last_shown_pass: u64,
 }
 
 impl SettingsView {
     pub fn new(state: Arc<Mutex<AppState>>) -> Self {
         Self {
+            calibration: CalibrationWizard::new(Arc::clone(&state)),
             state,
+            preferences_status: None,
+            system_info: None,
+            webhook_test: None,
+            webhook_status: None,
+            system_double_click_ms: None,
+            last_shown_pass: 0,
+        }
+    }
+
+    fn modifier_checkboxes(ui: &mut Ui, modifiers: &mut ClickModifiers) -> bool {
+        ui.horizontal(|ui| {
+            ui.label(t!("settings.modifiers"));
+            let ctrl = ui.checkbox(&mut modifiers.ctrl, t!("settings.ctrl")).changed();
+            let shift = ui.checkbox(&mut modifiers.shift, t!("settings.shift")).changed();
+            let alt = ui.checkbox(&mut modifiers.alt, t!("settings.alt")).changed();
+            ctrl || shift || alt
+        }).inner
+    }
+
+    fn webhook_ui(&mut self, ui: &mut Ui, theme: &AppTheme) {
+        let mut webhook = self.state.lock().unwrap().preferences.webhook.clone();
+        let mut changed = false;
+        let mut edited = false;
+
+        ui.label(t!("settings.post_session_events_as_json"));
+        changed |= ui.checkbox(&mut webhook.enabled, t!("settings.enable_webhook")).changed();
+
+        ui.horizontal(|ui| {
+            ui.label(t!("settings.url"));
+            let response = ui.add(egui::TextEdit::singleline(&mut webhook.url).hint_text("https://example.com/hook"));
+            edited |= response.changed();
+            changed |= response.lost_focus();
+        });
+
+        ui.horizontal(|ui| {
+            ui.label(t!("settings.authorization_header"));
+            let mut auth = webhook.auth_header.clone().unwrap_or_default();
+            let response = ui.add(egui::TextEdit::singleline(&mut auth).password(true).hint_text("Bearer <token>"));
+            if response.changed() {
+                webhook.auth_header = (!auth.trim().is_empty()).then_some(auth);
+                edited = true;
+            }
+            changed |= response.lost_focus();
+        });
+
+        ui.add_space(4.0);
+        ui.label(t!("settings.send_these_events"));
+        egui::Grid::new("webhook_events").num_columns(3).spacing([8.0, 4.0]).show(ui, |ui| {
+            for (i, kind) in EventKind::ALL.into_iter().enumerate() {
+                let mut selected = webhook.on_events.contains(&kind);
+                if ui.checkbox(&mut selected, format!("{:?}", kind)).changed() {
+                    if selected {
+                        webhook.on_events.push(kind);
+                    } else {
+                        webhook.on_events.retain(|k| *k != kind);
+                    }
+                    changed = true;
+                }
+                if i % 3 == 2 {
+                    ui.end_row();
+                }
+            }
+        });
+
+        if edited || changed {
+            let mut state = self.state.lock().unwrap();
+            state.preferences.webhook = webhook.clone();
+            // Text fields are saved when they lose focus rather than on every keystroke
+            if changed {
+                state.save_preferences();
+            }
+        }
+
+        ui.add_space(4.0);
+
+        if let Some(result) = self.webhook_test.as_ref().and_then(|rx| rx.try_recv().ok()) {
+            self.state.lock().unwrap().record_webhook_delivery(&result);
+            let message_type = if result.is_success() { StatusMessageType::Success } else { StatusMessageType::Error };
+            self.webhook_status = Some((t!("settings.test_event_result", result = result.summary()), message_type));
+            self.webhook_test = None;
+        }
+
+        let testing = self.webhook_test.is_some();
+        let can_test = !testing && !webhook.url.trim().is_empty();
+        if ui.add_enabled(can_test, egui::Button::new(t!("settings.test_webhook"))).clicked() {
+            let (tx, rx) = mpsc::channel();
+            let url = webhook.url.trim().to_string();
+            let auth_header = webhook.auth_header.clone();
+            thread::spawn(move || {
+                let event = SessionEvent::new(EventKind::Started, Some("Test event from Mouse Clicker".to_string()));
+                let _ = tx.send(webhook::post_event(&url, &event, auth_header.as_deref()));
+            });
+            self.webhook_test = Some(rx);
+            self.webhook_status = Some((t!("settings.sending_test_event"), StatusMessageType::Info));
+        }
+
+        if testing {
+            ui.ctx().request_repaint();
+        }
+
+        if let Some((message, message_type)) = &self.webhook_status {
+            components::status_message(ui, theme, message, *message_type);
         }
     }
 
     pub fn ui(&mut self, ui: &mut Ui) {
-        ui.heading("Settings");
+        ui.heading(t!("settings.settings"));
 
-        let mut config = {
+        // A skipped pass means the page wasn't shown last frame
+        let pass = ui.ctx().cumulative_pass_nr();
+        if self.system_double_click_ms.is_none() || pass > self.last_shown_pass + 1 {
+            self.system_double_click_ms = Some(mouse::system_double_click_ms());
+        }
+        self.last_shown_pass = pass;
+
+        let (mut config, theme, locked) = {
             let state = self.state.lock().unwrap();
-            state.current_config.clone()
+            (state.current_config.clone(), state.theme.clone(), state.is_config_locked())
         };
 
-        ui.collapsing("Click Type", |ui| {
+        if locked {
+            components::config_locked_banner(ui, &theme);
+        }
+
+        ui.add_enabled_ui(!locked, |ui| ui.collapsing(t!("settings.click_type_2"), |ui| {
             let mut randomize = config.click_options.randomize_click_type;
-            if ui.checkbox(&mut randomize, "Randomize Click Type").changed() {
+            if ui.checkbox(&mut randomize, t!("settings.randomize_click_type")).changed() {
                 config.click_options.randomize_click_type = randomize;
 
                 let mut state = self.state.lock().unwrap();
@@ -34,26 +176,30 @@ impl SettingsView {
 
             if !randomize {
                 ui.horizontal(|ui| {
-                    ui.label("Click Type:");
+                    ui.label(t!("settings.click_type"));
                     ComboBox::new("click_type", "Click Type")
                         .selected_text(format!("{:?}", config.click_options.click_type))
                         .show_ui(ui, |ui| {
                             let mut changed = false;
                             let mut click_type = config.click_options.click_type;
 
-                            if ui.selectable_value(&mut click_type, ClickType::Single, "Single").changed() {
+                            if ui.selectable_value(&mut click_type, ClickType::Single, t!("settings.single")).changed() {
+                                changed = true;
+                            }
+
+                            if ui.selectable_value(&mut click_type, ClickType::Double, t!("settings.double")).changed() {
                                 changed = true;
                             }
 
-                            if ui.selectable_value(&mut click_type, ClickType::Double, "Double").changed() {
+                            if ui.selectable_value(&mut click_type, ClickType::Triple, t!("settings.triple")).changed() {
                                 changed = true;
                             }
 
-                            if ui.selectable_value(&mut click_type, ClickType::Right, "Right").changed() {
+                            if ui.selectable_value(&mut click_type, ClickType::Right, t!("settings.right")).changed() {
                                 changed = true;
                             }
 
-                            if ui.selectable_value(&mut click_type, ClickType::Middle, "Middle").changed() {
+                            if ui.selectable_value(&mut click_type, ClickType::Middle, t!("settings.middle")).changed() {
                                 changed = true;
                             }
 
@@ -65,31 +211,78 @@ impl SettingsView {
                             }
                         });
                 });
+
+                let mut modifiers = config.click_options.modifiers;
+                if Self::modifier_checkboxes(ui, &mut modifiers) {
+                    let mut state = self.state.lock().unwrap();
+                    state.current_config.click_options.modifiers = modifiers;
+                }
             } else {
-                ui.heading("Click Type Weights");
+                ui.heading(t!("settings.click_type_weights"));
 
-                for i in 0..config.click_options.click_type_weights.len() {
-                    let (click_type, weight) = &mut config.click_options.click_type_weights[i];
-                    let label = format!("{:?}", click_type);
+                // Every type is listed, even ones missing from older profiles' weight lists
+                for click_type in ClickType::ALL {
+                    let mut weight = config.click_options.click_type_weight(click_type);
 
                     ui.horizontal(|ui| {
-                        ui.label(format!("{} Weight:", label));
-                        if ui.add(egui::Slider::new(weight, 0.0..=1.0).text("")).changed() {
+                        ui.label(t!("settings.click_type_weight", click_type = format!("{:?}", click_type)));
+                        if ui.add(egui::Slider::new(&mut weight, 0.0..=1.0).text("")).changed() {
                             let mut state = self.state.lock().unwrap();
-                            state.current_config.click_options.click_type_weights[i].1 = *weight;
+                            state.current_config.click_options.set_click_type_weight(click_type, weight);
+                        }
+                    });
+                }
+
+                ui.add_space(10.0);
+                ui.heading(t!("settings.modifier_variants"));
+
+                let mut variants = config.click_options.modifier_weights.clone();
+                let mut changed = false;
+                let mut remove_index = None;
+
+                if variants.is_empty() {
+                    ui.label(t!("settings.no_modifier_keys_are_held_while"));
+                }
+
+                for (i, (modifiers, weight)) in variants.iter_mut().enumerate() {
+                    ui.horizontal(|ui| {
+                        changed |= Self::modifier_checkboxes(ui, modifiers);
+                        ui.label(t!("settings.weight"));
+                        changed |= ui.add(egui::Slider::new(weight, 0.0..=1.0).text("")).changed();
+                        if ui.small_button(t!("settings.remove")).clicked() {
+                            remove_index = Some(i);
                         }
                     });
                 }
+
+                if ui.button(t!("settings.add_variant")).clicked() {
+                    // Keep plain clicks as an explicit choice once variants are in use
+                    if variants.is_empty() {
+                        variants.push((ClickModifiers::default(), 0.7));
+                    }
+                    variants.push((ClickModifiers { ctrl: true, ..Default::default() }, 0.3));
+                    changed = true;
+                }
+
+                if let Some(index) = remove_index {
+                    variants.remove(index);
+                    changed = true;
+                }
+
+                if changed {
+                    let mut state = self.state.lock().unwrap();
+                    state.current_config.click_options.modifier_weights = variants;
+                }
             }
-        });
+        }));
 
-        ui.collapsing("Timing Settings", |ui| {
+        ui.add_enabled_ui(!locked, |ui| ui.collapsing(t!("settings.timing_settings"), |ui| {
             let mut timing = config.click_timing.clone();
             let mut changed = false;
 
             ui.horizontal(|ui| {
-                ui.label("Min Delay (seconds):");
-                if ui.add(egui::Slider::new(&mut timing.min_delay, 0.1..=60.0).text("s")).changed() {
+                ui.label(t!("settings.min_delay_seconds"));
+                if ui.add(egui::Slider::new(&mut timing.min_delay, 0.1..=60.0).text(t!("settings.s"))).changed() {
                     changed = true;
 
                     // Ensure min_delay <= max_delay
@@ -100,59 +293,650 @@ impl SettingsView {
             });
 
             ui.horizontal(|ui| {
-                ui.label("Max Delay (seconds):");
-                if ui.add(egui::Slider::new(&mut timing.max_delay, timing.min_delay..=60.0).text("s")).changed() {
+                ui.label(t!("settings.max_delay_seconds"));
+                if ui.add(egui::Slider::new(&mut timing.max_delay, timing.min_delay..=60.0).text(t!("settings.s"))).changed() {
                     changed = true;
                 }
             });
 
             ui.horizontal(|ui| {
-                ui.label("Click Duration Mean (ms):");
-                if ui.add(egui::Slider::new(&mut timing.click_duration_mean, 10.0..=200.0).text("ms")).changed() {
+                ui.label(t!("settings.click_duration_mean_ms"));
+                if ui.add(egui::Slider::new(&mut timing.click_duration_mean, 10.0..=200.0).text(t!("settings.ms"))).changed() {
                     changed = true;
                 }
             });
 
             ui.horizontal(|ui| {
-                ui.label("Click Duration Std Dev (ms):");
-                if ui.add(egui::Slider::new(&mut timing.click_duration_std_dev, 1.0..=50.0).text("ms")).changed() {
+                ui.label(t!("settings.click_duration_std_dev_ms"));
+                if ui.add(egui::Slider::new(&mut timing.click_duration_std_dev, 1.0..=50.0).text(t!("settings.ms"))).changed() {
                     changed = true;
                 }
             });
 
+            let system_label = match self.system_double_click_ms {
+                Some(ms) => t!("settings.use_system_setting_ms", ms = ms),
+                None => t!("settings.use_system_setting"),
+            };
+            if ui.checkbox(&mut timing.use_system_double_click_time, system_label)
+                .on_hover_text(t!("settings.gaps_between_presses_follow_the_windows"))
+                .changed()
+            {
+                changed = true;
+            }
+
+            ui.add_enabled_ui(!timing.use_system_double_click_time, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(t!("settings.multi_click_gap_mean_ms"));
+                    if ui.add(egui::Slider::new(&mut timing.double_click_gap.mean, MIN_CLICK_GAP_MS..=MAX_CLICK_GAP_MS).text(t!("settings.ms"))).changed() {
+                        changed = true;
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label(t!("settings.multi_click_gap_std_dev_ms"));
+                    if ui.add(egui::Slider::new(&mut timing.double_click_gap.std_dev, 0.0..=100.0).text(t!("settings.ms"))).changed() {
+                        changed = true;
+                    }
+                });
+            });
+
+            ui.horizontal(|ui| {
+                let mut limited = timing.max_cpm.is_some();
+                if ui.checkbox(&mut limited, t!("settings.limit_clicks_per_minute")).changed() {
+                    timing.max_cpm = if limited { Some(400.0) } else { None };
+                    changed = true;
+                }
+
+                if let Some(max_cpm) = &mut timing.max_cpm {
+                    if ui.add(egui::Slider::new(max_cpm, 1.0..=1000.0).text(t!("settings.cpm"))).changed() {
+                        changed = true;
+                    }
+                }
+            });
+
             ui.horizontal(|ui| {
-                ui.label("Double Click Gap (ms):");
-                if ui.add(egui::Slider::new(&mut timing.double_click_gap, 50..=500).text("ms")).changed() {
+                ui.label(t!("settings.latency_compensation_ms", ms = format!("{:.0}", timing.latency_compensation_ms)));
+                if ui.button(t!("settings.calibrate")).clicked() {
+                    self.calibration.open = true;
+                }
+                if timing.latency_compensation_ms > 0.0 && ui.button(t!("settings.clear")).clicked() {
+                    timing.latency_compensation_ms = 0.0;
                     changed = true;
                 }
             });
 
+            ui.horizontal(|ui| {
+                if ui.checkbox(&mut timing.reaction_delay.enabled, t!("settings.reaction_delay"))
+                    .on_hover_text(t!("settings.wait_a_random_time_after_the"))
+                    .changed()
+                {
+                    changed = true;
+                }
+
+                let reaction = &mut timing.reaction_delay;
+                ui.add_enabled_ui(reaction.enabled, |ui| {
+                    if ui.add(egui::DragValue::new(&mut reaction.min_ms).range(0..=reaction.max_ms).suffix(" ms")).changed() {
+                        changed = true;
+                    }
+                    ui.label(t!("settings.to"));
+                    if ui.add(egui::DragValue::new(&mut reaction.max_ms).range(reaction.min_ms..=2000).suffix(" ms")).changed() {
+                        changed = true;
+                    }
+                });
+            });
+
+            ui.collapsing(t!("settings.per_type_duration_overrides"), |ui| {
+                egui::Grid::new("duration_overrides_grid")
+                    .num_columns(4)
+                    .spacing([16.0, 4.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.label(t!("settings.click_type_2"));
+                        ui.label(t!("settings.override"));
+                        ui.label(t!("settings.mean_ms"));
+                        ui.label(t!("settings.std_dev_ms"));
+                        ui.end_row();
+
+                        for click_type in ClickType::ALL {
+                            ui.label(format!("{:?}", click_type));
+
+                            let mut enabled = timing.click_duration_overrides.contains_key(&click_type);
+                            if ui.checkbox(&mut enabled, "").changed() {
+                                if enabled {
+                                    let defaults = (timing.click_duration_mean, timing.click_duration_std_dev);
+                                    timing.click_duration_overrides.insert(click_type, defaults);
+                                } else {
+                                    timing.click_duration_overrides.remove(&click_type);
+                                }
+                                changed = true;
+                            }
+
+                            if let Some((mean, std_dev)) = timing.click_duration_overrides.get_mut(&click_type) {
+                                changed |= ui.add(egui::DragValue::new(mean).speed(1.0).range(10.0..=200.0)).changed();
+                                changed |= ui.add(egui::DragValue::new(std_dev).speed(0.5).range(1.0..=50.0)).changed();
+                            } else {
+                                ui.label("-");
+                                ui.label("-");
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+
             if changed {
                 let mut state = self.state.lock().unwrap();
                 state.current_config.click_timing = timing;
             }
+        }));
+
+        ui.add_enabled_ui(!locked, |ui| ui.collapsing(t!("settings.burst_mode"), |ui| {
+            let mut burst = config.burst.clone();
+            let mut changed = false;
+
+            if ui.checkbox(&mut burst.enabled, t!("settings.click_in_bursts")).changed() {
+                changed = true;
+            }
+
+            ui.add_enabled_ui(burst.enabled, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(t!("settings.clicks_per_burst"));
+                    if ui.add(egui::Slider::new(&mut burst.clicks_per_burst, 2..=20)).changed() {
+                        changed = true;
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label(t!("settings.delay_between_burst_clicks"));
+                    if ui.add(egui::Slider::new(&mut burst.intra_burst_delay_ms, 10..=500).text(t!("settings.ms"))).changed() {
+                        changed = true;
+                    }
+                });
+
+                ui.label(egui::RichText::new(t!("settings.the_normal_click_delay_is_applied")).italics());
+            });
+
+            if changed {
+                let mut state = self.state.lock().unwrap();
+                state.current_config.burst = burst;
+            }
+        }));
+
+        ui.add_enabled_ui(!locked, |ui| ui.collapsing(t!("settings.mouse_movement"), |ui| {
+            let mut movement = config.mouse_movement.clone();
+            let mut changed = false;
+
+            ui.horizontal(|ui| {
+                ui.label(t!("settings.step_size"));
+                if ui.add(egui::DragValue::new(&mut movement.min_step_px).range(1.0..=movement.max_step_px).suffix(" px")).changed() {
+                    changed = true;
+                }
+                ui.label(t!("settings.to"));
+                if ui.add(egui::DragValue::new(&mut movement.max_step_px).range(movement.min_step_px..=200.0).suffix(" px")).changed() {
+                    changed = true;
+                }
+            });
+
+            ui.horizontal(|ui| {
+                ui.label(t!("settings.time_per_step"));
+                if ui.add(egui::Slider::new(&mut movement.step_time_ms, 1..=20).text(t!("settings.ms"))).changed() {
+                    changed = true;
+                }
+            });
+
+            ui.horizontal(|ui| {
+                ui.label(t!("settings.longest_move"));
+                if ui.add(egui::Slider::new(&mut movement.max_move_time_ms, 20..=1000).text(t!("settings.ms"))).changed() {
+                    changed = true;
+                }
+            });
+
+            ui.label(egui::RichText::new(t!("settings.steps_grow_with_distance_so_long")).small().color(theme.muted_text));
+
+            if changed {
+                let mut state = self.state.lock().unwrap();
+                state.current_config.mouse_movement = movement;
+            }
+        }));
+
+        ui.add_enabled_ui(!locked, |ui| ui.collapsing(t!("settings.anti_detection"), |ui| {
+            let mut anti_detection = config.anti_detection.clone();
+            let mut changed = false;
+
+            if ui.checkbox(&mut anti_detection.enabled, t!("settings.jitter_polling_sleeps_with_os_timers")).changed() {
+                changed = true;
+            }
+
+            ui.add_enabled_ui(anti_detection.enabled, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(t!("settings.timer_jitter"));
+                    if ui.add(egui::Slider::new(&mut anti_detection.os_timer_jitter_ms, 1..=50).text(t!("settings.ms_2"))).changed() {
+                        changed = true;
+                    }
+                });
+            });
+
+            if changed {
+                let mut state = self.state.lock().unwrap();
+                state.current_config.anti_detection = anti_detection;
+            }
+        }));
+
+        ui.add_enabled_ui(!locked, |ui| ui.collapsing(t!("settings.safety"), |ui| {
+            let mut safety = config.safety.clone();
+            let mut changed = false;
+
+            if ui.checkbox(&mut safety.dead_man_enabled, t!("settings.require_periodic_presence_confirmation")).changed() {
+                changed = true;
+            }
+
+            ui.add_enabled_ui(safety.dead_man_enabled, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(t!("settings.ask_every"));
+                    if ui.add(egui::Slider::new(&mut safety.dead_man_interval_minutes, 1..=240).text(t!("settings.min"))).changed() {
+                        changed = true;
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label(t!("settings.stop_if_unanswered_after"));
+                    if ui.add(egui::Slider::new(&mut safety.dead_man_timeout_secs, 10..=300).text(t!("settings.s"))).changed() {
+                        changed = true;
+                    }
+                });
+            });
+
+            ui.add_space(8.0);
+
+            let mut dry_run = self.state.lock().unwrap().dry_run;
+            if ui.checkbox(&mut dry_run, t!("settings.dry_run_go_through_the_motions")).changed() {
+                self.state.lock().unwrap().dry_run = dry_run;
+            }
+            ui.label(egui::RichText::new(t!("settings.for_checking_areas_and_timing_counters")).small().weak());
+
+            if changed {
+                self.state.lock().unwrap().current_config.safety = safety;
+            }
+        }));
+
+        ui.add_enabled_ui(!locked, |ui| ui.collapsing(t!("settings.start_condition"), |ui| {
+            let mut enabled = config.start_condition.is_some();
+            let mut condition = config.start_condition.clone().unwrap_or_default();
+            let mut startup_delay = config.startup_delay_secs;
+            let mut changed = false;
+
+            ui.horizontal(|ui| {
+                ui.label(t!("settings.startup_delay"));
+                changed |= ui.add(egui::DragValue::new(&mut startup_delay).range(0..=MAX_STARTUP_DELAY_SECS).suffix(" s")).changed();
+            });
+            ui.label(egui::RichText::new(t!("settings.time_to_switch_to_the_target_window")).small().weak());
+            ui.add_space(8.0);
+
+            if ui.checkbox(&mut enabled, t!("settings.wait_for_a_screen_region_to")).changed() {
+                changed = true;
+            }
+            ui.label(egui::RichText::new(t!("settings.the_first_click_waits_until_the")).small().weak());
+
+            ui.add_enabled_ui(enabled, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(t!("settings.region"));
+                    changed |= ui.add(egui::DragValue::new(&mut condition.x).prefix("x ")).changed();
+                    changed |= ui.add(egui::DragValue::new(&mut condition.y).prefix("y ")).changed();
+                    changed |= ui.add(egui::DragValue::new(&mut condition.width).range(1..=4096).prefix("w ")).changed();
+                    changed |= ui.add(egui::DragValue::new(&mut condition.height).range(1..=4096).prefix("h ")).changed();
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label(t!("settings.target_color"));
+                    changed |= egui::color_picker::color_edit_button_srgb(ui, &mut condition.target_color).changed();
+
+                    if ui.button(t!("settings.sample_current_color")).clicked() {
+                        match average_region_color(condition.x, condition.y, condition.width, condition.height) {
+                            Ok(color) => {
+                                condition.target_color = color;
+                                changed = true;
+                            },
+                            Err(e) => eprintln!("Failed to sample region color: {}", e),
+                        }
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label(t!("settings.tolerance"));
+                    changed |= ui.add(egui::Slider::new(&mut condition.tolerance, 0..=128)).changed();
+                });
+            });
+
+            if changed {
+                let mut state = self.state.lock().unwrap();
+                state.current_config.start_condition = enabled.then_some(condition);
+                state.current_config.startup_delay_secs = startup_delay;
+            }
+        }));
+
+        ui.collapsing(t!("settings.appearance"), |ui| {
+            let current_language = self.state.lock().unwrap().preferences.language.clone();
+            let current_code = if current_language.is_empty() { i18n::DEFAULT_LANGUAGE } else { current_language.as_str() };
+            let languages = i18n::available_languages();
+            let current_name = languages.iter()
+                .find(|(code, _)| code == current_code)
+                .map(|(_, name)| name.clone())
+                .unwrap_or_else(|| current_code.to_string());
+
+            ui.horizontal(|ui| {
+                ui.label(t!("settings.language"));
+                ComboBox::from_id_salt("ui_language")
+                    .selected_text(current_name)
+                    .show_ui(ui, |ui| {
+                        for (code, name) in &languages {
+                            if ui.selectable_label(code == current_code, name).clicked() && code != current_code {
+                                i18n::set_language(code);
+                                let mut state = self.state.lock().unwrap();
+                                state.preferences.language = code.clone();
+                                state.save_preferences();
+                            }
+                        }
+                    });
+            });
+            ui.add_space(8.0);
+
+            ui.label(egui::RichText::new(t!("settings.change_individual_colors_of_the_current")).small().weak());
+            ui.add_space(4.0);
+
+            egui::Grid::new("theme_colors").num_columns(3).spacing([8.0, 4.0]).show(ui, |ui| {
+                for (key, label) in OVERRIDABLE_COLORS {
+                    let mut state = self.state.lock().unwrap();
+                    let Some(current) = state.theme.color(key) else { continue };
+                    let [r, g, b, _] = current.to_array();
+                    let mut rgb = [r, g, b];
+
+                    ui.label(label);
+                    let mut new_color = None;
+                    if egui::color_picker::color_edit_button_srgb(ui, &mut rgb).changed() {
+                        state.preferences.color_overrides.insert(key.to_string(), rgb);
+                        new_color = Some(Color32::from_rgb(rgb[0], rgb[1], rgb[2]));
+                    }
+
+                    let overridden = state.preferences.color_overrides.contains_key(key);
+                    if ui.add_enabled(overridden, egui::Button::new(t!("settings.revert_to_default"))).clicked() {
+                        state.preferences.color_overrides.remove(key);
+                        new_color = AppTheme::preset(state.is_dark_mode).color(key);
+                    }
+                    ui.end_row();
+
+                    if let Some(color) = new_color {
+                        if let Some(slot) = state.theme.color_mut(key) {
+                            *slot = color;
+                        }
+                        state.theme.apply_to_ctx(ui.ctx());
+                        state.preferences.custom_theme = Some(state.theme.clone());
+                        state.save_preferences();
+                    }
+                }
+            });
         });
 
-        ui.collapsing("Application Settings", |ui| {
+        ui.collapsing(t!("settings.application_settings"), |ui| {
             let mut is_dark_mode = {
                 let state = self.state.lock().unwrap();
                 state.is_dark_mode
             };
 
-            if ui.checkbox(&mut is_dark_mode, "Dark Mode").changed() {
+            if ui.checkbox(&mut is_dark_mode, t!("settings.dark_mode")).changed() {
                 let mut state = self.state.lock().unwrap();
                 state.is_dark_mode = is_dark_mode;
                 // Note: The actual theme change is handled in the app.rs file
             }
 
+            let location = paths::location();
+            ui.horizontal(|ui| {
+                ui.label(t!("settings.data_directory"));
+                ui.label(egui::RichText::new(location.dir.display().to_string()).monospace());
+                if location.portable {
+                    ui.label(egui::RichText::new(t!("settings.portable")).weak());
+                }
+            });
+            if ui.button(t!("settings.open_in_explorer")).clicked() {
+                if let Err(e) = paths::open_data_dir() {
+                    eprintln!("Failed to open data directory: {}", e);
+                }
+            }
+
+            ui.add_space(10.0);
+
+            let mut click_event_log = {
+                let state = self.state.lock().unwrap();
+                state.preferences.click_event_log
+            };
+
+            if ui.checkbox(&mut click_event_log, t!("settings.log_every_click_to_a_session")).changed() {
+                let mut state = self.state.lock().unwrap();
+                state.preferences.click_event_log = click_event_log;
+                state.save_preferences();
+            }
+            ui.label(egui::RichText::new(t!("settings.takes_effect_the_next_time_clicking")).small().weak());
+
+            ui.add_space(10.0);
+
+            // Includes the full color scheme, so the exported file restores the theme as well
+            if ui.button(t!("settings.export_preferences")).clicked() {
+                if let Some(dir) = dialogs::pick_folder("Export preferences to") {
+                    let path = dir.join("app_prefs.json");
+                    let state = self.state.lock().unwrap();
+                    let mut preferences = state.preferences.clone();
+                    preferences.custom_theme = Some(state.theme.clone());
+
+                    self.preferences_status = Some(match preferences.save(&path) {
+                        Ok(()) => (t!("settings.exported_preferences_to", path = path.display()), StatusMessageType::Success),
+                        Err(e) => (t!("settings.failed_to_export_preferences", error = e), StatusMessageType::Error),
+                    });
+                }
+            }
+
+            if let Some((message, message_type)) = &self.preferences_status {
+                components::status_message(ui, &theme, message, *message_type);
+            }
+
+            ui.add_space(10.0);
+
+            if ui.button(t!("settings.repeat_tutorial")).clicked() {
+                let mut state = self.state.lock().unwrap();
+                state.preferences.onboarding_complete = false;
+                state.preferences.onboarding_step = 0;
+                state.current_view = AppView::Profiles;
+                state.save_preferences();
+            }
+
             ui.add_space(10.0);
 
-            if ui.button("Reset to Defaults").clicked() {
+            if ui.add_enabled(!locked, egui::Button::new(t!("settings.reset_to_defaults"))).clicked() {
                 let mut state = self.state.lock().unwrap();
                 let profile_name = state.current_config.profile_name.clone();
                 state.current_config = crate::modules::config::Config::default();
                 state.current_config.profile_name = profile_name;
             }
         });
+
+        ui.collapsing(t!("settings.sound_alerts"), |ui| {
+            let mut sounds = self.state.lock().unwrap().preferences.sound_alerts.clone();
+            let mut changed = false;
+
+            egui::Grid::new("sound_alerts").num_columns(2).spacing([8.0, 4.0]).show(ui, |ui| {
+                let rows: [(&mut bool, String, SoundAlert); 3] = [
+                    (&mut sounds.on_stop, t!("settings.when_a_session_stops"), SoundAlert::Stopped),
+                    (&mut sounds.on_error, t!("settings.when_an_error_occurs"), SoundAlert::Error),
+                    (&mut sounds.on_target_found, t!("settings.when_a_target_is_found"), SoundAlert::TargetFound),
+                ];
+                for (enabled, label, alert) in rows {
+                    changed |= ui.checkbox(enabled, label).changed();
+                    if ui.small_button(t!("settings.test")).clicked() {
+                        sound::play(alert);
+                    }
+                    ui.end_row();
+                }
+            });
+
+            ui.add_space(4.0);
+            let mut desktop_notifications = self.state.lock().unwrap().preferences.desktop_notifications;
+            let response = ui.add_enabled(
+                notifications::AVAILABLE,
+                egui::Checkbox::new(&mut desktop_notifications, t!("settings.desktop_notification_when_a_session")),
+            ).on_disabled_hover_text(t!("settings.this_build_was_made_without_desktop"));
+            changed |= response.changed();
+
+            if changed {
+                let mut state = self.state.lock().unwrap();
+                state.preferences.sound_alerts = sounds;
+                state.preferences.desktop_notifications = desktop_notifications;
+                state.save_preferences();
+            }
+        });
+
+        ui.collapsing(t!("settings.performance"), |ui| {
+            let mut priority = self.state.lock().unwrap().preferences.thread_priority;
+
+            ui.horizontal(|ui| {
+                ui.label(t!("settings.clicker_thread_priority"));
+                ComboBox::from_id_salt("thread_priority")
+                    .selected_text(format!("{:?}", priority))
+                    .show_ui(ui, |ui| {
+                        for option in ThreadPriority::ALL {
+                            if ui.selectable_value(&mut priority, option, format!("{:?}", option)).changed() {
+                                let mut state = self.state.lock().unwrap();
+                                state.preferences.thread_priority = priority;
+                                state.save_preferences();
+                            }
+                        }
+                    });
+            });
+            ui.label(egui::RichText::new(t!("settings.higher_priority_tightens_click_timing")).small().weak());
+
+            if let Some(warning) = priority.warning() {
+                ui.colored_label(theme.warning, warning.to_string());
+            }
+        });
+
+        ui.collapsing(t!("settings.integrations"), |ui| {
+            self.webhook_ui(ui, &theme);
+        });
+
+        ui.collapsing(t!("settings.hud_overlay"), |ui| {
+            let (mut hud, mut repositioning) = {
+                let state = self.state.lock().unwrap();
+                (state.preferences.hud.clone(), state.hud_repositioning)
+            };
+            let mut changed = false;
+
+            ui.label(t!("settings.a_small_status_window_shown_in"));
+            ui.add_space(4.0);
+
+            changed |= ui.checkbox(&mut hud.enabled, t!("settings.show_hud_when_minimized")).changed();
+            changed |= ui.checkbox(&mut hud.hide_when_stopped, t!("settings.hide_when_the_clicker_is_stopped")).changed();
+
+            ui.horizontal(|ui| {
+                ui.label(t!("settings.corner"));
+                ComboBox::new("hud_corner", "")
+                    .selected_text(format!("{:?}", hud.corner))
+                    .show_ui(ui, |ui| {
+                        for corner in [HudCorner::TopLeft, HudCorner::TopRight, HudCorner::BottomLeft, HudCorner::BottomRight] {
+                            if ui.selectable_value(&mut hud.corner, corner, format!("{:?}", corner)).changed() {
+                                // Picking a corner replaces any position the HUD was dragged to
+                                hud.position = None;
+                                changed = true;
+                            }
+                        }
+                    });
+            });
+
+            ui.horizontal(|ui| {
+                ui.label(t!("settings.opacity"));
+                changed |= ui.add(egui::Slider::new(&mut hud.opacity, 0.2..=1.0)).changed();
+            });
+
+            ui.horizontal(|ui| {
+                let label = if repositioning { "Done Positioning" } else { "Reposition HUD" };
+                if ui.add_enabled(hud.enabled, egui::Button::new(label)).clicked() {
+                    repositioning = !repositioning;
+                    changed = true;
+                }
+
+                if hud.position.is_some() && ui.button(t!("settings.reset_position")).clicked() {
+                    hud.position = None;
+                    changed = true;
+                }
+            });
+
+            if repositioning {
+                ui.label(t!("settings.drag_the_hud_to_where_you"));
+            }
+
+            if changed {
+                let mut state = self.state.lock().unwrap();
+                state.preferences.hud = hud;
+                state.hud_repositioning = repositioning;
+                state.save_preferences();
+            }
+        });
+
+        ui.collapsing(t!("settings.session_overlay"), |ui| {
+            let mut overlay_enabled = {
+                let state = self.state.lock().unwrap();
+                state.preferences.overlay_enabled
+            };
+
+            ui.label(t!("settings.an_always_on_top_window_with"));
+            ui.add_space(4.0);
+
+            if ui.checkbox(&mut overlay_enabled, t!("settings.show_session_overlay")).changed() {
+                let mut state = self.state.lock().unwrap();
+                state.preferences.overlay_enabled = overlay_enabled;
+                state.save_preferences();
+            }
+
+            if ui.link(t!("settings.more_overlay_options")).clicked() {
+                let mut state = self.state.lock().unwrap();
+                state.current_view = AppView::Overlay;
+                state.view_transition.reset();
+            }
+        });
+
+        ui.add_space(16.0);
+        self.system_info_ui(ui);
+
+        self.calibration.show(ui.ctx());
+    }
+
+    /// Read-only card describing the user's environment, for bug reports
+    fn system_info_ui(&mut self, ui: &mut Ui) {
+        let theme = self.state.lock().unwrap().theme.clone();
+        let pixels_per_point = ui.ctx().native_pixels_per_point().unwrap_or(1.0);
+        let info = self.system_info.get_or_insert_with(|| SystemInfo::collect(pixels_per_point));
+
+        theme.card_frame().show(ui, |ui| {
+            ui.label(egui::RichText::new(t!("settings.system_information")).strong());
+            ui.add_space(4.0);
+
+            egui::Grid::new("system_info").num_columns(2).spacing([12.0, 4.0]).show(ui, |ui| {
+                ui.label(t!("settings.os"));
+                ui.label(&info.os);
+                ui.end_row();
+
+                ui.label(t!("settings.screen_resolution"));
+                ui.label(info.resolution.map(|(w, h)| format!("{}x{}", w, h)).unwrap_or_else(|| t!("settings.unknown")));
+                ui.end_row();
+
+                ui.label(t!("settings.dpi_scaling"));
+                ui.label(format!("{}%", info.dpi_percent));
+                ui.end_row();
+
+                ui.label(t!("settings.cpu_cores"));
+                ui.label(info.cpu_cores.map(|cores| cores.to_string()).unwrap_or_else(|| t!("settings.unknown")));
+                ui.end_row();
+            });
+
+            ui.add_space(4.0);
+            if ui.button(t!("settings.copy_to_clipboard")).clicked() {
+                ui.ctx().copy_text(info.report());
+            }
+        });
     }
 }
@@ -1,33 +1,176 @@
 use egui::{Ui, Color32, Stroke, Vec2, Pos2, RichText};
+use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
+use std::sync::atomic::Ordering;
+use std::sync::mpsc::Receiver;
 use std::time::Instant;
+use chrono::{Duration, Local};
 
 use crate::gui::app::AppState;
+use crate::gui::clicker::ClickerPerfStats;
 use crate::gui::theme::AppTheme;
-use crate::gui::components;
+use crate::gui::components::{self, StatusMessageType};
+use crate::modules::event_log::{export_session_events, ClickEvent, EventKind, SessionEvent};
+use crate::modules::paths;
+use crate::modules::ui::{format_duration, format_duration_precise, format_thousands};
+use crate::t;
+
+/// Number of most recent events shown in the event log
+const EVENT_LOG_DISPLAY_LIMIT: usize = 200;
+
+/// Differences smaller than this fraction show as unchanged in the session comparison
+const COMPARISON_TOLERANCE: f32 = 0.05;
+
+/// A session's results, saved to compare against a later session
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SessionSnapshot {
+    pub profile_name: String,
+    pub click_count: u64,
+    pub elapsed_secs: f32,
+    pub avg_cpm: f32,
+    pub peak_cpm: f32,
+    /// 0 to 1, how close the average rate stayed to the peak
+    pub consistency: f32,
+}
 
 pub struct StatsView {
     state: Arc<Mutex<AppState>>,
     click_history: Vec<(f32, f32)>, // (time, clicks per minute)
+    export_status: Option<(String, StatusMessageType)>,
+    click_events: Receiver<ClickEvent>,
+    /// Clicks received in the last minute, oldest first
+    recent_clicks: VecDeque<ClickEvent>,
+    perf_stats: ClickerPerfStats,
+    /// Whether the current session is shown side by side with the latest snapshot
+    comparing: bool,
 }
 
 impl StatsView {
     pub fn new(state: Arc<Mutex<AppState>>) -> Self {
+        let click_events = state.lock().unwrap().click_events.subscribe();
         Self {
             state,
             click_history: Vec::new(),
+            export_status: None,
+            click_events,
+            recent_clicks: VecDeque::new(),
+            perf_stats: ClickerPerfStats::default(),
+            comparing: false,
+        }
+    }
+
+    /// Average rate, peak rate and consistency over the click history, if there is any
+    fn rate_insights(&self) -> Option<(f32, f32, f32)> {
+        if self.click_history.is_empty() {
+            return None;
+        }
+        let avg_cpm = self.click_history.iter().map(|(_, cpm)| *cpm).sum::<f32>() / self.click_history.len() as f32;
+        let max_cpm = self.click_history.iter().map(|(_, cpm)| *cpm).fold(0.0, f32::max);
+        let consistency = if max_cpm > 0.0 { 1.0 - ((max_cpm - avg_cpm) / max_cpm).min(1.0) } else { 0.0 };
+        Some((avg_cpm, max_cpm, consistency))
+    }
+
+    /// The session so far. A stopped session is measured up to its last data point.
+    fn current_snapshot(&self, click_count: u64, elapsed_seconds: f32) -> SessionSnapshot {
+        let (avg_cpm, peak_cpm, consistency) = self.rate_insights().unwrap_or_default();
+        let elapsed_secs = if elapsed_seconds > 0.0 {
+            elapsed_seconds
+        } else {
+            self.click_history.last().map_or(0.0, |(time, _)| *time)
+        };
+
+        SessionSnapshot {
+            profile_name: self.state.lock().unwrap().current_config.profile_name.clone(),
+            click_count,
+            elapsed_secs,
+            avg_cpm,
+            peak_cpm,
+            consistency,
+        }
+    }
+
+    /// The current session beside a saved one, with each difference colored by whether it's an improvement
+    fn comparison_ui(ui: &mut Ui, theme: &AppTheme, current: &SessionSnapshot, saved: &SessionSnapshot) {
+        // Higher is better for every rate; session length is shown without judging it
+        let rows: [(String, String, String, f32, f32, bool); 6] = [
+            (t!("stats.profile"), current.profile_name.clone(), saved.profile_name.clone(), 0.0, 0.0, false),
+            (t!("stats.total_clicks_label"), format_thousands(current.click_count), format_thousands(saved.click_count),
+                current.click_count as f32, saved.click_count as f32, true),
+            (t!("stats.running_time_label"), format_duration(current.elapsed_secs), format_duration(saved.elapsed_secs),
+                current.elapsed_secs, saved.elapsed_secs, false),
+            (t!("stats.average_cpm"), format!("{:.1}", current.avg_cpm), format!("{:.1}", saved.avg_cpm), current.avg_cpm, saved.avg_cpm, true),
+            (t!("stats.peak_cpm"), format!("{:.1}", current.peak_cpm), format!("{:.1}", saved.peak_cpm), current.peak_cpm, saved.peak_cpm, true),
+            (t!("stats.consistency"), format!("{:.1}%", current.consistency * 100.0), format!("{:.1}%", saved.consistency * 100.0),
+                current.consistency, saved.consistency, true),
+        ];
+
+        egui::Grid::new("session_comparison")
+            .num_columns(4)
+            .spacing([24.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("");
+                ui.label(RichText::new(t!("stats.current_session")).strong());
+                ui.label(RichText::new(t!("stats.snapshot")).strong());
+                ui.label(RichText::new(t!("stats.change")).strong());
+                ui.end_row();
+
+                for (i, (label, current_text, saved_text, current_value, saved_value, judged)) in rows.into_iter().enumerate() {
+                    ui.label(RichText::new(label).strong());
+                    ui.label(current_text);
+                    ui.label(saved_text);
+
+                    if i == 0 {
+                        ui.label("");
+                    } else {
+                        let change = if saved_value > 0.0 {
+                            (current_value - saved_value) / saved_value
+                        } else if current_value > 0.0 {
+                            1.0
+                        } else {
+                            0.0
+                        };
+                        let (text, color) = if change.abs() < COMPARISON_TOLERANCE {
+                            ("≈".to_string(), theme.muted_text)
+                        } else {
+                            let color = match (judged, change > 0.0) {
+                                (false, _) => theme.muted_text,
+                                (true, true) => theme.success,
+                                (true, false) => theme.error,
+                            };
+                            (format!("{:+.0}%", change * 100.0), color)
+                        };
+                        ui.label(RichText::new(text).color(color));
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// Pull in clicks broadcast by the clicker thread. Called every frame so the queue never backs up.
+    pub fn receive_click_events(&mut self) {
+        self.recent_clicks.extend(self.click_events.try_iter());
+
+        let cutoff = Local::now() - Duration::minutes(1);
+        while self.recent_clicks.front().is_some_and(|event| event.timestamp < cutoff) {
+            self.recent_clicks.pop_front();
         }
     }
 
+    /// Timings measured by the clicker thread, shown in the Performance Profiling card
+    pub fn set_performance_stats(&mut self, stats: ClickerPerfStats) {
+        self.perf_stats = stats;
+    }
+
     pub fn ui(&mut self, ui: &mut Ui) {
         let theme = {
             let state = self.state.lock().unwrap();
             state.theme.clone()
         };
 
-        let (click_count, start_time, elapsed_seconds, clicks_per_minute) = {
+        let (click_count, start_time, elapsed_seconds, clicks_per_minute, rate_limited_count, skipped_presses, bursts) = {
             let state = self.state.lock().unwrap();
-            let click_count = state.click_count;
+            let click_count = state.click_count.load(Ordering::Relaxed);
             let start_time = state.start_time;
 
             let (elapsed_seconds, clicks_per_minute) = if let Some(start) = start_time {
@@ -43,7 +186,11 @@ impl StatsView {
                 (0.0, 0.0)
             };
 
-            (click_count, start_time, elapsed_seconds, clicks_per_minute)
+            // Only shown when burst mode is or was in use this session
+            let bursts = (state.current_config.burst.enabled || state.bursts_completed > 0)
+                .then_some(state.bursts_completed);
+
+            (click_count, start_time, elapsed_seconds, clicks_per_minute, state.rate_limited_count, state.skipped_double_presses, bursts)
         };
 
         // Update click history
@@ -56,7 +203,7 @@ impl StatsView {
         }
 
         // Current Session Stats Card
-        components::card(ui, &theme, "Current Session Statistics", |ui| {
+        components::card(ui, &theme, &t!("stats.current_session_statistics"), |ui| {
             ui.add_space(8.0);
 
             // Create a grid layout for stats
@@ -66,30 +213,51 @@ impl StatsView {
                 .striped(true)
                 .show(ui, |ui| {
                     // Total Clicks
-                    ui.label(RichText::new("Total Clicks:").strong());
-                    ui.label(RichText::new(format!("{}", click_count)).size(18.0));
+                    ui.label(RichText::new(t!("stats.total_clicks")).strong());
+                    ui.label(RichText::new(format_thousands(click_count)).size(18.0));
                     ui.end_row();
 
+                    if let Some(bursts) = bursts {
+                        ui.label(RichText::new(t!("stats.bursts_completed")).strong());
+                        ui.label(RichText::new(format!("{}", bursts)).size(18.0));
+                        ui.end_row();
+                    }
+
                     // Running Time
-                    ui.label(RichText::new("Running Time:").strong());
+                    ui.label(RichText::new(t!("stats.running_time")).strong());
                     if let Some(_) = start_time {
-                        ui.label(RichText::new(format!("{:.1} seconds", elapsed_seconds)).size(18.0));
+                        ui.label(RichText::new(format_duration(elapsed_seconds)).size(18.0))
+                            .on_hover_text(format_duration_precise(elapsed_seconds));
                     } else {
-                        ui.label(RichText::new("Not running").italics());
+                        ui.label(RichText::new(t!("stats.not_running")).italics());
                     }
                     ui.end_row();
 
                     // Clicks per Minute
-                    ui.label(RichText::new("Clicks per Minute:").strong());
+                    ui.label(RichText::new(t!("stats.clicks_per_minute")).strong());
                     if let Some(_) = start_time {
                         ui.label(RichText::new(format!("{:.2}", clicks_per_minute)).size(18.0));
                     } else {
-                        ui.label(RichText::new("N/A").italics());
+                        ui.label(RichText::new(t!("stats.n_a")).italics());
                     }
                     ui.end_row();
 
+                    // Clicks in the last minute
+                    ui.label(RichText::new(t!("stats.last_minute")).strong());
+                    ui.label(RichText::new(t!("stats.clicks_count", count = self.recent_clicks.len())).size(18.0));
+                    ui.end_row();
+
+                    // Only shown when recent clicks used a reaction delay
+                    let reactions: Vec<u64> = self.recent_clicks.iter().filter_map(|click| click.reaction_delay_ms).collect();
+                    if !reactions.is_empty() {
+                        let average = reactions.iter().sum::<u64>() as f32 / reactions.len() as f32;
+                        ui.label(RichText::new(t!("stats.avg_reaction_delay")).strong());
+                        ui.label(RichText::new(t!("stats.value_ms", value = format!("{:.0}", average))).size(18.0));
+                        ui.end_row();
+                    }
+
                     // Efficiency (just a fun metric)
-                    ui.label(RichText::new("Efficiency:").strong());
+                    ui.label(RichText::new(t!("stats.efficiency")).strong());
                     if start_time.is_some() && clicks_per_minute > 0.0 {
                         let efficiency = (clicks_per_minute / 300.0).min(1.0) * 100.0; // Assuming 300 CPM is max
                         let efficiency_text = format!("{:.1}%", efficiency);
@@ -102,28 +270,62 @@ impl StatsView {
                         };
                         ui.label(RichText::new(efficiency_text).color(color).size(18.0));
                     } else {
-                        ui.label(RichText::new("N/A").italics());
+                        ui.label(RichText::new(t!("stats.n_a")).italics());
                     }
                     ui.end_row();
                 });
 
+            if rate_limited_count > 0 {
+                ui.add_space(8.0);
+                ui.label(RichText::new(t!("stats.rate_limited_times", count = rate_limited_count)).color(theme.warning));
+            }
+
+            if skipped_presses > 0 {
+                ui.add_space(8.0);
+                ui.label(RichText::new(t!("stats.skipped_clicks_button_held", count = skipped_presses)).color(theme.warning));
+            }
+
             ui.add_space(16.0);
 
-            // Reset button
+            let latest_snapshot = self.state.lock().unwrap().session_snapshots.back().cloned();
+
+            // Reset and snapshot buttons
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                if components::accent_button(ui, &theme, "Reset Statistics") {
+                if components::secondary_button(ui, &theme, &t!("stats.save_snapshot"), Some(&t!("stats.keep_these_results_to_compare"))) {
+                    let snapshot = self.current_snapshot(click_count, elapsed_seconds);
+                    self.state.lock().unwrap().save_session_snapshot(snapshot);
+                }
+
+                if latest_snapshot.is_some() {
+                    let label = if self.comparing { t!("stats.hide_comparison") } else { t!("stats.compare") };
+                    if components::secondary_button(ui, &theme, &label, None) {
+                        self.comparing = !self.comparing;
+                    }
+                }
+
+                if components::accent_button(ui, &theme, &t!("stats.reset_statistics"), Some(&t!("stats.clear_the_click_count_and_restart"))) {
                     let mut state = self.state.lock().unwrap();
-                    state.click_count = 0;
+                    state.click_count.store(0, Ordering::Relaxed);
+                    state.rate_limited_count = 0;
+                    state.skipped_double_presses = 0;
+                    state.bursts_completed = 0;
+                    self.recent_clicks.clear();
                     state.start_time = Some(Instant::now());
                     self.click_history.clear();
                 }
             });
+
+            if let Some(saved) = latest_snapshot.filter(|_| self.comparing) {
+                ui.add_space(12.0);
+                let current = self.current_snapshot(click_count, elapsed_seconds);
+                Self::comparison_ui(ui, &theme, &current, &saved);
+            }
         });
 
         ui.add_space(16.0);
 
         // Click Rate Graph Card
-        components::card(ui, &theme, "Click Rate Over Time", |ui| {
+        components::card(ui, &theme, &t!("stats.click_rate_over_time"), |ui| {
             // Draw the graph
             let graph_height = 220.0;
             let graph_width = ui.available_width();
@@ -240,7 +442,7 @@ impl StatsView {
                     painter.text(
                         Pos2::new(x_pos, response.rect.bottom() + 12.0),
                         egui::Align2::CENTER_TOP,
-                        format!("{:.0}s", time_value),
+                        format_duration(time_value),
                         egui::FontId::proportional(12.0),
                         theme.text,
                     );
@@ -277,31 +479,27 @@ impl StatsView {
         ui.add_space(16.0);
 
         // Performance Insights Card
-        components::card(ui, &theme, "Performance Insights", |ui| {
-            if !self.click_history.is_empty() && clicks_per_minute > 0.0 {
-                // Calculate some insights
-                let avg_cpm = self.click_history.iter().map(|(_, cpm)| *cpm).sum::<f32>() / self.click_history.len() as f32;
-                let max_cpm = self.click_history.iter().map(|(_, cpm)| *cpm).fold(0.0, f32::max);
-                let consistency = 1.0 - ((max_cpm - avg_cpm) / max_cpm).min(1.0);
+        components::card(ui, &theme, &t!("stats.performance_insights"), |ui| {
+            if let Some((avg_cpm, max_cpm, consistency)) = self.rate_insights().filter(|_| clicks_per_minute > 0.0) {
 
                 ui.add_space(8.0);
 
                 // Progress bars for different metrics
-                ui.label(RichText::new("Average Clicks per Minute").strong());
-                components::progress_bar(ui, &theme, avg_cpm / 300.0, Some(&format!("{:.1} CPM", avg_cpm)));
+                ui.label(RichText::new(t!("stats.average_clicks_per_minute")).strong());
+                components::progress_bar(ui, &theme, avg_cpm / 300.0, Some(&t!("stats.value_cpm", value = format!("{:.1}", avg_cpm))));
                 ui.add_space(8.0);
 
-                ui.label(RichText::new("Peak Performance").strong());
-                components::progress_bar(ui, &theme, max_cpm / 300.0, Some(&format!("{:.1} CPM", max_cpm)));
+                ui.label(RichText::new(t!("stats.peak_performance")).strong());
+                components::progress_bar(ui, &theme, max_cpm / 300.0, Some(&t!("stats.value_cpm", value = format!("{:.1}", max_cpm))));
                 ui.add_space(8.0);
 
-                ui.label(RichText::new("Consistency").strong());
+                ui.label(RichText::new(t!("stats.consistency")).strong());
                 components::progress_bar(ui, &theme, consistency, Some(&format!("{:.1}%", consistency * 100.0)));
                 ui.add_space(8.0);
 
                 // Performance tips
                 ui.add_space(8.0);
-                ui.label(RichText::new("Tips for Improvement").strong().size(16.0));
+                ui.label(RichText::new(t!("stats.tips_for_improvement")).strong().size(16.0));
                 ui.add_space(4.0);
 
                 let tips = if avg_cpm < 60.0 {
@@ -332,9 +530,138 @@ impl StatsView {
                 }
             } else {
                 ui.add_space(10.0);
-                ui.label("Start a clicking session to see performance insights.");
+                ui.label(t!("stats.start_a_clicking_session_to_see"));
+                ui.add_space(10.0);
+            }
+        });
+
+        ui.add_space(16.0);
+        self.performance_profiling_ui(ui, &theme);
+
+        ui.add_space(16.0);
+        self.event_log_ui(ui, &theme);
+    }
+
+    /// Why the measured click rate differs from the one the delays suggest
+    fn performance_profiling_ui(&self, ui: &mut Ui, theme: &AppTheme) {
+        let stats = self.perf_stats;
+
+        components::card(ui, theme, &t!("stats.performance_profiling"), |ui| {
+            if stats.configured_expected_cpm <= 0.0 {
+                ui.add_space(10.0);
+                ui.label(t!("stats.start_a_clicking_session_to_see_2"));
                 ui.add_space(10.0);
+                return;
             }
+
+            ui.add_space(8.0);
+            egui::Grid::new("perf_grid")
+                .num_columns(2)
+                .spacing([40.0, 8.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label(RichText::new(t!("stats.expected_cpm")).strong());
+                    ui.label(RichText::new(format!("{:.1}", stats.configured_expected_cpm)).size(18.0));
+                    ui.end_row();
+
+                    ui.label(RichText::new(t!("stats.actual_cpm_last_60s")).strong());
+                    ui.label(RichText::new(format!("{:.1}", stats.actual_cpm_last_60s)).size(18.0));
+                    ui.end_row();
+
+                    ui.label(RichText::new(t!("stats.avg_movement_time")).strong());
+                    ui.label(RichText::new(t!("stats.value_ms", value = format!("{:.1}", stats.movement_time_ms))).size(18.0));
+                    ui.end_row();
+
+                    ui.label(RichText::new(t!("stats.avg_click_time")).strong());
+                    ui.label(RichText::new(t!("stats.value_ms", value = format!("{:.1}", stats.click_time_ms))).size(18.0));
+                    ui.end_row();
+
+                    ui.label(RichText::new(t!("stats.avg_sleep_overhead")).strong());
+                    ui.label(RichText::new(t!("stats.value_ms", value = format!("{:.1}", stats.sleep_overhead_ms))).size(18.0));
+                    ui.end_row();
+                });
+
+            ui.add_space(8.0);
+            ui.label(RichText::new(t!("stats.movement_click_and_sleep_overhead_all"))
+                .small()
+                .color(theme.muted_text));
         });
     }
+
+    fn event_log_ui(&mut self, ui: &mut Ui, theme: &AppTheme) {
+        ui.collapsing(t!("stats.event_log"), |ui| {
+            let events: Vec<SessionEvent> = {
+                let state = self.state.lock().unwrap();
+                let start = state.event_log.len().saturating_sub(EVENT_LOG_DISPLAY_LIMIT);
+                state.event_log[start..].to_vec()
+            };
+
+            ui.horizontal(|ui| {
+                if ui.add_enabled(!events.is_empty(), egui::Button::new(t!("stats.export_log"))).clicked() {
+                    let state = self.state.lock().unwrap();
+                    let path = paths::data_dir().join(format!("event_log_{}.jsonl", Local::now().format("%Y%m%d_%H%M%S")));
+                    self.export_status = Some(match export_session_events(&state.event_log, &path) {
+                        Ok(()) => (t!("stats.exported_events_to", count = state.event_log.len(), path = path.display()), StatusMessageType::Success),
+                        Err(e) => (t!("stats.failed_to_export_log", error = e), StatusMessageType::Error),
+                    });
+                }
+                ui.label(RichText::new(t!("stats.showing_last_events", count = events.len())).color(theme.muted_text));
+            });
+
+            if let Some((message, message_type)) = &self.export_status {
+                components::status_message(ui, theme, message, *message_type);
+            }
+
+            egui::ScrollArea::vertical().max_height(240.0).stick_to_bottom(true).show(ui, |ui| {
+                for event in &events {
+                    let color = match event.kind {
+                        EventKind::Error | EventKind::TargetNotFound => theme.error,
+                        EventKind::Stopped | EventKind::Paused | EventKind::ThresholdAdjusted => theme.warning,
+                        EventKind::Started | EventKind::StartConditionMet | EventKind::Resumed | EventKind::TargetFound => theme.success,
+                        EventKind::ClickPerformed | EventKind::ProfilesExported | EventKind::ProfilesImported => theme.text,
+                    };
+
+                    ui.horizontal(|ui| {
+                        let local_time = event.timestamp.with_timezone(&Local);
+                        ui.label(RichText::new(local_time.format("%H:%M:%S").to_string()).color(theme.muted_text).monospace());
+                        ui.label(RichText::new(format!("{:?}", event.kind)).color(color).strong());
+                        if let Some(detail) = &event.detail {
+                            ui.label(detail);
+                        }
+                    });
+                }
+
+                if events.is_empty() {
+                    ui.label(RichText::new(t!("stats.no_events_yet")).italics());
+                }
+            });
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(click_count: u64) -> SessionSnapshot {
+        SessionSnapshot {
+            profile_name: "Overnight".to_string(),
+            click_count,
+            elapsed_secs: 259_200.0,
+            avg_cpm: 1000.0,
+            peak_cpm: 1200.0,
+            consistency: 0.9,
+        }
+    }
+
+    #[test]
+    fn click_counts_above_u32_max_survive_serde() {
+        for click_count in [u32::MAX as u64 + 1, 5_000_000_000, u64::MAX] {
+            let json = serde_json::to_string(&snapshot(click_count)).unwrap();
+            assert!(json.contains(&format!("\"click_count\":{}", click_count)), "{}", json);
+
+            let restored: SessionSnapshot = serde_json::from_str(&json).unwrap();
+            assert_eq!(restored, snapshot(click_count));
+        }
+    }
 }
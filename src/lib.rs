@@ -0,0 +1,5 @@
+//! Mouse Clicker: click configuration, timing, input and image matching in `modules`,
+//! and the egui front end built on them in `gui`
+
+pub mod modules;
+pub mod gui;
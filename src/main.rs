@@ -1,18 +1,17 @@
-mod modules;
-mod gui;
-
 use eframe::egui;
-use std::path::Path;
 
-use modules::error::Result;
-use gui::MouseClickerApp;
+use mouse::modules;
+use mouse::modules::error::Result;
+use mouse::modules::paths;
+use mouse::gui::MouseClickerApp;
 
 fn main() -> Result<()> {
-    // Create profiles directory if it doesn't exist
-    let profiles_dir = "profiles";
-    if !Path::new(profiles_dir).exists() {
-        std::fs::create_dir_all(profiles_dir)?;
-    }
+    // Resolve where profiles, targets, logs and settings live before anything reads them
+    let portable = std::env::args().any(|arg| arg == paths::PORTABLE_FLAG);
+    let location = paths::init(portable);
+    println!("Data directory: {:?}{}", location.dir, if location.portable { " (portable)" } else { "" });
+
+    std::fs::create_dir_all(paths::profiles_dir())?;
 
     // Set up the native options
     let mut native_options = eframe::NativeOptions::default();
@@ -0,0 +1,91 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Mutex;
+
+/// Fan-out channel where every subscriber receives its own copy of each message
+///
+/// Each subscriber gets an unbounded queue, so slow consumers never cause messages
+/// to be dropped for themselves or anyone else.
+pub struct Broadcaster<T: Clone> {
+    subscribers: Mutex<Vec<Sender<T>>>,
+}
+
+impl<T: Clone> Broadcaster<T> {
+    pub fn new() -> Self {
+        Self {
+            subscribers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Start receiving every message sent from now on
+    pub fn subscribe(&self) -> Receiver<T> {
+        let (sender, receiver) = mpsc::channel();
+        self.subscribers.lock().unwrap().push(sender);
+        receiver
+    }
+
+    /// Deliver a message to all subscribers, forgetting any whose receiver was dropped
+    pub fn send(&self, message: T) {
+        self.subscribers
+            .lock()
+            .unwrap()
+            .retain(|subscriber| subscriber.send(message.clone()).is_ok());
+    }
+}
+
+impl<T: Clone> Default for Broadcaster<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn every_live_subscriber_gets_every_message() {
+        let broadcaster = Broadcaster::new();
+        let first = broadcaster.subscribe();
+        let dropped = broadcaster.subscribe();
+        let last = broadcaster.subscribe();
+
+        broadcaster.send(1);
+        drop(dropped);
+        broadcaster.send(2);
+        broadcaster.send(3);
+
+        assert_eq!(first.try_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(last.try_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+        // The dropped subscriber was forgotten on the first send after it went away
+        assert_eq!(broadcaster.subscribers.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn subscribers_only_get_messages_sent_after_subscribing() {
+        let broadcaster = Broadcaster::new();
+        let early = broadcaster.subscribe();
+        broadcaster.send("before");
+        let late = broadcaster.subscribe();
+        broadcaster.send("after");
+
+        assert_eq!(early.try_iter().collect::<Vec<_>>(), vec!["before", "after"]);
+        assert_eq!(late.try_iter().collect::<Vec<_>>(), vec!["after"]);
+    }
+
+    #[test]
+    fn messages_from_another_thread_reach_every_subscriber() {
+        let broadcaster = Arc::new(Broadcaster::new());
+        let receivers: Vec<Receiver<u64>> = (0..4).map(|_| broadcaster.subscribe()).collect();
+
+        let sender = Arc::clone(&broadcaster);
+        thread::spawn(move || (0..1000).for_each(|i| sender.send(i)))
+            .join()
+            .unwrap();
+
+        for receiver in receivers {
+            assert_eq!(receiver.try_iter().collect::<Vec<_>>(), (0..1000).collect::<Vec<_>>());
+        }
+    }
+}
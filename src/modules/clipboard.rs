@@ -0,0 +1,176 @@
+use image::RgbaImage;
+use windows::Win32::Foundation::{HGLOBAL, HWND};
+use windows::Win32::Graphics::Gdi::{BITMAPINFOHEADER, BI_BITFIELDS, BI_RGB};
+use windows::Win32::System::DataExchange::{CloseClipboard, GetClipboardData, IsClipboardFormatAvailable, OpenClipboard};
+use windows::Win32::System::Memory::{GlobalLock, GlobalSize, GlobalUnlock};
+use crate::modules::error::{AppError, Result};
+
+/// Clipboard format for a DIB with a BITMAPV5HEADER. Windows synthesizes it from any copied bitmap.
+const CF_DIBV5: u32 = 17;
+
+/// Whether the clipboard currently holds an image
+pub fn has_image() -> bool {
+    unsafe { IsClipboardFormatAvailable(CF_DIBV5).is_ok() }
+}
+
+/// Read the image on the clipboard, or `None` if it holds something else
+pub fn read_image() -> Result<Option<RgbaImage>> {
+    if !has_image() {
+        return Ok(None);
+    }
+
+    unsafe {
+        OpenClipboard(HWND(0))
+            .map_err(|e| AppError::SystemError(format!("Failed to open clipboard: {}", e)))?;
+
+        let image = read_dib();
+        let _ = CloseClipboard();
+        image.map(Some)
+    }
+}
+
+/// Copy the DIB out of an open clipboard and decode it
+unsafe fn read_dib() -> Result<RgbaImage> {
+    let handle = GetClipboardData(CF_DIBV5)
+        .map_err(|e| AppError::SystemError(format!("Failed to read clipboard image: {}", e)))?;
+    let memory = HGLOBAL(handle.0 as *mut _);
+
+    let data = GlobalLock(memory) as *const u8;
+    if data.is_null() {
+        return Err(AppError::SystemError("Failed to lock clipboard image".to_string()));
+    }
+
+    // The clipboard owns the memory, so take a copy before unlocking it
+    let bytes = std::slice::from_raw_parts(data, GlobalSize(memory)).to_vec();
+    let _ = GlobalUnlock(memory);
+
+    dib_to_image(&bytes)
+}
+
+/// Decode a packed DIB (header followed by pixels) holding a 24 or 32 bits per pixel image
+fn dib_to_image(dib: &[u8]) -> Result<RgbaImage> {
+    let header_len = std::mem::size_of::<BITMAPINFOHEADER>();
+    if dib.len() < header_len {
+        return Err(AppError::ParseError("Clipboard image is truncated".to_string()));
+    }
+
+    // Every DIB header version starts with the fields of BITMAPINFOHEADER
+    let header = unsafe { std::ptr::read_unaligned(dib.as_ptr() as *const BITMAPINFOHEADER) };
+    let width = header.biWidth;
+    let height = header.biHeight.unsigned_abs();
+    let bottom_up = header.biHeight > 0;
+    let bytes_per_pixel = match header.biBitCount {
+        32 => 4,
+        24 => 3,
+        bits => return Err(AppError::ParseError(format!("Unsupported clipboard image depth: {} bits", bits))),
+    };
+
+    if width <= 0 || height == 0 {
+        return Err(AppError::ParseError("Clipboard image is empty".to_string()));
+    }
+
+    // A plain BITMAPINFOHEADER with bitfields is followed by the three color masks
+    let compression = header.biCompression;
+    let mut pixels_start = header.biSize as usize;
+    if compression == BI_BITFIELDS.0 && header.biSize as usize == header_len {
+        pixels_start += 12;
+    } else if compression != BI_RGB.0 && compression != BI_BITFIELDS.0 {
+        return Err(AppError::ParseError("Compressed clipboard images aren't supported".to_string()));
+    }
+
+    let width = width as u32;
+    let stride = (width as usize * bytes_per_pixel + 3) & !3;
+    if dib.len() < pixels_start + stride * height as usize {
+        return Err(AppError::ParseError("Clipboard image is truncated".to_string()));
+    }
+
+    let pixels = &dib[pixels_start..];
+    let mut image = RgbaImage::new(width, height);
+    let mut any_alpha = false;
+
+    for y in 0..height {
+        let source_row = if bottom_up { height - 1 - y } else { y };
+        let row = &pixels[source_row as usize * stride..];
+
+        for x in 0..width {
+            let px = &row[x as usize * bytes_per_pixel..];
+            let alpha = if bytes_per_pixel == 4 { px[3] } else { 255 };
+            any_alpha |= alpha != 0;
+            image.put_pixel(x, y, image::Rgba([px[2], px[1], px[0], alpha]));
+        }
+    }
+
+    // Many programs leave the alpha byte at zero, which would make the whole image transparent
+    if !any_alpha {
+        for pixel in image.pixels_mut() {
+            pixel.0[3] = 255;
+        }
+    }
+
+    Ok(image)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A packed DIB: a BITMAPINFOHEADER followed by the given pixel rows
+    fn dib(width: i32, height: i32, bits: u16, compression: u32, pixels: &[u8]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&40u32.to_le_bytes());
+        bytes.extend_from_slice(&width.to_le_bytes());
+        bytes.extend_from_slice(&height.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&bits.to_le_bytes());
+        bytes.extend_from_slice(&compression.to_le_bytes());
+        bytes.extend_from_slice(&[0; 20]);
+        bytes.extend_from_slice(pixels);
+        bytes
+    }
+
+    #[test]
+    fn bottom_up_24_bit_rows_are_flipped() {
+        // 1x2 image, each 3 byte row padded to 4. The bottom row comes first.
+        let pixels = [255, 0, 0, 0, 0, 0, 255, 0];
+        let image = dib_to_image(&dib(1, 2, 24, BI_RGB.0, &pixels)).unwrap();
+
+        assert_eq!(image.dimensions(), (1, 2));
+        assert_eq!(image.get_pixel(0, 0).0, [255, 0, 0, 255]);
+        assert_eq!(image.get_pixel(0, 1).0, [0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn top_down_32_bit_keeps_alpha() {
+        let pixels = [1, 2, 3, 128, 4, 5, 6, 64];
+        let image = dib_to_image(&dib(2, -1, 32, BI_RGB.0, &pixels)).unwrap();
+
+        assert_eq!(image.get_pixel(0, 0).0, [3, 2, 1, 128]);
+        assert_eq!(image.get_pixel(1, 0).0, [6, 5, 4, 64]);
+    }
+
+    #[test]
+    fn all_zero_alpha_is_treated_as_opaque() {
+        let pixels = [1, 2, 3, 0, 4, 5, 6, 0];
+        let image = dib_to_image(&dib(2, 1, 32, BI_RGB.0, &pixels)).unwrap();
+
+        assert!(image.pixels().all(|pixel| pixel.0[3] == 255));
+    }
+
+    #[test]
+    fn bitfield_masks_after_the_header_are_skipped() {
+        let mut pixels = vec![0; 12];
+        pixels.extend_from_slice(&[10, 20, 30, 255]);
+        let image = dib_to_image(&dib(1, 1, 32, BI_BITFIELDS.0, &pixels)).unwrap();
+
+        assert_eq!(image.get_pixel(0, 0).0, [30, 20, 10, 255]);
+    }
+
+    #[test]
+    fn malformed_dibs_are_rejected() {
+        assert!(dib_to_image(&[0; 10]).is_err());
+        assert!(dib_to_image(&dib(2, 2, 32, BI_RGB.0, &[0; 8])).is_err());
+        assert!(dib_to_image(&dib(1, 1, 8, BI_RGB.0, &[0; 4])).is_err());
+        assert!(dib_to_image(&dib(0, 1, 32, BI_RGB.0, &[])).is_err());
+        assert!(dib_to_image(&dib(1, 1, 32, 1, &[0; 4])).is_err());
+    }
+}
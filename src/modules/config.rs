@@ -1,14 +1,18 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 use crate::modules::error::{AppError, Result};
+use crate::modules::paths;
+use crate::modules::toml_format;
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum ClickType {
     Single,
     Double,
     Right,
     Middle,
+    Triple,
 }
 
 impl Default for ClickType {
@@ -17,34 +21,312 @@ impl Default for ClickType {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl ClickType {
+    pub const ALL: [ClickType; 5] = [
+        ClickType::Single,
+        ClickType::Double,
+        ClickType::Triple,
+        ClickType::Right,
+        ClickType::Middle,
+    ];
+
+    // Case-insensitive name as used by environment overrides, e.g. "double"
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|click_type| format!("{:?}", click_type).eq_ignore_ascii_case(name.trim()))
+    }
+
+    // Number of presses that make up the click
+    pub fn press_count(&self) -> u32 {
+        match self {
+            ClickType::Double => 2,
+            ClickType::Triple => 3,
+            _ => 1,
+        }
+    }
+}
+
+// Name the primary click area goes by in logs and warnings
+pub const PRIMARY_AREA_NAME: &str = "Primary area";
+
+/// A rectangle that clicks land in, either centered on its monitor or at an offset from its top-left corner
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ClickArea {
+    #[serde(default)]
+    pub name: String,
     pub width: i32,
     pub height: i32,
     pub centered: bool,
     pub x_offset: i32,
     pub y_offset: i32,
+    // Monitor the area is on; offsets are relative to its top-left corner. 0 is the primary monitor.
+    #[serde(default)]
+    pub monitor_index: u32,
+    // Area whose last click this one is placed relative to; offsets are then from that point
+    #[serde(default)]
+    pub anchor_area_name: Option<String>,
+    // Disabled areas stay in the list but are never picked for a click
+    #[serde(default = "default_area_enabled")]
+    pub enabled: bool,
+}
+
+fn default_area_enabled() -> bool {
+    true
 }
 
 impl Default for ClickArea {
     fn default() -> Self {
         Self {
+            name: String::new(),
             width: 200,
             height: 200,
             centered: true,
             x_offset: 0,
             y_offset: 0,
+            monitor_index: 0,
+            anchor_area_name: None,
+            enabled: true,
+        }
+    }
+}
+
+impl ClickArea {
+    // Name shown in the UI, falling back to the area's position in the list
+    pub fn display_name(&self, index: usize) -> String {
+        if self.name.is_empty() {
+            format!("Area {}", index + 1)
+        } else {
+            self.name.clone()
         }
     }
+
+    // How far the area's rectangle reaches past a screen of the given size, per axis
+    pub fn overflow(&self, (screen_width, screen_height): (i32, i32)) -> (i32, i32) {
+        let (left, top) = if self.centered {
+            ((screen_width - self.width) / 2, (screen_height - self.height) / 2)
+        } else {
+            (self.x_offset, self.y_offset)
+        };
+
+        let overflow_x = (left + self.width - screen_width).max(0) + (-left).max(0);
+        let overflow_y = (top + self.height - screen_height).max(0) + (-top).max(0);
+        (overflow_x, overflow_y)
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+// Problems that don't stop a configuration from running but are likely mistakes
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationWarning {
+    AreaOutOfBounds { area_name: String, overflow_x: i32, overflow_y: i32 },
+    // Areas that anchor to each other in a loop, in anchor order
+    CircularAreaAnchor { area_names: Vec<String> },
+    // A realtime clicker thread can starve other threads, including the mouse and keyboard
+    RealtimePriorityRisk,
+}
+
+impl std::fmt::Display for ValidationWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ValidationWarning::AreaOutOfBounds { area_name, overflow_x, overflow_y } => write!(
+                f,
+                "'{}' extends past the screen by {}px horizontally and {}px vertically",
+                area_name, overflow_x, overflow_y
+            ),
+            ValidationWarning::CircularAreaAnchor { area_names } => write!(
+                f,
+                "Areas anchor to each other in a loop: {} → {}",
+                area_names.join(" → "),
+                area_names[0]
+            ),
+            ValidationWarning::RealtimePriorityRisk => write!(
+                f,
+                "Realtime priority can starve other programs and make the system unresponsive while clicking"
+            ),
+        }
+    }
+}
+
+// Allowed range for a sampled gap between presses (ms)
+pub const MIN_CLICK_GAP_MS: f64 = 40.0;
+pub const MAX_CLICK_GAP_MS: f64 = 600.0;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(from = "ClickGapFormat")]
+pub struct ClickGap {
+    pub mean: f64,     // ms
+    pub std_dev: f64,  // ms
+}
+
+impl Default for ClickGap {
+    fn default() -> Self {
+        Self {
+            mean: 200.0,
+            std_dev: 30.0,
+        }
+    }
+}
+
+// Older profiles stored the gap as a single number of milliseconds
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ClickGapFormat {
+    Legacy(u64),
+    Distribution { mean: f64, std_dev: f64 },
+}
+
+impl From<ClickGapFormat> for ClickGap {
+    fn from(format: ClickGapFormat) -> Self {
+        match format {
+            ClickGapFormat::Legacy(ms) => Self {
+                mean: ms as f64,
+                std_dev: ms as f64 * 0.15,
+            },
+            ClickGapFormat::Distribution { mean, std_dev } => Self { mean, std_dev },
+        }
+    }
+}
+
+/// How long to wait between clicks (in seconds) and how long each press is held (in milliseconds)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ClickTiming {
     pub min_delay: f32,
     pub max_delay: f32,
     pub click_duration_mean: f64,
     pub click_duration_std_dev: f64,
-    pub double_click_gap: u64,  // Time between clicks in a double-click (ms)
+    pub double_click_gap: ClickGap,  // Time between presses in a double or triple click
+    #[serde(default)]
+    pub use_system_double_click_time: bool,  // Derive the gap from the Windows double-click speed instead
+    #[serde(default)]
+    pub click_duration_overrides: HashMap<ClickType, (f64, f64)>,  // (mean, std_dev) per click type
+    #[serde(default)]
+    pub max_cpm: Option<f32>,  // Hard cap on clicks in any 60 second window
+    #[serde(default)]
+    pub latency_compensation_ms: f64,  // Measured click overhead taken off each delay
+    #[serde(default)]
+    pub reaction_delay: ReactionDelayConfig,  // Pause between reaching the target and pressing
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReactionDelayConfig {
+    pub enabled: bool,
+    pub min_ms: u64,
+    pub max_ms: u64,
+}
+
+impl Default for ReactionDelayConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_ms: 80,
+            max_ms: 300,
+        }
+    }
+}
+
+impl ClickTiming {
+    // Get the (mean, std_dev) hold duration for a click type, falling back to the global values
+    pub fn click_duration_for(&self, click_type: ClickType) -> (f64, f64) {
+        self.click_duration_overrides
+            .get(&click_type)
+            .copied()
+            .unwrap_or((self.click_duration_mean, self.click_duration_std_dev))
+    }
+
+    /// Default timing with a different minimum delay between clicks, in seconds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mouse::modules::config::*;
+    /// let timing = ClickTiming::with_min_delay(1.0);
+    /// assert_eq!(timing.min_delay, 1.0);
+    /// assert_eq!(timing.max_delay, ClickTiming::default().max_delay);
+    /// ```
+    pub fn with_min_delay(min_delay: f32) -> Self {
+        ClickTimingBuilder::default().min_delay(min_delay).build()
+    }
+
+    /// Default timing with a different maximum delay between clicks, in seconds
+    pub fn with_max_delay(max_delay: f32) -> Self {
+        ClickTimingBuilder::default().max_delay(max_delay).build()
+    }
+
+    /// Default timing with a different hold duration, in milliseconds
+    pub fn with_click_duration(mean: f64, std_dev: f64) -> Self {
+        ClickTimingBuilder::default().click_duration(mean, std_dev).build()
+    }
+
+    /// Default timing capped at `max_cpm` clicks a minute
+    pub fn with_max_cpm(max_cpm: f32) -> Self {
+        ClickTimingBuilder::default().max_cpm(max_cpm).build()
+    }
+}
+
+/// Builds a `ClickTiming` from its defaults, changing only the fields that are set
+///
+/// # Examples
+///
+/// ```
+/// # use mouse::modules::config::*;
+/// let timing = ClickTimingBuilder::default()
+///     .min_delay(1.0)
+///     .max_delay(5.0)
+///     .click_duration_override(ClickType::Right, 120.0, 10.0)
+///     .build();
+///
+/// assert_eq!((timing.min_delay, timing.max_delay), (1.0, 5.0));
+/// assert_eq!(timing.click_duration_for(ClickType::Right), (120.0, 10.0));
+/// assert_eq!(timing.click_duration_mean, ClickTiming::default().click_duration_mean);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ClickTimingBuilder {
+    timing: ClickTiming,
+}
+
+impl ClickTimingBuilder {
+    pub fn min_delay(mut self, min_delay: f32) -> Self {
+        self.timing.min_delay = min_delay;
+        self
+    }
+
+    pub fn max_delay(mut self, max_delay: f32) -> Self {
+        self.timing.max_delay = max_delay;
+        self
+    }
+
+    pub fn click_duration(mut self, mean: f64, std_dev: f64) -> Self {
+        self.timing.click_duration_mean = mean;
+        self.timing.click_duration_std_dev = std_dev;
+        self
+    }
+
+    pub fn click_duration_override(mut self, click_type: ClickType, mean: f64, std_dev: f64) -> Self {
+        self.timing.click_duration_overrides.insert(click_type, (mean, std_dev));
+        self
+    }
+
+    pub fn double_click_gap(mut self, gap: ClickGap) -> Self {
+        self.timing.double_click_gap = gap;
+        self
+    }
+
+    pub fn max_cpm(mut self, max_cpm: f32) -> Self {
+        self.timing.max_cpm = Some(max_cpm);
+        self
+    }
+
+    pub fn latency_compensation_ms(mut self, latency_compensation_ms: f64) -> Self {
+        self.timing.latency_compensation_ms = latency_compensation_ms;
+        self
+    }
+
+    pub fn reaction_delay(mut self, reaction_delay: ReactionDelayConfig) -> Self {
+        self.timing.reaction_delay = reaction_delay;
+        self
+    }
+
+    pub fn build(self) -> ClickTiming {
+        self.timing
+    }
 }
 
 impl Default for ClickTiming {
@@ -54,16 +336,52 @@ impl Default for ClickTiming {
             max_delay: 38.0,
             click_duration_mean: 80.0,
             click_duration_std_dev: 20.0,
-            double_click_gap: 200,
+            double_click_gap: ClickGap::default(),
+            use_system_double_click_time: false,
+            click_duration_overrides: HashMap::new(),
+            max_cpm: None,
+            latency_compensation_ms: 0.0,
+            reaction_delay: ReactionDelayConfig::default(),
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct ClickModifiers {
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+}
+
+impl ClickModifiers {
+    pub fn is_empty(&self) -> bool {
+        !self.ctrl && !self.shift && !self.alt
+    }
+
+    pub fn label(&self) -> String {
+        let mut keys = Vec::new();
+        if self.ctrl { keys.push("Ctrl"); }
+        if self.shift { keys.push("Shift"); }
+        if self.alt { keys.push("Alt"); }
+
+        if keys.is_empty() {
+            "None".to_string()
+        } else {
+            keys.join("+")
+        }
+    }
+}
+
+/// Which button is clicked and with which modifier keys, fixed or picked at random per click
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ClickOptions {
     pub click_type: ClickType,
     pub randomize_click_type: bool,
     pub click_type_weights: Vec<(ClickType, f32)>,  // (click_type, weight)
+    #[serde(default)]
+    pub modifiers: ClickModifiers,                  // Held during the fixed click type
+    #[serde(default)]
+    pub modifier_weights: Vec<(ClickModifiers, f32)>,  // (modifiers, weight) when randomizing
 }
 
 impl Default for ClickOptions {
@@ -76,7 +394,40 @@ impl Default for ClickOptions {
                 (ClickType::Double, 0.1),
                 (ClickType::Right, 0.1),
                 (ClickType::Middle, 0.1),
+                (ClickType::Triple, 0.0),
             ],
+            modifiers: ClickModifiers::default(),
+            modifier_weights: Vec::new(),
+        }
+    }
+}
+
+impl ClickOptions {
+    // The click type most clicks will use: the fixed type, or the heaviest weighted one when randomizing
+    pub fn dominant_click_type(&self) -> ClickType {
+        if !self.randomize_click_type {
+            return self.click_type;
+        }
+
+        self.click_type_weights
+            .iter()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(click_type, _)| *click_type)
+            .unwrap_or(self.click_type)
+    }
+
+    // Weight of a click type when randomizing; types missing from older profiles count as zero
+    pub fn click_type_weight(&self, click_type: ClickType) -> f32 {
+        self.click_type_weights
+            .iter()
+            .find(|(t, _)| *t == click_type)
+            .map_or(0.0, |(_, weight)| *weight)
+    }
+
+    pub fn set_click_type_weight(&mut self, click_type: ClickType, weight: f32) {
+        match self.click_type_weights.iter_mut().find(|(t, _)| *t == click_type) {
+            Some(entry) => entry.1 = weight,
+            None => self.click_type_weights.push((click_type, weight)),
         }
     }
 }
@@ -94,13 +445,41 @@ impl Default for AreaSelectionMode {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Several click areas used in turn, at random, or at random in proportion to their weights
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct MultiAreaConfig {
     pub areas: Vec<(ClickArea, f32)>,  // (area, weight)
     pub selection_mode: AreaSelectionMode,
     pub enabled: bool,
 }
 
+impl MultiAreaConfig {
+    // Enabled areas with their index in `areas` and their weight
+    pub fn active_areas(&self) -> Vec<(usize, &ClickArea, f32)> {
+        self.areas.iter()
+            .enumerate()
+            .filter(|(_, (area, _))| area.enabled)
+            .map(|(i, (area, weight))| (i, area, *weight))
+            .collect()
+    }
+
+    // Chance of each area being picked for a click, summing to 1. Disabled areas get 0.
+    pub fn normalize_weights(&self) -> Vec<f32> {
+        let active_weight = |(area, weight): &(ClickArea, f32)| if area.enabled { weight.max(0.0) } else { 0.0 };
+        let count = self.areas.iter().filter(|(area, _)| area.enabled).count();
+        let total: f32 = self.areas.iter().map(active_weight).sum();
+
+        match self.selection_mode {
+            AreaSelectionMode::Weighted if total > 0.0 => {
+                self.areas.iter().map(|entry| active_weight(entry) / total).collect()
+            },
+            _ => self.areas.iter()
+                .map(|(area, _)| if area.enabled { 1.0 / count as f32 } else { 0.0 })
+                .collect(),
+        }
+    }
+}
+
 impl Default for MultiAreaConfig {
     fn default() -> Self {
         Self {
@@ -111,13 +490,133 @@ impl Default for MultiAreaConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SafetyConfig {
+    pub dead_man_enabled: bool,        // Periodically ask the user to confirm they're present
+    pub dead_man_interval_minutes: u32,
+    pub dead_man_timeout_secs: u32,    // How long the prompt waits before stopping the run
+}
+
+impl Default for SafetyConfig {
+    fn default() -> Self {
+        Self {
+            dead_man_enabled: false,
+            dead_man_interval_minutes: 30,
+            dead_man_timeout_secs: 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BurstConfig {
+    pub enabled: bool,
+    pub clicks_per_burst: u32,
+    pub intra_burst_delay_ms: u64,     // Pause between clicks inside a burst; the normal delay follows the burst
+}
+
+impl Default for BurstConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            clicks_per_burst: 3,
+            intra_burst_delay_ms: 50,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MouseMovementConfig {
+    pub min_step_px: f32,              // Step length for short moves
+    pub max_step_px: f32,              // Step length for moves across the whole screen
+    pub step_time_ms: u64,             // Pause after each step
+    pub max_move_time_ms: u64,         // Long moves speed up their steps to finish within this
+}
+
+impl Default for MouseMovementConfig {
+    fn default() -> Self {
+        Self {
+            min_step_px: 10.0,
+            max_step_px: 40.0,
+            step_time_ms: 2,
+            max_move_time_ms: 150,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AntiDetectionConfig {
+    pub enabled: bool,
+    pub os_timer_jitter_ms: u64,       // Polling sleeps vary by up to this much either way
+}
+
+impl Default for AntiDetectionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            os_timer_jitter_ms: 15,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ColorCondition {
+    pub x: i32,                        // Screen region whose average color is checked
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub target_color: [u8; 3],
+    pub tolerance: u8,                 // Largest difference allowed in any one channel
+}
+
+impl Default for ColorCondition {
+    fn default() -> Self {
+        Self {
+            x: 0,
+            y: 0,
+            width: 100,
+            height: 20,
+            target_color: [0, 200, 0],
+            tolerance: 20,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ImageClickConfig {
+    pub enabled: bool,                 // Click on a target found on screen instead of inside the click areas
+    pub targets: Vec<String>,          // IDs of the targets looked for; the best match is clicked
+}
+
+impl ColorCondition {
+    pub fn matches(&self, color: [u8; 3]) -> bool {
+        color.iter().zip(self.target_color).all(|(&c, t)| c.abs_diff(t) <= self.tolerance)
+    }
+}
+
+/// Everything about a clicking session, saved as a profile
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Config {
     pub profile_name: String,
     pub click_area: ClickArea,         // Primary click area (for backward compatibility)
     pub click_timing: ClickTiming,
     pub click_options: ClickOptions,
     pub multi_area: MultiAreaConfig,   // Multiple click areas
+    #[serde(default)]
+    pub safety: SafetyConfig,
+    #[serde(default)]
+    pub burst: BurstConfig,
+    #[serde(default)]
+    pub anti_detection: AntiDetectionConfig,
+    #[serde(default)]
+    pub mouse_movement: MouseMovementConfig,
+    #[serde(default)]
+    pub start_condition: Option<ColorCondition>,  // The first click waits until this region shows the target color
+    #[serde(default = "default_startup_delay")]
+    pub startup_delay_secs: u32,  // Countdown after Start, to switch to the target window
+    #[serde(default)]
+    pub image_click: ImageClickConfig,
+    #[serde(skip)]
+    pub dry_run: bool,  // Go through the motions without moving the cursor or clicking; set from AppState::dry_run, never saved to profiles
 }
 
 impl Default for Config {
@@ -128,11 +627,58 @@ impl Default for Config {
             click_timing: ClickTiming::default(),
             click_options: ClickOptions::default(),
             multi_area: MultiAreaConfig::default(),
+            safety: SafetyConfig::default(),
+            burst: BurstConfig::default(),
+            anti_detection: AntiDetectionConfig::default(),
+            mouse_movement: MouseMovementConfig::default(),
+            start_condition: None,
+            startup_delay_secs: default_startup_delay(),
+            image_click: ImageClickConfig::default(),
+            dry_run: false,
         }
     }
 }
 
+// Longest countdown the settings allow before the first click
+pub const MAX_STARTUP_DELAY_SECS: u32 = 30;
+
+fn default_startup_delay() -> u32 {
+    3
+}
+
+// Prefix of the environment variables read by `Config::apply_env_overrides`
+pub const ENV_PREFIX: &str = "MOUSE_CLICKER_";
+
+// Parse `MOUSE_CLICKER_<name>` into `field` if it's set, leaving the field alone and noting a warning if it doesn't parse
+fn env_override<T>(
+    vars: &HashMap<String, String>,
+    name: &str,
+    field: &mut T,
+    parse: impl Fn(&str) -> Option<T>,
+    warnings: &mut Vec<String>,
+) {
+    let var = format!("{}{}", ENV_PREFIX, name);
+    let Some(value) = vars.get(&var) else { return };
+    match parse(value) {
+        Some(parsed) => *field = parsed,
+        None => warnings.push(format!("ignoring {}: '{}' is not a valid value", var, value)),
+    }
+}
+
 impl Config {
+    /// Default settings under a new profile name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mouse::modules::config::*;
+    /// let mut config = Config::new("Fishing");
+    /// config.click_timing.min_delay = 2.5;
+    /// config.click_timing.max_delay = 4.0;
+    ///
+    /// assert_eq!(config.profile_name, "Fishing");
+    /// assert!(!config.multi_area.enabled);
+    /// ```
     pub fn new(profile_name: &str) -> Self {
         Self {
             profile_name: profile_name.to_string(),
@@ -140,23 +686,57 @@ impl Config {
         }
     }
 
+    // The format is chosen by the file extension; anything but .toml is JSON
+    fn is_toml_path(path: &str) -> bool {
+        Path::new(path).extension().is_some_and(|ext| ext.eq_ignore_ascii_case("toml"))
+    }
+
+    /// Write the config to `path`, as TOML if it ends in `.toml` and as JSON otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mouse::modules::config::*;
+    /// let mut config = Config::new("Round trip");
+    /// config.click_timing.max_cpm = Some(45.0);
+    ///
+    /// let dir = tempfile::tempdir()?;
+    /// let path = dir.path().join("round_trip.json");
+    /// let path = path.to_str().unwrap();
+    /// config.save(path)?;
+    ///
+    /// assert_eq!(Config::load(path)?, config);
+    /// # Ok::<(), mouse::modules::error::AppError>(())
+    /// ```
     pub fn save(&self, path: &str) -> Result<()> {
-        let json = serde_json::to_string_pretty(self)
-            .map_err(|e| AppError::ParseError(format!("Failed to serialize config: {}", e)))?;
+        self.save_atomic(Path::new(path))
+    }
 
-        fs::write(path, json)
-            .map_err(|e| AppError::IoError(e))?;
+    /// Write the config like [`Config::save`], through a `.tmp` file that then replaces `path`,
+    /// so a crash mid-write leaves the previous file intact rather than a truncated one
+    pub fn save_atomic(&self, path: &Path) -> Result<()> {
+        let contents = if path.to_str().is_some_and(Self::is_toml_path) {
+            toml_format::to_string(self).map_err(|e| e.to_string())
+        } else {
+            serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+        }.map_err(|e| AppError::ParseError(format!("Failed to serialize config: {}", e)))?;
 
+        paths::write_atomic(path, contents)?;
         Ok(())
     }
 
+    /// Read a config written by [`Config::save`]. A missing file gives the default config.
     pub fn load(path: &str) -> Result<Self> {
         if !Path::new(path).exists() {
             return Ok(Config::default());
         }
 
+        if Self::is_toml_path(path) {
+            return Self::load_toml(path);
+        }
+
         let json = fs::read_to_string(path)
-            .map_err(|e| AppError::IoError(e))?;
+            .map_err(AppError::from)?;
 
         let config = serde_json::from_str(&json)
             .map_err(|e| AppError::ParseError(format!("Failed to deserialize config: {}", e)))?;
@@ -164,7 +744,190 @@ impl Config {
         Ok(config)
     }
 
-    // Add a new click area to the multi-area configuration
+    // Nested structs are written as TOML tables and enums as quoted strings
+    pub fn save_toml(&self, path: &str) -> Result<()> {
+        let toml = toml_format::to_string(self)
+            .map_err(|e| AppError::ParseError(format!("Failed to serialize config: {}", e)))?;
+
+        paths::write_atomic(Path::new(path), toml)?;
+        Ok(())
+    }
+
+    pub fn load_toml(path: &str) -> Result<Self> {
+        let toml = fs::read_to_string(path)?;
+
+        toml_format::from_str(&toml)
+            .map_err(|e| AppError::ParseError(format!("Failed to deserialize config: {}", e)))
+    }
+
+    /// Check that every click area fits on its monitor and that no areas anchor to each other in a loop.
+    /// `screens` holds the physical size of each monitor, primary first; areas on a monitor that isn't
+    /// connected are checked against the primary. Anchored areas move with their anchor, so aren't bounds checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mouse::modules::config::*;
+    /// let mut config = Config::new("Edge");
+    /// config.click_area = ClickArea {
+    ///     width: 300,
+    ///     height: 200,
+    ///     centered: false,
+    ///     x_offset: 1800,
+    ///     y_offset: 100,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// // 180 pixels of the area hang off the right edge of a 1920x1080 screen
+    /// let warnings = config.validate(&[(1920, 1080)]);
+    /// assert_eq!(warnings, vec![ValidationWarning::AreaOutOfBounds {
+    ///     area_name: PRIMARY_AREA_NAME.to_string(),
+    ///     overflow_x: 180,
+    ///     overflow_y: 0,
+    /// }]);
+    /// ```
+    pub fn validate(&self, screens: &[(i32, i32)]) -> Vec<ValidationWarning> {
+        let Some(&primary) = screens.first() else {
+            return Vec::new();
+        };
+
+        let named_areas = std::iter::once((PRIMARY_AREA_NAME.to_string(), &self.click_area))
+            .chain(self.multi_area.areas.iter().enumerate().map(|(i, (area, _))| (area.display_name(i), area)));
+
+        let mut warnings: Vec<ValidationWarning> = named_areas
+            .filter(|(_, area)| area.anchor_area_name.is_none())
+            .filter_map(|(area_name, area)| {
+                let screen = screens.get(area.monitor_index as usize).copied().unwrap_or(primary);
+                let (overflow_x, overflow_y) = area.overflow(screen);
+                (overflow_x > 0 || overflow_y > 0)
+                    .then_some(ValidationWarning::AreaOutOfBounds { area_name, overflow_x, overflow_y })
+            })
+            .collect();
+
+        warnings.extend(self.anchor_cycles().into_iter().map(|area_names| ValidationWarning::CircularAreaAnchor { area_names }));
+        warnings
+    }
+
+    // Each loop of areas anchored to one another, starting from its alphabetically first area
+    fn anchor_cycles(&self) -> Vec<Vec<String>> {
+        let anchor_of = |name: &str| {
+            self.multi_area.areas.iter()
+                .find(|(area, _)| area.name == name)
+                .and_then(|(area, _)| area.anchor_area_name.clone())
+        };
+
+        let mut cycles: Vec<Vec<String>> = Vec::new();
+        for (area, _) in &self.multi_area.areas {
+            if area.name.is_empty() {
+                continue;
+            }
+
+            let mut path = vec![area.name.clone()];
+            let mut next = area.anchor_area_name.clone();
+            while let Some(name) = next {
+                if name == path[0] {
+                    // Only report the loop once, from the area that sorts first
+                    if path.iter().all(|other| path[0] <= *other) && !cycles.contains(&path) {
+                        cycles.push(path);
+                    }
+                    break;
+                }
+                if path.contains(&name) {
+                    // A loop further along that doesn't come back here, found from one of its own areas
+                    break;
+                }
+                next = anchor_of(&name);
+                path.push(name);
+            }
+        }
+        cycles
+    }
+
+    /// Overwrite fields with values from `MOUSE_CLICKER_*` environment variables, so a session can be
+    /// configured without a profile file. Supports `MIN_DELAY` and `MAX_DELAY` (seconds), `CLICK_TYPE`
+    /// (single, double, triple, right or middle), `AREA_WIDTH` and `AREA_HEIGHT` (pixels).
+    /// Other `MOUSE_CLICKER_*` variables are silently ignored.
+    ///
+    /// The app applies these to its starting config in `AppState::new` (through `AppState::default`),
+    /// not in `main.rs`, so they take effect before any profile is picked. Loading a profile replaces them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mouse::modules::config::*;
+    /// std::env::set_var("MOUSE_CLICKER_CLICK_TYPE", "double");
+    /// std::env::set_var("MOUSE_CLICKER_AREA_WIDTH", "50");
+    ///
+    /// let mut config = Config::default();
+    /// config.apply_env_overrides();
+    /// assert_eq!(config.click_options.click_type, ClickType::Double);
+    /// assert_eq!(config.click_area.width, 50);
+    /// ```
+    pub fn apply_env_overrides(&mut self) {
+        let vars: HashMap<String, String> = std::env::vars()
+            .filter(|(name, _)| name.starts_with(ENV_PREFIX))
+            .collect();
+
+        for warning in self.apply_overrides(&vars) {
+            eprintln!("Warning: {}", warning);
+        }
+    }
+
+    /// [`Config::apply_env_overrides`] with the variables taken from `vars` instead of the environment.
+    /// Returns a warning for each supported variable whose value didn't parse and was ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mouse::modules::config::*;
+    /// # use std::collections::HashMap;
+    /// let vars = HashMap::from([
+    ///     ("MOUSE_CLICKER_MIN_DELAY".to_string(), "2.5".to_string()),
+    ///     ("MOUSE_CLICKER_AREA_HEIGHT".to_string(), "tall".to_string()),
+    /// ]);
+    ///
+    /// let mut config = Config::default();
+    /// let warnings = config.apply_overrides(&vars);
+    /// assert_eq!(config.click_timing.min_delay, 2.5);
+    /// assert_eq!(config.click_area.height, ClickArea::default().height);
+    /// assert_eq!(warnings.len(), 1);
+    /// ```
+    pub fn apply_overrides(&mut self, vars: &HashMap<String, String>) -> Vec<String> {
+        let positive_f32 = |value: &str| value.trim().parse::<f32>().ok().filter(|v| *v > 0.0);
+        let positive_i32 = |value: &str| value.trim().parse::<i32>().ok().filter(|v| *v > 0);
+
+        let mut warnings = Vec::new();
+        env_override(vars, "MIN_DELAY", &mut self.click_timing.min_delay, positive_f32, &mut warnings);
+        env_override(vars, "MAX_DELAY", &mut self.click_timing.max_delay, positive_f32, &mut warnings);
+        env_override(vars, "CLICK_TYPE", &mut self.click_options.click_type, ClickType::from_name, &mut warnings);
+        env_override(vars, "AREA_WIDTH", &mut self.click_area.width, positive_i32, &mut warnings);
+        env_override(vars, "AREA_HEIGHT", &mut self.click_area.height, positive_i32, &mut warnings);
+
+        if self.click_timing.min_delay > self.click_timing.max_delay {
+            warnings.push(format!(
+                "{}MIN_DELAY is above MAX_DELAY, using {}s for both",
+                ENV_PREFIX, self.click_timing.min_delay
+            ));
+            self.click_timing.max_delay = self.click_timing.min_delay;
+        }
+
+        warnings
+    }
+
+    /// Add a new click area to the multi-area configuration, turning multi-area mode on
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mouse::modules::config::*;
+    /// let mut config = Config::new("Two buttons");
+    /// let button = ClickArea { width: 80, height: 30, ..Default::default() };
+    /// config.add_click_area(ClickArea { name: "OK".to_string(), ..button.clone() }, 0.8);
+    /// config.add_click_area(ClickArea { name: "Retry".to_string(), ..button }, 0.2);
+    ///
+    /// assert!(config.multi_area.enabled);
+    /// assert_eq!(config.multi_area.areas.len(), 2);
+    /// ```
     pub fn add_click_area(&mut self, area: ClickArea, weight: f32) {
         self.multi_area.areas.push((area, weight));
         if !self.multi_area.areas.is_empty() {
@@ -172,7 +935,20 @@ impl Config {
         }
     }
 
-    // Remove a click area from the multi-area configuration
+    /// Remove a click area from the multi-area configuration, returning whether `index` existed.
+    /// Multi-area mode is turned off once the last area is gone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mouse::modules::config::*;
+    /// let mut config = Config::new("One button");
+    /// config.add_click_area(ClickArea::default(), 1.0);
+    ///
+    /// assert!(!config.remove_click_area(3));
+    /// assert!(config.remove_click_area(0));
+    /// assert!(!config.multi_area.enabled);
+    /// ```
     pub fn remove_click_area(&mut self, index: usize) -> bool {
         if index < self.multi_area.areas.len() {
             self.multi_area.areas.remove(index);
@@ -190,4 +966,265 @@ impl Config {
         self.multi_area.areas.clear();
         self.multi_area.enabled = false;
     }
-}
\ No newline at end of file
+
+    // Compare every leaf value with another configuration, returning the ones that differ
+    pub fn diff(&self, other: &Config) -> Vec<ConfigDiff> {
+        let (Ok(old), Ok(new)) = (serde_json::to_value(self), serde_json::to_value(other)) else {
+            return Vec::new();
+        };
+
+        let mut diffs = Vec::new();
+        collect_diffs(String::new(), Some(&old), Some(&new), &mut diffs);
+        diffs
+    }
+
+    // List the dotted paths of the fields that differ from another configuration
+    pub fn changed_fields(&self, other: &Config) -> Vec<String> {
+        self.diff(other).into_iter().map(|diff| diff.field_path).collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigDiff {
+    pub field_path: String,  // Dot notation, with [i] for list elements
+    pub old_value: String,
+    pub new_value: String,
+}
+
+fn display_value(value: Option<&serde_json::Value>) -> String {
+    match value {
+        Some(serde_json::Value::String(s)) => s.clone(),
+        Some(value) => value.to_string(),
+        None => "(none)".to_string(),
+    }
+}
+
+// Walk both values in step; a side that's missing (e.g. a list that got shorter) is None
+fn collect_diffs(path: String, old: Option<&serde_json::Value>, new: Option<&serde_json::Value>, diffs: &mut Vec<ConfigDiff>) {
+    use serde_json::Value;
+
+    match (old, new) {
+        (Some(Value::Object(old_map)), Some(Value::Object(new_map))) => {
+            let mut keys: Vec<&String> = old_map.keys().collect();
+            keys.extend(new_map.keys().filter(|key| !old_map.contains_key(*key)));
+
+            for key in keys {
+                let child_path = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                collect_diffs(child_path, old_map.get(key), new_map.get(key), diffs);
+            }
+        },
+        (Some(Value::Array(old_items)), Some(Value::Array(new_items))) => {
+            for i in 0..old_items.len().max(new_items.len()) {
+                collect_diffs(format!("{}[{}]", path, i), old_items.get(i), new_items.get(i), diffs);
+            }
+        },
+        _ => {
+            if old != new {
+                diffs.push(ConfigDiff {
+                    field_path: path,
+                    old_value: display_value(old),
+                    new_value: display_value(new),
+                });
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_only_changes_the_fields_that_are_set() {
+        let timing = ClickTimingBuilder::default()
+            .max_delay(5.0)
+            .click_duration_override(ClickType::Middle, 150.0, 15.0)
+            .max_cpm(90.0)
+            .build();
+
+        assert_eq!(
+            timing,
+            ClickTiming {
+                max_delay: 5.0,
+                click_duration_overrides: HashMap::from([(ClickType::Middle, (150.0, 15.0))]),
+                max_cpm: Some(90.0),
+                ..ClickTiming::default()
+            }
+        );
+        assert_eq!(timing.click_duration_for(ClickType::Middle), (150.0, 15.0));
+        assert_eq!(timing.click_duration_for(ClickType::Single), (80.0, 20.0));
+    }
+
+    #[test]
+    fn one_field_constructors_match_the_builder() {
+        assert_eq!(ClickTiming::with_min_delay(0.5), ClickTimingBuilder::default().min_delay(0.5).build());
+        assert_eq!(ClickTiming::with_max_delay(9.0), ClickTimingBuilder::default().max_delay(9.0).build());
+        assert_eq!(
+            ClickTiming::with_click_duration(40.0, 4.0),
+            ClickTimingBuilder::default().click_duration(40.0, 4.0).build()
+        );
+        assert_eq!(ClickTiming::with_max_cpm(30.0).max_cpm, Some(30.0));
+        assert_eq!(ClickTimingBuilder::default().build(), ClickTiming::default());
+    }
+
+    #[test]
+    fn identical_configs_have_no_changed_fields() {
+        let config = Config::new("Same");
+        assert!(config.changed_fields(&config.clone()).is_empty());
+    }
+
+    #[test]
+    fn changed_fields_names_a_nested_field() {
+        let old = Config::default();
+        let mut new = old.clone();
+        new.click_timing.reaction_delay.max_ms = 450;
+
+        assert_eq!(old.changed_fields(&new), vec!["click_timing.reaction_delay.max_ms"]);
+        assert_eq!(
+            old.diff(&new),
+            vec![ConfigDiff {
+                field_path: "click_timing.reaction_delay.max_ms".to_string(),
+                old_value: "300".to_string(),
+                new_value: "450".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn changed_fields_reports_elements_added_and_removed() {
+        let mut old = Config::default();
+        old.multi_area.areas.push((ClickArea::default(), 1.0));
+        let mut new = old.clone();
+        new.multi_area.areas.push((ClickArea::default(), 0.5));
+
+        // The new element is reported whole, since there's nothing on the old side to compare it with
+        assert_eq!(old.changed_fields(&new), vec!["multi_area.areas[1]"]);
+        let removed = new.diff(&old);
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].field_path, "multi_area.areas[1]");
+        assert_eq!(removed[0].new_value, "(none)");
+    }
+
+    #[test]
+    fn changed_fields_names_a_field_inside_a_list_element() {
+        let mut old = Config::default();
+        old.multi_area.areas.push((ClickArea::default(), 1.0));
+        old.multi_area.areas.push((ClickArea::default(), 1.0));
+        let mut new = old.clone();
+        new.multi_area.areas[1].0.width = 64;
+        new.multi_area.areas[1].1 = 0.25;
+
+        assert_eq!(
+            old.changed_fields(&new),
+            vec!["multi_area.areas[1][0].width", "multi_area.areas[1][1]"]
+        );
+    }
+
+    #[test]
+    fn readers_see_the_old_or_new_profile_while_it_is_saved() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("Busy.json");
+
+        // Very different sizes, so a partly written file would be obvious
+        let small = Config::new("Busy");
+        let mut large = Config::new("Busy");
+        for i in 0..500 {
+            large.add_click_area(ClickArea { name: format!("Area {}", i), ..Default::default() }, 1.0);
+        }
+        small.save_atomic(&path).unwrap();
+
+        let done = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let reader = {
+            let (path, done, small, large) = (path.clone(), done.clone(), small.clone(), large.clone());
+            std::thread::spawn(move || {
+                while !done.load(std::sync::atomic::Ordering::SeqCst) {
+                    // Windows can refuse to open the file while it's being replaced; that's fine,
+                    // as long as whatever is read is one whole profile or the other
+                    let Ok(json) = fs::read_to_string(&path) else { continue };
+                    let config: Config = serde_json::from_str(&json).unwrap();
+                    assert!(config == small || config == large);
+                }
+            })
+        };
+
+        for i in 0..100 {
+            let config = if i % 2 == 0 { &large } else { &small };
+            config.save_atomic(&path).unwrap();
+        }
+        done.store(true, std::sync::atomic::Ordering::SeqCst);
+        reader.join().unwrap();
+
+        // Nothing is left behind but the profile itself
+        let files: Vec<_> = fs::read_dir(dir.path()).unwrap().map(|entry| entry.unwrap().file_name()).collect();
+        assert_eq!(files, vec![std::ffi::OsString::from("Busy.json")]);
+    }
+
+    #[test]
+    fn shipped_sample_profile_loads_and_validates() {
+        let config: Config = serde_json::from_str(include_str!("../../profiles/Default.json")).unwrap();
+
+        assert_eq!(config.profile_name, "Default");
+        assert_eq!(config.multi_area.areas.len(), 2);
+        // Written before the gap had a spread; the legacy millisecond value still loads
+        assert_eq!(config.click_timing.double_click_gap.mean, 200.0);
+        assert_eq!(config.validate(&[(1920, 1080)]), Vec::new());
+    }
+
+    fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect()
+    }
+
+    #[test]
+    fn overrides_set_every_supported_field() {
+        let mut config = Config::default();
+        let warnings = config.apply_overrides(&vars(&[
+            ("MOUSE_CLICKER_MIN_DELAY", "1.5"),
+            ("MOUSE_CLICKER_MAX_DELAY", " 4 "),
+            ("MOUSE_CLICKER_CLICK_TYPE", "Right"),
+            ("MOUSE_CLICKER_AREA_WIDTH", "50"),
+            ("MOUSE_CLICKER_AREA_HEIGHT", "60"),
+        ]));
+
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert_eq!((config.click_timing.min_delay, config.click_timing.max_delay), (1.5, 4.0));
+        assert_eq!(config.click_options.click_type, ClickType::Right);
+        assert_eq!((config.click_area.width, config.click_area.height), (50, 60));
+    }
+
+    #[test]
+    fn malformed_overrides_leave_the_field_alone() {
+        let mut config = Config::default();
+        let warnings = config.apply_overrides(&vars(&[
+            ("MOUSE_CLICKER_MIN_DELAY", "soon"),
+            ("MOUSE_CLICKER_MAX_DELAY", "-3"),
+            ("MOUSE_CLICKER_CLICK_TYPE", "quadruple"),
+            ("MOUSE_CLICKER_AREA_WIDTH", "0"),
+            ("MOUSE_CLICKER_AREA_HEIGHT", "12.5"),
+        ]));
+
+        assert_eq!(warnings.len(), 5, "{:?}", warnings);
+        assert_eq!(config, Config::default());
+    }
+
+    #[test]
+    fn unknown_variables_are_silently_ignored() {
+        let mut config = Config::default();
+        let warnings = config.apply_overrides(&vars(&[
+            ("MOUSE_CLICKER_MIN_DELAYS", "1"),
+            ("MOUSE_CLICKER_", "1"),
+            ("PATH", "/usr/bin"),
+        ]));
+
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert_eq!(config, Config::default());
+    }
+
+    #[test]
+    fn min_delay_above_max_delay_raises_the_max() {
+        let mut config = Config::default();
+        let warnings = config.apply_overrides(&vars(&[("MOUSE_CLICKER_MIN_DELAY", "60")]));
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!((config.click_timing.min_delay, config.click_timing.max_delay), (60.0, 60.0));
+    }
+}
@@ -0,0 +1,77 @@
+use std::path::PathBuf;
+use windows::core::{PCWSTR, PWSTR};
+use windows::Win32::Foundation::{HWND, LPARAM, MAX_PATH};
+use windows::Win32::System::Com::{CoInitializeEx, CoTaskMemFree, COINIT_APARTMENTTHREADED};
+use windows::Win32::UI::Controls::Dialogs::{GetOpenFileNameW, OFN_FILEMUSTEXIST, OFN_NOCHANGEDIR, OFN_PATHMUSTEXIST, OPENFILENAMEW};
+use windows::Win32::UI::Shell::{SHBrowseForFolderW, SHGetPathFromIDListW, BIF_NEWDIALOGSTYLE, BIF_RETURNONLYFSDIRS, BROWSEINFOW};
+
+/// Show the system folder picker and block until the user picks a folder or cancels
+pub fn pick_folder(title: &str) -> Option<PathBuf> {
+    let title: Vec<u16> = title.encode_utf16().chain(std::iter::once(0)).collect();
+    let mut display_name = [0u16; MAX_PATH as usize];
+
+    let info = BROWSEINFOW {
+        hwndOwner: HWND(0),
+        pidlRoot: std::ptr::null_mut(),
+        pszDisplayName: PWSTR(display_name.as_mut_ptr()),
+        lpszTitle: PCWSTR(title.as_ptr()),
+        ulFlags: BIF_RETURNONLYFSDIRS | BIF_NEWDIALOGSTYLE,
+        lpfn: None,
+        lParam: LPARAM(0),
+        iImage: 0,
+    };
+
+    unsafe {
+        // The new dialog style needs COM; the window may already have initialized it, which is fine
+        let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+
+        let pidl = SHBrowseForFolderW(&info);
+        if pidl.is_null() {
+            return None;
+        }
+
+        let mut path = [0u16; MAX_PATH as usize];
+        let found = SHGetPathFromIDListW(pidl, &mut path).as_bool();
+        CoTaskMemFree(Some(pidl as *const _));
+
+        if !found {
+            return None;
+        }
+
+        let len = path.iter().position(|&c| c == 0).unwrap_or(path.len());
+        Some(PathBuf::from(String::from_utf16_lossy(&path[..len])))
+    }
+}
+
+/// Show the system open file dialog and block until the user picks a file or cancels.
+/// `filters` pairs a description with its patterns, e.g. `("AutoHotkey scripts", "*.ahk")`.
+pub fn pick_file(title: &str, filters: &[(&str, &str)]) -> Option<PathBuf> {
+    let title: Vec<u16> = title.encode_utf16().chain(std::iter::once(0)).collect();
+    // Each description and pattern ends in a NUL, and the whole list in one more
+    let filter: Vec<u16> = filters.iter()
+        .flat_map(|(description, patterns)| [*description, *patterns])
+        .flat_map(|text| text.encode_utf16().chain(std::iter::once(0)))
+        .chain(std::iter::once(0))
+        .collect();
+    let mut path = [0u16; MAX_PATH as usize];
+
+    let mut info = OPENFILENAMEW {
+        lStructSize: std::mem::size_of::<OPENFILENAMEW>() as u32,
+        lpstrFilter: PCWSTR(filter.as_ptr()),
+        lpstrFile: PWSTR(path.as_mut_ptr()),
+        nMaxFile: path.len() as u32,
+        lpstrTitle: PCWSTR(title.as_ptr()),
+        // Without NOCHANGEDIR the dialog moves the working directory to the picked folder
+        Flags: OFN_FILEMUSTEXIST | OFN_PATHMUSTEXIST | OFN_NOCHANGEDIR,
+        ..Default::default()
+    };
+
+    unsafe {
+        if !GetOpenFileNameW(&mut info).as_bool() {
+            return None;
+        }
+    }
+
+    let len = path.iter().position(|&c| c == 0).unwrap_or(path.len());
+    Some(PathBuf::from(String::from_utf16_lossy(&path[..len])))
+}
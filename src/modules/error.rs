@@ -1,22 +1,64 @@
 use std::io;
 use std::fmt;
+use std::sync::Arc;
 use image::error::ImageError;
 use base64::DecodeError;
 use serde_json::Error as JsonError;
 
-#[derive(Debug)]
+// Wrapped errors are shared so the error stays cloneable; none of them are Clone
+#[derive(Debug, Clone)]
 pub enum AppError {
-    IoError(io::Error),
+    IoError(Arc<io::Error>),
     ParseError(String),
-    ImageError(ImageError),
+    ImageError(Arc<ImageError>),
     Base64Error(DecodeError),
-    JsonError(JsonError),
+    JsonError(Arc<JsonError>),
+    /// A Windows API call failed
+    SystemError(String),
+    /// An error explained for the user, with the error it came from kept for logs and bug reports
+    UserFacing { message: String, technical: Box<AppError> },
+}
+
+impl AppError {
+    /// Wrap `source` with a message that makes sense to someone who doesn't know the code
+    pub fn user_facing(message: impl Into<String>, source: AppError) -> AppError {
+        AppError::UserFacing { message: message.into(), technical: Box::new(source) }
+    }
+
+    /// The whole chain of errors, one per line, for copying into a bug report
+    pub fn technical_details(&self) -> String {
+        let mut details = self.to_string();
+        let mut previous = details.clone();
+        let mut source = std::error::Error::source(self);
+        while let Some(error) = source {
+            // Wrapping variants already show their inner error, so it only gets a line of its own if it adds something
+            let text = error.to_string();
+            if !previous.contains(&text) {
+                details.push_str(&format!("\nCaused by: {}", text));
+            }
+            previous = text;
+            source = error.source();
+        }
+        details
+    }
 }
 
 // Implement Send for AppError
 unsafe impl Send for AppError {}
 
-impl std::error::Error for AppError {}
+impl std::error::Error for AppError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AppError::IoError(e) => Some(e.as_ref()),
+            AppError::ParseError(_) => None,
+            AppError::ImageError(e) => Some(e.as_ref()),
+            AppError::Base64Error(e) => Some(e),
+            AppError::JsonError(e) => Some(e.as_ref()),
+            AppError::SystemError(_) => None,
+            AppError::UserFacing { technical, .. } => Some(technical.as_ref()),
+        }
+    }
+}
 
 impl fmt::Display for AppError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -26,19 +68,21 @@ impl fmt::Display for AppError {
             AppError::ImageError(e) => write!(f, "Image error: {}", e),
             AppError::Base64Error(e) => write!(f, "Base64 error: {}", e),
             AppError::JsonError(e) => write!(f, "JSON error: {}", e),
+            AppError::SystemError(s) => write!(f, "System error: {}", s),
+            AppError::UserFacing { message, .. } => write!(f, "{}", message),
         }
     }
 }
 
 impl From<io::Error> for AppError {
     fn from(error: io::Error) -> Self {
-        AppError::IoError(error)
+        AppError::IoError(Arc::new(error))
     }
 }
 
 impl From<ImageError> for AppError {
     fn from(error: ImageError) -> Self {
-        AppError::ImageError(error)
+        AppError::ImageError(Arc::new(error))
     }
 }
 
@@ -50,8 +94,39 @@ impl From<DecodeError> for AppError {
 
 impl From<JsonError> for AppError {
     fn from(error: JsonError) -> Self {
-        AppError::JsonError(error)
+        AppError::JsonError(Arc::new(error))
     }
 }
 
 pub type Result<T> = std::result::Result<T, AppError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapped_errors_are_not_repeated_in_the_details() {
+        let error = AppError::from(io::Error::other("disk full"));
+        assert_eq!(error.technical_details(), "IO error: disk full");
+    }
+
+    #[test]
+    fn user_facing_details_end_with_the_technical_error() {
+        let error = AppError::user_facing(
+            "Couldn't save the profile.",
+            AppError::from(io::Error::new(io::ErrorKind::PermissionDenied, "access denied")),
+        );
+        assert_eq!(error.technical_details(), "Couldn't save the profile.\nCaused by: IO error: access denied");
+    }
+
+    #[test]
+    fn causes_with_new_text_each_get_a_line() {
+        let inner = AppError::from(serde_json::from_str::<u32>("x").unwrap_err());
+        let error = AppError::user_facing("Outer", AppError::user_facing("Middle", inner.clone()));
+
+        let details = error.technical_details();
+        assert_eq!(details.lines().count(), 3);
+        assert!(details.starts_with("Outer\nCaused by: Middle\nCaused by: JSON error: "));
+        assert_eq!(details.matches(&inner.to_string()["JSON error: ".len()..]).count(), 1);
+    }
+}
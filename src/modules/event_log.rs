@@ -0,0 +1,327 @@
+use crate::modules::config::ClickType;
+use crate::modules::error::{AppError, Result};
+use chrono::{DateTime, Local, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// A log file is rotated once it grows past this size
+const MAX_LOG_FILE_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Oldest log files are deleted once the directory holds more than this many
+const MAX_LOG_FILES: usize = 50;
+
+/// How often buffered events are flushed to disk
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A single click performed by the clicker
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ClickEvent {
+    /// When the click happened
+    pub timestamp: DateTime<Local>,
+    /// Screen coordinates of the click
+    pub x: i32,
+    pub y: i32,
+    /// Which kind of click was sent
+    pub click_type: ClickType,
+    /// Name of the click area or image target that produced the click
+    pub source: String,
+    /// Result of post-click verification, if any was performed
+    pub verified: Option<bool>,
+    /// Simulated reaction time between the cursor arriving and the press, if enabled
+    #[serde(default)]
+    pub reaction_delay_ms: Option<u64>,
+    /// Index into `multi_area.areas` of the area clicked, or `None` for the primary area and image targets
+    #[serde(default)]
+    pub click_area_index: Option<usize>,
+}
+
+/// What happened in a session event
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum EventKind {
+    Started,
+    StartConditionMet,
+    Stopped,
+    Paused,
+    Resumed,
+    ClickPerformed,
+    TargetFound,
+    TargetNotFound,
+    ThresholdAdjusted,
+    ProfilesExported,
+    ProfilesImported,
+    Error,
+}
+
+impl EventKind {
+    pub const ALL: [EventKind; 12] = [
+        EventKind::Started,
+        EventKind::StartConditionMet,
+        EventKind::Stopped,
+        EventKind::Paused,
+        EventKind::Resumed,
+        EventKind::ClickPerformed,
+        EventKind::TargetFound,
+        EventKind::TargetNotFound,
+        EventKind::ThresholdAdjusted,
+        EventKind::ProfilesExported,
+        EventKind::ProfilesImported,
+        EventKind::Error,
+    ];
+}
+
+/// A timestamped entry in the in-app session event log
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SessionEvent {
+    pub timestamp: DateTime<Utc>,
+    pub kind: EventKind,
+    pub detail: Option<String>,
+}
+
+impl SessionEvent {
+    pub fn new(kind: EventKind, detail: Option<String>) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            kind,
+            detail,
+        }
+    }
+}
+
+/// Write session events to a JSON Lines file
+pub fn export_session_events(events: &[SessionEvent], path: &Path) -> Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    for event in events {
+        writeln!(writer, "{}", serde_json::to_string(event)?)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Appends click events to a JSON Lines file from a background writer thread
+pub struct EventLogger {
+    should_stop: Arc<AtomicBool>,
+    writer_handle: Option<JoinHandle<()>>,
+}
+
+impl EventLogger {
+    /// Start a new session log in the given directory, writing every event that arrives on `receiver`
+    pub fn start(logs_dir: impl AsRef<Path>, receiver: Receiver<ClickEvent>) -> Result<Self> {
+        let logs_dir = logs_dir.as_ref().to_path_buf();
+        fs::create_dir_all(&logs_dir)?;
+
+        let session = Local::now().format("%Y%m%d_%H%M%S").to_string();
+        let mut writer = SessionWriter::open(logs_dir, session)?;
+
+        let should_stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = Arc::clone(&should_stop);
+        let writer_handle = thread::spawn(move || {
+            let mut last_flush = Instant::now();
+
+            while !stop_flag.load(Ordering::SeqCst) {
+                match receiver.recv_timeout(FLUSH_INTERVAL) {
+                    Ok(event) => {
+                        if let Err(e) = writer.write(&event) {
+                            eprintln!("Failed to write click event: {}", e);
+                        }
+                    },
+                    Err(RecvTimeoutError::Timeout) => {},
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+
+                if last_flush.elapsed() >= FLUSH_INTERVAL {
+                    writer.flush();
+                    last_flush = Instant::now();
+                }
+            }
+
+            // Write whatever was still queued when the session ended
+            for event in receiver.try_iter() {
+                if let Err(e) = writer.write(&event) {
+                    eprintln!("Failed to write click event: {}", e);
+                }
+            }
+
+            writer.flush();
+        });
+
+        Ok(Self {
+            should_stop,
+            writer_handle: Some(writer_handle),
+        })
+    }
+}
+
+impl Drop for EventLogger {
+    fn drop(&mut self) {
+        // The writer drains its queue and flushes before exiting
+        self.should_stop.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.writer_handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Owns the current log file and handles rotation
+struct SessionWriter {
+    logs_dir: PathBuf,
+    session: String,
+    part: u32,
+    writer: BufWriter<File>,
+    bytes_written: u64,
+}
+
+impl SessionWriter {
+    fn open(logs_dir: PathBuf, session: String) -> Result<Self> {
+        let writer = Self::create_part(&logs_dir, &session, 0)?;
+        Ok(Self {
+            logs_dir,
+            session,
+            part: 0,
+            writer,
+            bytes_written: 0,
+        })
+    }
+
+    fn create_part(logs_dir: &Path, session: &str, part: u32) -> Result<BufWriter<File>> {
+        let file_name = if part == 0 {
+            format!("session_{}.jsonl", session)
+        } else {
+            format!("session_{}_{}.jsonl", session, part)
+        };
+
+        let file = File::create(logs_dir.join(file_name))?;
+        prune_old_logs(logs_dir);
+        Ok(BufWriter::new(file))
+    }
+
+    fn write(&mut self, event: &ClickEvent) -> Result<()> {
+        if self.bytes_written >= MAX_LOG_FILE_BYTES {
+            self.flush();
+            self.part += 1;
+            self.writer = Self::create_part(&self.logs_dir, &self.session, self.part)?;
+            self.bytes_written = 0;
+        }
+
+        let line = serde_json::to_string(event)?;
+        writeln!(self.writer, "{}", line)?;
+        self.bytes_written += line.len() as u64 + 1;
+
+        Ok(())
+    }
+
+    fn flush(&mut self) {
+        if let Err(e) = self.writer.flush() {
+            eprintln!("Failed to flush event log: {}", e);
+        }
+    }
+}
+
+/// Delete the oldest log files so the directory stays within `MAX_LOG_FILES`
+fn prune_old_logs(logs_dir: &Path) {
+    let logs = list_session_logs(logs_dir);
+    if logs.len() <= MAX_LOG_FILES {
+        return;
+    }
+
+    for path in &logs[..logs.len() - MAX_LOG_FILES] {
+        if let Err(e) = fs::remove_file(path) {
+            eprintln!("Failed to remove old log {:?}: {}", path, e);
+        }
+    }
+}
+
+/// List the session log files in a directory, oldest first
+pub fn list_session_logs(logs_dir: &Path) -> Vec<PathBuf> {
+    let mut logs: Vec<PathBuf> = fs::read_dir(logs_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "jsonl"))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Name order would put part 10 of a session before part 2
+    logs.sort_by_cached_key(|path| (session_log_key(path), path.clone()));
+    logs
+}
+
+/// Start time and part number of a session log, from a name like `session_20240131_235959_2.jsonl`.
+/// Names that don't follow the pattern give `None` and sort first.
+fn session_log_key(path: &Path) -> Option<(NaiveDateTime, u32)> {
+    let stem = path.file_stem()?.to_str()?.strip_prefix("session_")?;
+    let started = NaiveDateTime::parse_from_str(stem.get(..15)?, "%Y%m%d_%H%M%S").ok()?;
+    let part = match stem.get(15..)? {
+        "" => 0,
+        rest => rest.strip_prefix('_')?.parse().ok()?,
+    };
+    Some((started, part))
+}
+
+/// Read every event from a session log, skipping lines that can't be parsed
+pub fn load_events(path: &Path) -> Result<Vec<ClickEvent>> {
+    let file = File::open(path)
+        .map_err(|e| AppError::ParseError(format!("Failed to open log {:?}: {}", path, e)))?;
+
+    let mut events = Vec::new();
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match serde_json::from_str::<ClickEvent>(&line) {
+            Ok(event) => events.push(event),
+            Err(e) => eprintln!("Skipping malformed log line: {}", e),
+        }
+    }
+
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn session_logs_sort_by_start_time_then_part_number() {
+        let dir = std::env::temp_dir().join(format!("mouse_session_logs_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let names = [
+            "session_20240131_235959_10.jsonl",
+            "session_20240201_000000.jsonl",
+            "session_20240131_235959_2.jsonl",
+            "session_20240131_235959.jsonl",
+            "notes.jsonl",
+            "session_20240131_235959_1.jsonl",
+        ];
+        for name in names {
+            File::create(dir.join(name)).unwrap();
+        }
+        File::create(dir.join("session_20240101_000000.txt")).unwrap();
+
+        let listed: Vec<String> = list_session_logs(&dir).iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(listed, [
+            "notes.jsonl",
+            "session_20240131_235959.jsonl",
+            "session_20240131_235959_1.jsonl",
+            "session_20240131_235959_2.jsonl",
+            "session_20240131_235959_10.jsonl",
+            "session_20240201_000000.jsonl",
+        ]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{OnceLock, RwLock};
+use crate::modules::error::{AppError, Result};
+use crate::modules::paths;
+
+/// Language every other one falls back to for keys it doesn't translate
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+/// Key each locale file uses for its own name, as shown in the language selector
+const LANGUAGE_NAME_KEY: &str = "language.name";
+
+/// Locales compiled into the executable, so they work without any files next to it
+const BUILT_IN: [(&str, &str); 2] = [
+    ("en", include_str!("../../locale/en.json")),
+    ("de", include_str!("../../locale/de.json")),
+];
+
+/// Translated UI strings for one language
+#[derive(Debug, Clone)]
+pub struct Locale {
+    strings: HashMap<String, String>,
+    fallback: HashMap<String, String>,
+}
+
+impl Locale {
+    /// Load `locale/{language}.json`, looking in the data directory first so a file there
+    /// can add a language or replace a built-in one. Unknown languages get English.
+    pub fn load(language: &str) -> Self {
+        let fallback = parse(built_in(DEFAULT_LANGUAGE).unwrap_or("{}")).unwrap_or_default();
+
+        let strings = match read_locale_file(language) {
+            Some(Ok(strings)) => strings,
+            Some(Err(e)) => {
+                eprintln!("Failed to load locale '{}': {}", language, e);
+                HashMap::new()
+            },
+            None => built_in(language).and_then(|json| parse(json).ok()).unwrap_or_default(),
+        };
+
+        Self { strings, fallback }
+    }
+
+    /// The string for `key`, in English if this language doesn't have it, or the key itself if neither does
+    pub fn get<'a>(&'a self, key: &'a str) -> &'a str {
+        self.strings.get(key)
+            .or_else(|| self.fallback.get(key))
+            .map(String::as_str)
+            .unwrap_or(key)
+    }
+
+    /// The string for `key` with each `{name}` in it replaced by the matching argument
+    pub fn format(&self, key: &str, args: &[(&str, String)]) -> String {
+        args.iter().fold(self.get(key).to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{}}}", name), value)
+        })
+    }
+}
+
+fn built_in(language: &str) -> Option<&'static str> {
+    BUILT_IN.iter().find(|(code, _)| *code == language).map(|(_, json)| *json)
+}
+
+fn parse(json: &str) -> Result<HashMap<String, String>> {
+    serde_json::from_str(json)
+        .map_err(|e| AppError::ParseError(format!("Failed to parse locale: {}", e)))
+}
+
+pub fn locale_dir() -> PathBuf {
+    paths::data_dir().join("locale")
+}
+
+fn read_locale_file(language: &str) -> Option<Result<HashMap<String, String>>> {
+    let path = locale_dir().join(format!("{}.json", language));
+    if !path.exists() {
+        return None;
+    }
+    Some(fs::read_to_string(&path).map_err(AppError::from).and_then(|json| parse(&json)))
+}
+
+/// Every language that can be picked, as (code, display name), built-in ones first
+pub fn available_languages() -> Vec<(String, String)> {
+    let mut codes: Vec<String> = BUILT_IN.iter().map(|(code, _)| code.to_string()).collect();
+
+    if let Ok(entries) = fs::read_dir(locale_dir()) {
+        let mut extra: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let path = entry.path();
+                (path.extension()? == "json").then(|| path.file_stem()?.to_str().map(str::to_string))?
+            })
+            .filter(|code| !codes.contains(code))
+            .collect();
+        extra.sort();
+        codes.extend(extra);
+    }
+
+    codes.into_iter()
+        .map(|code| {
+            let locale = Locale::load(&code);
+            let name = locale.strings.get(LANGUAGE_NAME_KEY).cloned().unwrap_or_else(|| code.clone());
+            (code, name)
+        })
+        .collect()
+}
+
+static CURRENT: OnceLock<RwLock<Locale>> = OnceLock::new();
+
+fn current() -> &'static RwLock<Locale> {
+    CURRENT.get_or_init(|| RwLock::new(Locale::load(DEFAULT_LANGUAGE)))
+}
+
+/// Switch the UI language. Takes effect from the next frame; an empty code means English.
+pub fn set_language(language: &str) {
+    let language = if language.is_empty() { DEFAULT_LANGUAGE } else { language };
+    let locale = Locale::load(language);
+    match current().write() {
+        Ok(mut current) => *current = locale,
+        Err(poisoned) => *poisoned.into_inner() = locale,
+    }
+}
+
+/// Look up `key` in the current language. Use through `t!`.
+pub fn translate(key: &str) -> String {
+    match current().read() {
+        Ok(locale) => locale.get(key).to_string(),
+        Err(_) => key.to_string(),
+    }
+}
+
+/// Look up `key` in the current language and fill in its `{name}` placeholders. Use through `t!`.
+pub fn translate_with(key: &str, args: &[(&str, String)]) -> String {
+    match current().read() {
+        Ok(locale) => locale.format(key, args),
+        Err(_) => key.to_string(),
+    }
+}
+
+/// The UI string for a key in the current language, e.g. `t!("settings.click_type")`.
+/// Placeholders are filled in by name: `t!("stats.clicks", count = 12)` for `"{count} clicks"`.
+#[macro_export]
+macro_rules! t {
+    ($key:literal) => {
+        $crate::modules::i18n::translate($key)
+    };
+    ($key:literal, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::modules::i18n::translate_with($key, &[$((stringify!($name), $value.to_string())),+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn locale(strings: &[(&str, &str)], fallback: &[(&str, &str)]) -> Locale {
+        let map = |pairs: &[(&str, &str)]| pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        Locale { strings: map(strings), fallback: map(fallback) }
+    }
+
+    #[test]
+    fn placeholders_are_filled_in_by_name_in_any_order() {
+        let locale = locale(&[("log.shown", "{shown} von {total} Ereignissen")], &[]);
+        let args = [("total", 9.to_string()), ("shown", 4.to_string())];
+        assert_eq!(locale.format("log.shown", &args), "4 von 9 Ereignissen");
+    }
+
+    #[test]
+    fn fallback_strings_get_their_placeholders_filled_too() {
+        let locale = locale(&[], &[("stats.clicks", "{count} clicks, {count} total")]);
+        assert_eq!(locale.format("stats.clicks", &[("count", "3".to_string())]), "3 clicks, 3 total");
+    }
+
+    #[test]
+    fn unknown_placeholders_and_missing_keys_are_left_alone() {
+        let locale = locale(&[("a", "{x} and {y}")], &[]);
+        assert_eq!(locale.format("a", &[("x", "1".to_string())]), "1 and {y}");
+        assert_eq!(locale.format("missing", &[("x", "1".to_string())]), "missing");
+    }
+
+    #[test]
+    fn every_placeholder_in_german_is_also_in_english() {
+        let english = parse(built_in("en").unwrap()).unwrap();
+        let german = parse(built_in("de").unwrap()).unwrap();
+        let placeholders = |text: &str| -> Vec<String> {
+            let mut names: Vec<String> = text.split('{').skip(1).filter_map(|rest| Some(rest.split_once('}')?.0.to_string())).collect();
+            names.sort();
+            names
+        };
+
+        for (key, text) in &german {
+            let english_text = english.get(key).unwrap_or_else(|| panic!("'{}' is only in de.json", key));
+            assert_eq!(placeholders(text), placeholders(english_text), "placeholders of '{}'", key);
+        }
+    }
+}
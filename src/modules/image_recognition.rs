@@ -1,12 +1,24 @@
 use crate::modules::error::{AppError, Result};
-use image::{DynamicImage, GenericImageView, RgbaImage};
-use std::path::PathBuf;
+use crate::modules::validation::ensure_valid_name;
+use crate::modules::paths;
+use image::{DynamicImage, GenericImageView, GrayImage, RgbaImage};
+#[cfg(any(feature = "parallel-matching", feature = "parallel-io"))]
+use rayon::prelude::*;
+use std::path::{Path, PathBuf};
 use std::fs;
-use std::io::Cursor;
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
 use base64::{Engine as _, engine::general_purpose};
+use chrono::{DateTime, Utc};
 use windows::Win32::Graphics::Gdi;
-use windows::Win32::Foundation::HWND;
+use windows::core::PCWSTR;
+use windows::Win32::Foundation::{HANDLE, HWND, POINT, RECT};
+use windows::Win32::Storage::Xps::{PrintWindow, PW_CLIENTONLY};
+use windows::Win32::UI::WindowsAndMessaging::{FindWindowW, GetClientRect};
 use std::mem::size_of;
+use std::thread;
+use std::time::Duration;
+use enigo::{Enigo, MouseControllable};
 
 /// Represents a target image that can be searched for on the screen
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -19,8 +31,187 @@ pub struct TargetImage {
     pub image_data: String,
     /// Confidence threshold for matching (0.0 to 1.0)
     pub threshold: f32,
-    /// Optional click offset from the center of the matched image
+    /// Click offset from the top-left corner of the matched image. Deprecated in favor of
+    /// `click_alignment` and only used when that isn't set.
+    #[serde(default)]
     pub click_offset: Option<(i32, i32)>,
+    /// Where in the matched image to click
+    #[serde(default)]
+    pub click_alignment: Option<ClickAlignment>,
+    /// Scroll down looking for the target when it isn't visible
+    #[serde(default)]
+    pub auto_scroll_to_target: bool,
+    /// Pages to scroll before giving up
+    #[serde(default = "default_max_scroll_attempts")]
+    pub max_scroll_attempts: u8,
+    /// Free-form group the target is listed under
+    #[serde(default = "default_category")]
+    pub category: String,
+    /// When the target was last searched for, whether or not it was found
+    #[serde(default)]
+    pub last_used: Option<DateTime<Utc>>,
+    /// Lower the threshold step by step while the target keeps failing to match
+    #[serde(default)]
+    pub auto_adjust_threshold: bool,
+    /// Misses in a row before the threshold is lowered
+    #[serde(default = "default_miss_tolerance")]
+    pub miss_tolerance: u32,
+    /// Threshold the user chose, restored after the next match. Zero in files saved
+    /// before auto-adjusting existed, in which case `threshold` is the original.
+    #[serde(default)]
+    pub original_threshold: f32,
+}
+
+impl TargetImage {
+    /// Size of the stored image file in bytes, before base64 encoding
+    pub fn encoded_size(&self) -> usize {
+        let padding = self.image_data.bytes().rev().take_while(|&b| b == b'=').count();
+        (self.image_data.len() / 4 * 3).saturating_sub(padding)
+    }
+
+    /// Whether the threshold has been lowered from the one the user chose
+    pub fn threshold_adjusted(&self) -> bool {
+        self.original_threshold > 0.0 && (self.threshold - self.original_threshold).abs() > f32::EPSILON
+    }
+
+    /// Screen position to click for a match with its top-left corner at (x, y)
+    pub fn click_point(&self, x: i32, y: i32, width: u32, height: u32) -> (i32, i32) {
+        match (self.click_alignment, self.click_offset) {
+            (Some(alignment), _) => alignment.position(x, y, width, height),
+            (None, Some((offset_x, offset_y))) => (x + offset_x, y + offset_y),
+            (None, None) => ClickAlignment::Center.position(x, y, width, height),
+        }
+    }
+}
+
+/// Point within a matched image that gets clicked
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ClickAlignment {
+    Center,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    /// Offset from the top-left corner
+    Custom(i32, i32),
+}
+
+impl ClickAlignment {
+    /// Every alignment, with `Custom` at a zero offset
+    pub const ALL: [ClickAlignment; 6] = [
+        ClickAlignment::Center,
+        ClickAlignment::TopLeft,
+        ClickAlignment::TopRight,
+        ClickAlignment::BottomLeft,
+        ClickAlignment::BottomRight,
+        ClickAlignment::Custom(0, 0),
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ClickAlignment::Center => "Center",
+            ClickAlignment::TopLeft => "Top left",
+            ClickAlignment::TopRight => "Top right",
+            ClickAlignment::BottomLeft => "Bottom left",
+            ClickAlignment::BottomRight => "Bottom right",
+            ClickAlignment::Custom(..) => "Custom",
+        }
+    }
+
+    /// Screen position for a match of the given size with its top-left corner at (x, y).
+    /// Corners are the outermost pixels of the match, so the click still lands on it.
+    pub fn position(self, x: i32, y: i32, width: u32, height: u32) -> (i32, i32) {
+        let right = x + (width as i32 - 1).max(0);
+        let bottom = y + (height as i32 - 1).max(0);
+
+        match self {
+            ClickAlignment::Center => (x + (width / 2) as i32, y + (height / 2) as i32),
+            ClickAlignment::TopLeft => (x, y),
+            ClickAlignment::TopRight => (right, y),
+            ClickAlignment::BottomLeft => (x, bottom),
+            ClickAlignment::BottomRight => (right, bottom),
+            ClickAlignment::Custom(offset_x, offset_y) => (x + offset_x, y + offset_y),
+        }
+    }
+}
+
+/// Threshold used for targets that don't specify one
+pub const DEFAULT_THRESHOLD: f32 = 0.8;
+
+/// Category for targets that haven't been sorted into one
+pub const DEFAULT_CATEGORY: &str = "Uncategorized";
+
+fn default_category() -> String {
+    DEFAULT_CATEGORY.to_string()
+}
+
+fn default_max_scroll_attempts() -> u8 {
+    5
+}
+
+fn default_miss_tolerance() -> u32 {
+    5
+}
+
+/// How much an auto-adjusting target's threshold drops after too many misses in a row
+const THRESHOLD_DECAY_STEP: f32 = 0.02;
+
+/// Auto-adjusting never lowers a threshold below this
+const THRESHOLD_DECAY_FLOOR: f32 = 0.5;
+
+/// Wheel notches sent per scroll attempt, roughly a page in most lists
+const SCROLL_LINES_PER_ATTEMPT: i32 = 5;
+
+/// Time for the scrolled content to redraw before the next screenshot
+const SCROLL_SETTLE_TIME: Duration = Duration::from_millis(250);
+
+/// How much of the end of a match log is read to find its latest entry
+const MATCH_LOG_TAIL_BYTES: u64 = 4096;
+
+/// Result of matching one target against a shared screenshot
+struct SearchOutcome {
+    target_id: String,
+    score: f32,
+    /// Screen rectangle (x, y, width, height) of the best match
+    rect: (i32, i32, u32, u32),
+    /// Where to click, if the score reached the target's threshold
+    click_point: Option<(i32, i32)>,
+}
+
+/// One search for a target, as appended to `<target_id>.matchlog.jsonl`
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ImageMatchLog {
+    pub target_id: String,
+    /// Best correlation seen, whether or not it reached the threshold
+    pub score: f32,
+    pub timestamp: DateTime<Utc>,
+    pub found: bool,
+}
+
+/// Target settings written next to an exported PNG
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+struct TargetMetadata {
+    name: String,
+    threshold: f32,
+    #[serde(default)]
+    click_offset: Option<(i32, i32)>,
+    #[serde(default)]
+    click_alignment: Option<ClickAlignment>,
+    #[serde(default)]
+    auto_scroll_to_target: bool,
+    #[serde(default = "default_max_scroll_attempts")]
+    max_scroll_attempts: u8,
+    #[serde(default = "default_category")]
+    category: String,
+    #[serde(default)]
+    auto_adjust_threshold: bool,
+    #[serde(default = "default_miss_tolerance")]
+    miss_tolerance: u32,
+}
+
+/// Generate a new unique target ID
+pub fn new_target_id() -> String {
+    format!("target_{}", chrono::Utc::now().timestamp_millis())
 }
 
 /// Manages a collection of target images
@@ -30,12 +221,20 @@ pub struct ImageLibrary {
     targets_dir: PathBuf,
     /// Collection of loaded target images
     targets: Vec<TargetImage>,
+    /// Failed searches in a row for each target ID, reset by a match
+    consecutive_misses: HashMap<String, u32>,
+    /// Threshold changes made by auto-adjusting since they were last taken, for the event log
+    threshold_adjustments: Vec<String>,
+    /// Screen rectangle (x, y, width, height) of the most recent match
+    last_match: Option<(i32, i32, u32, u32)>,
+    /// Latest match log entry for each target ID
+    last_match_logs: HashMap<String, ImageMatchLog>,
 }
 
 impl ImageLibrary {
     /// Create a new image library
-    pub fn new(targets_dir: &str) -> Self {
-        let targets_dir = PathBuf::from(targets_dir);
+    pub fn new(targets_dir: impl AsRef<Path>) -> Self {
+        let targets_dir = targets_dir.as_ref().to_path_buf();
         println!("Creating image library with targets directory: {:?}", targets_dir);
 
         // Create the directory if it doesn't exist
@@ -64,6 +263,10 @@ impl ImageLibrary {
         Self {
             targets_dir,
             targets: Vec::new(),
+            consecutive_misses: HashMap::new(),
+            threshold_adjustments: Vec::new(),
+            last_match: None,
+            last_match_logs: HashMap::new(),
         }
     }
 
@@ -71,6 +274,7 @@ impl ImageLibrary {
     pub fn load_targets(&mut self) -> Result<()> {
         println!("Loading targets from directory: {:?}", self.targets_dir);
         self.targets.clear();
+        self.last_match_logs.clear();
 
         // Read all JSON files in the targets directory
         for entry in fs::read_dir(&self.targets_dir)? {
@@ -83,9 +287,15 @@ impl ImageLibrary {
                 println!("JSON content length: {}", json_content.len());
 
                 match serde_json::from_str::<TargetImage>(&json_content) {
-                    Ok(target) => {
+                    Ok(mut target) => {
+                        if target.original_threshold <= 0.0 {
+                            target.original_threshold = target.threshold;
+                        }
                         println!("Loaded target: id={}, name={}, image_data_length={}",
                             target.id, target.name, target.image_data.len());
+                        if let Some(entry) = read_last_match_log(&self.match_log_path(&target.id)) {
+                            self.last_match_logs.insert(target.id.clone(), entry);
+                        }
                         self.targets.push(target);
                     },
                     Err(e) => {
@@ -104,13 +314,17 @@ impl ImageLibrary {
         println!("Saving target: id={}, name={}, image_data_length={}",
             target.id, target.name, target.image_data.len());
 
+        // The ID becomes the file name; the name is checked too so it stays safe to export under
+        ensure_valid_name(&target.id)?;
+        ensure_valid_name(&target.name)?;
+
         let file_path = self.targets_dir.join(format!("{}.json", target.id));
         println!("Target file path: {:?}", file_path);
 
         let json_content = serde_json::to_string_pretty(target)?;
         println!("JSON content length: {}", json_content.len());
 
-        fs::write(&file_path, &json_content)?;
+        paths::write_atomic(&file_path, &json_content)?;
         println!("Target saved successfully");
 
         // Verify the file was written correctly
@@ -133,7 +347,7 @@ impl ImageLibrary {
         width: u32,
         height: u32,
         threshold: f32,
-        click_offset: Option<(i32, i32)>,
+        click_alignment: ClickAlignment,
     ) -> Result<TargetImage> {
         println!("Creating target from screenshot: {}x{} at ({}, {})", width, height, x, y);
 
@@ -141,8 +355,18 @@ impl ImageLibrary {
         let screenshot = capture_screen_area(x, y, width, height)?;
         println!("Captured screenshot: {}x{}", screenshot.width(), screenshot.height());
 
-        let image = DynamicImage::ImageRgba8(screenshot);
+        self.create_target_from_image(id, name, &DynamicImage::ImageRgba8(screenshot), threshold, click_alignment)
+    }
 
+    /// Create a new target from an image that was already captured, such as a cropped screenshot
+    pub fn create_target_from_image(
+        &self,
+        id: &str,
+        name: &str,
+        image: &DynamicImage,
+        threshold: f32,
+        click_alignment: ClickAlignment,
+    ) -> Result<TargetImage> {
         // Convert to base64
         let mut buffer = Vec::new();
         image.write_to(&mut Cursor::new(&mut buffer), image::ImageOutputFormat::Png)?;
@@ -155,7 +379,15 @@ impl ImageLibrary {
             name: name.to_string(),
             image_data: base64_data,
             threshold,
-            click_offset,
+            click_offset: None,
+            click_alignment: Some(click_alignment),
+            auto_scroll_to_target: false,
+            max_scroll_attempts: default_max_scroll_attempts(),
+            category: default_category(),
+            last_used: None,
+            auto_adjust_threshold: false,
+            miss_tolerance: default_miss_tolerance(),
+            original_threshold: threshold,
         };
 
         println!("Created target: id={}, name={}, image_data_length={}", id, name, target.image_data.len());
@@ -164,45 +396,554 @@ impl ImageLibrary {
     }
 
     /// Find a target image on the screen
-    pub fn find_on_screen(&self, target_id: &str) -> Result<Option<(i32, i32)>> {
-        // Find the target by ID
-        let target = self.targets.iter()
-            .find(|t| t.id == target_id)
-            .ok_or_else(|| AppError::ParseError(format!("Target not found: {}", target_id)))?;
-
-        // Take a screenshot of the entire screen
-        let screenshot = capture_screen()?;
+    ///
+    /// Targets with `auto_scroll_to_target` set are looked for again after each page scrolled
+    /// with `enigo`, up to `max_scroll_attempts` pages.
+    pub fn find_on_screen(&mut self, target_id: &str, enigo: &mut Enigo) -> Result<Option<(i32, i32)>> {
+        Ok(self.find_on_screen_scored(target_id, enigo)?.map(|(point, _)| point))
+    }
 
-        // Convert to DynamicImage
-        let screen_image = DynamicImage::ImageRgba8(screenshot);
+    /// Find a target image on the screen, returning the click point with the match's correlation score
+    ///
+    /// Every search is appended to the target's match log with the best score seen, found or not.
+    pub fn find_on_screen_scored(&mut self, target_id: &str, enigo: &mut Enigo) -> Result<Option<((i32, i32), f32)>> {
+        let target = self.mark_used(target_id)?;
 
         // Decode the target image from base64
         let target_data = general_purpose::STANDARD.decode(&target.image_data)?;
         let target_image = image::load_from_memory(&target_data)?;
+        let (width, height) = target_image.dimensions();
 
-        // Find the target in the screenshot
-        match find_template(&screen_image, &target_image, target.threshold) {
-            Some((x, y)) => {
-                // Apply click offset if specified
-                let (x, y) = if let Some((offset_x, offset_y)) = target.click_offset {
-                    (x + offset_x, y + offset_y)
-                } else {
-                    // Default to center of the matched image
-                    let (width, height) = target_image.dimensions();
-                    (x + (width / 2) as i32, y + (height / 2) as i32)
-                };
-
-                Ok(Some((x, y)))
-            },
-            None => Ok(None),
+        let scroll_attempts = if target.auto_scroll_to_target { target.max_scroll_attempts } else { 0 };
+        let mut best_score: f32 = 0.0;
+
+        for attempt in 0..=scroll_attempts {
+            if attempt > 0 {
+                println!("Target '{}' not visible, scrolling (attempt {}/{})", target.name, attempt, scroll_attempts);
+                enigo.mouse_scroll_y(SCROLL_LINES_PER_ATTEMPT);
+                thread::sleep(SCROLL_SETTLE_TIME);
+            }
+
+            // Take a screenshot of the entire screen
+            let screen_image = DynamicImage::ImageRgba8(capture_screen()?);
+
+            // Find the target in the screenshot
+            if let Some((score, x, y)) = best_template_match(&screen_image, &target_image, target.threshold) {
+                if score >= target.threshold {
+                    self.last_match = Some((x, y, width, height));
+                    self.record_search_result(target_id, true);
+                    self.log_match(target_id, score, true);
+                    return Ok(Some((target.click_point(x, y, width, height), score)));
+                }
+                best_score = best_score.max(score);
+            }
+        }
+
+        self.last_match = None;
+        self.record_search_result(target_id, false);
+        self.log_match(target_id, best_score, false);
+        Ok(None)
+    }
+
+    /// Look for several targets in one screenshot, returning the ID and click point of the one
+    /// that matched with the highest score, if any reached its threshold
+    ///
+    /// Every target is matched against the same capture, and each one's best score goes to its
+    /// match log. With the `parallel-matching` feature the targets are searched concurrently.
+    pub fn find_any_on_screen(&mut self, screen: &RgbaImage, target_ids: &[&str]) -> Result<Option<(String, i32, i32)>> {
+        for id in target_ids {
+            self.mark_used(id)?;
+        }
+
+        let targets: Vec<(&TargetImage, DynamicImage)> = target_ids.iter()
+            .map(|id| {
+                let target = self.targets.iter()
+                    .find(|t| t.id == *id)
+                    .ok_or_else(|| AppError::ParseError(format!("Target not found: {}", id)))?;
+                let image = image::load_from_memory(&general_purpose::STANDARD.decode(&target.image_data)?)?;
+                Ok((target, image))
+            })
+            .collect::<Result<_>>()?;
+
+        let screen = DynamicImage::ImageRgba8(screen.clone());
+        let search = |(target, image): &(&TargetImage, DynamicImage)| {
+            let (width, height) = image.dimensions();
+            let (score, x, y) = best_template_match(&screen, image, target.threshold).unwrap_or((0.0, 0, 0));
+            SearchOutcome {
+                target_id: target.id.clone(),
+                score,
+                rect: (x, y, width, height),
+                click_point: (score >= target.threshold).then(|| target.click_point(x, y, width, height)),
+            }
+        };
+
+        #[cfg(feature = "parallel-matching")]
+        let outcomes: Vec<SearchOutcome> = targets.par_iter().map(search).collect();
+        #[cfg(not(feature = "parallel-matching"))]
+        let outcomes: Vec<SearchOutcome> = targets.iter().map(search).collect();
+
+        for outcome in &outcomes {
+            self.record_search_result(&outcome.target_id, outcome.click_point.is_some());
+            self.log_match(&outcome.target_id, outcome.score, outcome.click_point.is_some());
+        }
+
+        let best = outcomes.into_iter()
+            .filter(|outcome| outcome.click_point.is_some())
+            .max_by(|a, b| a.score.total_cmp(&b.score));
+        self.last_match = best.as_ref().map(|outcome| outcome.rect);
+
+        Ok(best.and_then(|outcome| {
+            let (x, y) = outcome.click_point?;
+            Some((outcome.target_id, x, y))
+        }))
+    }
+
+    /// Stamp a target as used and return a copy of it. Every search counts as a use,
+    /// so targets that are still searched for aren't pruned.
+    fn mark_used(&mut self, target_id: &str) -> Result<TargetImage> {
+        let target = self.targets.iter_mut()
+            .find(|t| t.id == target_id)
+            .ok_or_else(|| AppError::ParseError(format!("Target not found: {}", target_id)))?;
+
+        target.last_used = Some(Utc::now());
+        let target = target.clone();
+        if let Err(e) = self.save_target(&target) {
+            eprintln!("Failed to record use of target {}: {}", target.id, e);
+        }
+        Ok(target)
+    }
+
+    fn match_log_path(&self, target_id: &str) -> PathBuf {
+        self.targets_dir.join(format!("{}.matchlog.jsonl", target_id))
+    }
+
+    /// Append a search result to the target's match log. Failures are only reported,
+    /// since a missing log entry shouldn't fail the search.
+    fn log_match(&mut self, target_id: &str, score: f32, found: bool) {
+        let entry = ImageMatchLog {
+            target_id: target_id.to_string(),
+            score,
+            timestamp: Utc::now(),
+            found,
+        };
+
+        let append = || -> Result<()> {
+            let line = serde_json::to_string(&entry)?;
+            let mut file = fs::OpenOptions::new().create(true).append(true).open(self.match_log_path(target_id))?;
+            writeln!(file, "{}", line)?;
+            Ok(())
+        };
+        if let Err(e) = append() {
+            eprintln!("Failed to write match log for target {}: {}", target_id, e);
+        }
+
+        self.last_match_logs.insert(target_id.to_string(), entry);
+    }
+
+    /// Re-read the latest match log entry of every target, to pick up searches logged by another library
+    pub fn reload_match_logs(&mut self) {
+        self.last_match_logs = self.targets.iter()
+            .filter_map(|target| Some((target.id.clone(), read_last_match_log(&self.match_log_path(&target.id))?)))
+            .collect();
+    }
+
+    /// The most recent search logged for a target
+    pub fn last_match_log(&self, target_id: &str) -> Option<&ImageMatchLog> {
+        self.last_match_logs.get(target_id)
+    }
+
+    /// Screen rectangle (x, y, width, height) the last search found its target in, if it found it
+    pub fn last_match(&self) -> Option<(i32, i32, u32, u32)> {
+        self.last_match
+    }
+
+    /// Count a miss or reset the count on a match, adjusting the threshold of auto-adjusting targets.
+    /// After `miss_tolerance` misses in a row the threshold drops by a step; a match restores it.
+    fn record_search_result(&mut self, target_id: &str, found: bool) {
+        let Some(target) = self.targets.iter_mut().find(|t| t.id == target_id) else { return };
+
+        let misses = self.consecutive_misses.entry(target_id.to_string()).or_insert(0);
+        let adjustment = if found {
+            *misses = 0;
+            target.threshold_adjusted().then(|| {
+                let message = format!("'{}' matched, threshold restored from {:.2} to {:.2}", target.name, target.threshold, target.original_threshold);
+                target.threshold = target.original_threshold;
+                message
+            })
+        } else {
+            *misses += 1;
+            if !target.auto_adjust_threshold || *misses < target.miss_tolerance.max(1) {
+                return;
+            }
+            *misses = 0;
+
+            let lowered = (target.threshold - THRESHOLD_DECAY_STEP).max(THRESHOLD_DECAY_FLOOR);
+            (lowered < target.threshold).then(|| {
+                let message = format!("'{}' missed {} times in a row, threshold lowered from {:.2} to {:.2}",
+                    target.name, target.miss_tolerance.max(1), target.threshold, lowered);
+                target.threshold = lowered;
+                message
+            })
+        };
+
+        if let Some(message) = adjustment {
+            println!("{}", message);
+            let target = target.clone();
+            if let Err(e) = self.save_target(&target) {
+                eprintln!("Failed to save adjusted threshold for target {}: {}", target.id, e);
+            }
+            self.threshold_adjustments.push(message);
         }
     }
 
+    /// Threshold changes made by auto-adjusting since the last call, oldest first
+    pub fn take_threshold_adjustments(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.threshold_adjustments)
+    }
+
+    /// Turn threshold auto-adjusting on or off for a target and save it.
+    /// Turning it off puts back the threshold the user chose.
+    pub fn set_auto_adjust_threshold(&mut self, target_id: &str, enabled: bool, miss_tolerance: u32) -> Result<()> {
+        let target = self.targets.iter_mut()
+            .find(|t| t.id == target_id)
+            .ok_or_else(|| AppError::ParseError(format!("Target not found: {}", target_id)))?;
+
+        target.auto_adjust_threshold = enabled;
+        target.miss_tolerance = miss_tolerance.max(1);
+        if !enabled && target.threshold_adjusted() {
+            target.threshold = target.original_threshold;
+        }
+        let target = target.clone();
+        self.consecutive_misses.remove(target_id);
+        self.save_target(&target)
+    }
+
+    /// Update a target's match threshold and save it
+    pub fn set_threshold(&mut self, target_id: &str, threshold: f32) -> Result<()> {
+        let target = self.targets.iter_mut()
+            .find(|t| t.id == target_id)
+            .ok_or_else(|| AppError::ParseError(format!("Target not found: {}", target_id)))?;
+
+        target.threshold = threshold;
+        target.original_threshold = threshold;
+        let target = target.clone();
+        self.consecutive_misses.remove(target_id);
+        self.save_target(&target)
+    }
+
+    /// Pick a threshold for a target from how well it matches over several screenshots
+    ///
+    /// Takes `num_screenshots` captures one second apart, assuming the target stays visible,
+    /// and sets the threshold to 90% of the weakest best-match score seen.
+    pub fn optimize_threshold(&mut self, target_id: &str, num_screenshots: u8) -> Result<f32> {
+        if num_screenshots == 0 {
+            return Err(AppError::ParseError("At least one screenshot is needed".to_string()));
+        }
+
+        let target = self.targets.iter()
+            .find(|t| t.id == target_id)
+            .ok_or_else(|| AppError::ParseError(format!("Target not found: {}", target_id)))?;
+        let target_image = base64_to_image(&target.image_data)?;
+
+        let mut min_correlation = f32::MAX;
+        for i in 0..num_screenshots {
+            if i > 0 {
+                std::thread::sleep(std::time::Duration::from_secs(1));
+            }
+
+            let screen_image = DynamicImage::ImageRgba8(capture_screen()?);
+            let (correlation, _, _) = best_template_match(&screen_image, &target_image, 0.0)
+                .ok_or_else(|| AppError::ParseError("Target is larger than the screen".to_string()))?;
+
+            println!("Auto-tune capture {}: best correlation {:.3}", i + 1, correlation);
+            min_correlation = min_correlation.min(correlation);
+        }
+
+        let threshold = (min_correlation * 0.9).clamp(0.0, 1.0);
+        self.set_threshold(target_id, threshold)?;
+
+        Ok(threshold)
+    }
+
+    /// Best correlation a target reaches on an already captured screen, or None if it doesn't fit on it
+    pub fn match_score(&self, target_id: &str, screen: &DynamicImage) -> Result<Option<f32>> {
+        let target = self.targets.iter()
+            .find(|t| t.id == target_id)
+            .ok_or_else(|| AppError::ParseError(format!("Target not found: {}", target_id)))?;
+        let target_image = base64_to_image(&target.image_data)?;
+
+        Ok(best_template_match(screen, &target_image, target.threshold).map(|(correlation, _, _)| correlation))
+    }
+
     /// Get all loaded targets
     pub fn get_targets(&self) -> &[TargetImage] {
         &self.targets
     }
 
+    /// Group the loaded targets by category
+    pub fn get_targets_by_category(&self) -> HashMap<String, Vec<&TargetImage>> {
+        let mut groups: HashMap<String, Vec<&TargetImage>> = HashMap::new();
+        for target in &self.targets {
+            groups.entry(target.category.clone()).or_default().push(target);
+        }
+        groups
+    }
+
+    /// Names of all categories in use, sorted
+    pub fn categories(&self) -> Vec<String> {
+        let mut categories: Vec<String> = self.targets.iter().map(|t| t.category.clone()).collect();
+        categories.sort();
+        categories.dedup();
+        categories
+    }
+
+    /// Move a target to another category and save it
+    pub fn set_category(&mut self, target_id: &str, category: &str) -> Result<()> {
+        let target = self.targets.iter_mut()
+            .find(|t| t.id == target_id)
+            .ok_or_else(|| AppError::ParseError(format!("Target not found: {}", target_id)))?;
+
+        let category = category.trim();
+        target.category = if category.is_empty() { default_category() } else { category.to_string() };
+        let target = target.clone();
+        self.save_target(&target)
+    }
+
+    /// Change whether a target is scrolled to when it isn't visible and save it
+    pub fn set_auto_scroll(&mut self, target_id: &str, enabled: bool, max_attempts: u8) -> Result<()> {
+        let target = self.targets.iter_mut()
+            .find(|t| t.id == target_id)
+            .ok_or_else(|| AppError::ParseError(format!("Target not found: {}", target_id)))?;
+
+        target.auto_scroll_to_target = enabled;
+        target.max_scroll_attempts = max_attempts;
+        let target = target.clone();
+        self.save_target(&target)
+    }
+
+    /// Change where a target is clicked and save it
+    pub fn set_click_alignment(&mut self, target_id: &str, alignment: ClickAlignment) -> Result<()> {
+        let target = self.targets.iter_mut()
+            .find(|t| t.id == target_id)
+            .ok_or_else(|| AppError::ParseError(format!("Target not found: {}", target_id)))?;
+
+        target.click_alignment = Some(alignment);
+        let target = target.clone();
+        self.save_target(&target)
+    }
+
+    /// Re-encode a PNG target as JPEG at the given quality (1-100) to shrink it
+    ///
+    /// Fails for targets that are already compressed and for targets with transparent
+    /// pixels, since JPEG would turn the masked-out areas into solid color.
+    pub fn compress_target(&mut self, target_id: &str, quality: u8) -> Result<()> {
+        let target = self.targets.iter_mut()
+            .find(|t| t.id == target_id)
+            .ok_or_else(|| AppError::ParseError(format!("Target not found: {}", target_id)))?;
+
+        let image_data = general_purpose::STANDARD.decode(&target.image_data)?;
+        if image::guess_format(&image_data)? != image::ImageFormat::Png {
+            return Err(AppError::ParseError("Target is already compressed".to_string()));
+        }
+
+        let image = image::load_from_memory(&image_data)?;
+        if has_transparency(&image) {
+            return Err(AppError::ParseError("Target has transparent pixels that JPEG can't keep".to_string()));
+        }
+
+        let mut buffer = Vec::new();
+        DynamicImage::ImageRgb8(image.to_rgb8())
+            .write_to(&mut Cursor::new(&mut buffer), image::ImageOutputFormat::Jpeg(quality.clamp(1, 100)))?;
+
+        target.image_data = general_purpose::STANDARD.encode(&buffer);
+        let target = target.clone();
+        self.save_target(&target)
+    }
+
+    /// Replace a target's image, keeping its threshold, click position and other settings
+    pub fn update_target_image(&mut self, target_id: &str, image_data: String) -> Result<()> {
+        let target = self.targets.iter_mut()
+            .find(|t| t.id == target_id)
+            .ok_or_else(|| AppError::ParseError(format!("Target not found: {}", target_id)))?;
+
+        target.image_data = image_data;
+        let target = target.clone();
+        self.save_target(&target)
+    }
+
+    /// Export a target as a PNG file with its settings in a sidecar JSON file
+    pub fn export_target(&self, target_id: &str, png_path: &Path) -> Result<()> {
+        let target = self.targets.iter()
+            .find(|t| t.id == target_id)
+            .ok_or_else(|| AppError::ParseError(format!("Target not found: {}", target_id)))?;
+
+        // PNG data is written unchanged; compressed targets are converted back to PNG
+        let image_data = general_purpose::STANDARD.decode(&target.image_data)?;
+        if image::guess_format(&image_data)? == image::ImageFormat::Png {
+            fs::write(png_path, &image_data)?;
+        } else {
+            image::load_from_memory(&image_data)?.save_with_format(png_path, image::ImageFormat::Png)?;
+        }
+
+        let metadata = TargetMetadata {
+            name: target.name.clone(),
+            // Export the threshold the user chose rather than a temporarily lowered one
+            threshold: if target.original_threshold > 0.0 { target.original_threshold } else { target.threshold },
+            click_offset: target.click_offset,
+            click_alignment: target.click_alignment,
+            auto_scroll_to_target: target.auto_scroll_to_target,
+            max_scroll_attempts: target.max_scroll_attempts,
+            category: target.category.clone(),
+            auto_adjust_threshold: target.auto_adjust_threshold,
+            miss_tolerance: target.miss_tolerance,
+        };
+        fs::write(png_path.with_extension("json"), serde_json::to_string_pretty(&metadata)?)?;
+
+        Ok(())
+    }
+
+    /// Import a target from a PNG file, using its sidecar JSON file if there is one
+    pub fn import_target(&mut self, png_path: &Path) -> Result<TargetImage> {
+        let target = Self::read_import(png_path, new_target_id())?;
+        self.save_target(&target)?;
+        self.targets.push(target.clone());
+
+        Ok(target)
+    }
+
+    /// Import every PNG in a directory as a target, returning the imported targets and how many
+    /// files were skipped because they couldn't be read or saved.
+    ///
+    /// All the files are read first, then saved together. With the `parallel-io` feature the saves
+    /// run across threads, which `save_target` taking `&self` allows; without it they run one by one.
+    pub fn import_from_directory(&mut self, dir: &Path) -> Result<(Vec<TargetImage>, usize)> {
+        let mut png_paths: Vec<PathBuf> = fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("png")))
+            .collect();
+        png_paths.sort();
+
+        // IDs come from the clock, so the index keeps targets read in the same millisecond apart
+        let base_id = new_target_id();
+        let mut skipped = 0;
+        let targets: Vec<TargetImage> = png_paths.iter()
+            .enumerate()
+            .filter_map(|(i, path)| match Self::read_import(path, format!("{}_{}", base_id, i)) {
+                Ok(target) => Some(target),
+                Err(e) => {
+                    eprintln!("Skipping {:?}: {}", path, e);
+                    skipped += 1;
+                    None
+                },
+            })
+            .collect();
+
+        #[cfg(feature = "parallel-io")]
+        let saved: Vec<Result<()>> = targets.par_iter().map(|target| self.save_target(target)).collect();
+        #[cfg(not(feature = "parallel-io"))]
+        let saved: Vec<Result<()>> = targets.iter().map(|target| self.save_target(target)).collect();
+
+        let mut imported = Vec::new();
+        for (target, result) in targets.into_iter().zip(saved) {
+            match result {
+                Ok(()) => imported.push(target),
+                Err(e) => {
+                    eprintln!("Failed to save imported target '{}': {}", target.name, e);
+                    skipped += 1;
+                },
+            }
+        }
+
+        self.targets.extend(imported.iter().cloned());
+        Ok((imported, skipped))
+    }
+
+    /// Build a target with the given ID from a PNG file and its sidecar JSON file, without saving it
+    fn read_import(png_path: &Path, id: String) -> Result<TargetImage> {
+        let png_data = fs::read(png_path)?;
+
+        // Make sure the file is actually an image before adding it
+        image::load_from_memory(&png_data)?;
+
+        let metadata_path = png_path.with_extension("json");
+        let metadata = if metadata_path.exists() {
+            serde_json::from_str(&fs::read_to_string(&metadata_path)?)?
+        } else {
+            TargetMetadata {
+                name: png_path.file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .unwrap_or("Imported target")
+                    .to_string(),
+                threshold: DEFAULT_THRESHOLD,
+                click_offset: None,
+                click_alignment: None,
+                auto_scroll_to_target: false,
+                max_scroll_attempts: default_max_scroll_attempts(),
+                category: default_category(),
+                auto_adjust_threshold: false,
+                miss_tolerance: default_miss_tolerance(),
+            }
+        };
+
+        // Always use a fresh ID so the import can't overwrite an existing target
+        Ok(TargetImage {
+            id,
+            name: metadata.name,
+            image_data: general_purpose::STANDARD.encode(&png_data),
+            threshold: metadata.threshold,
+            click_offset: metadata.click_offset,
+            click_alignment: metadata.click_alignment,
+            auto_scroll_to_target: metadata.auto_scroll_to_target,
+            max_scroll_attempts: metadata.max_scroll_attempts,
+            category: metadata.category,
+            last_used: None,
+            auto_adjust_threshold: metadata.auto_adjust_threshold,
+            miss_tolerance: metadata.miss_tolerance,
+            original_threshold: metadata.threshold,
+        })
+    }
+
+    /// Add a target made from an image, such as one pasted from the clipboard
+    pub fn add_image_target(&mut self, name: &str, image: &DynamicImage) -> Result<TargetImage> {
+        let target = TargetImage {
+            id: new_target_id(),
+            name: name.to_string(),
+            image_data: image_to_base64(image)?,
+            threshold: DEFAULT_THRESHOLD,
+            click_offset: None,
+            click_alignment: Some(ClickAlignment::Center),
+            auto_scroll_to_target: false,
+            max_scroll_attempts: default_max_scroll_attempts(),
+            category: default_category(),
+            last_used: None,
+            auto_adjust_threshold: false,
+            miss_tolerance: default_miss_tolerance(),
+            original_threshold: DEFAULT_THRESHOLD,
+        };
+
+        self.save_target(&target)?;
+        self.targets.push(target.clone());
+
+        Ok(target)
+    }
+
+    /// Delete every target that hasn't been searched for in `older_than_days` days, returning their names.
+    /// Targets that have never been searched for are kept, since there's no telling how old they are.
+    pub fn prune_stale(&mut self, older_than_days: u32) -> Result<Vec<String>> {
+        let cutoff = Utc::now() - chrono::Duration::days(older_than_days as i64);
+        let stale: Vec<(String, String)> = self.targets.iter()
+            .filter(|t| t.last_used.is_some_and(|used| used < cutoff))
+            .map(|t| (t.id.clone(), t.name.clone()))
+            .collect();
+
+        let mut removed = Vec::new();
+        for (id, name) in stale {
+            self.delete_target(&id)?;
+            removed.push(name);
+        }
+        Ok(removed)
+    }
+
     /// Delete a target by ID
     pub fn delete_target(&mut self, target_id: &str) -> Result<()> {
         let file_path = self.targets_dir.join(format!("{}.json", target_id));
@@ -210,6 +951,15 @@ impl ImageLibrary {
         if file_path.exists() {
             fs::remove_file(file_path)?;
             self.targets.retain(|t| t.id != target_id);
+            self.consecutive_misses.remove(target_id);
+
+            let match_log = self.match_log_path(target_id);
+            if match_log.exists() {
+                if let Err(e) = fs::remove_file(&match_log) {
+                    eprintln!("Failed to remove match log {:?}: {}", match_log, e);
+                }
+            }
+            self.last_match_logs.remove(target_id);
             Ok(())
         } else {
             Err(AppError::ParseError(format!("Target not found: {}", target_id)))
@@ -217,12 +967,39 @@ impl ImageLibrary {
     }
 }
 
+/// Latest entry of a match log, read from the end of the file so long logs stay cheap
+fn read_last_match_log(path: &Path) -> Option<ImageMatchLog> {
+    let mut file = fs::File::open(path).ok()?;
+    let len = file.metadata().ok()?.len();
+    file.seek(SeekFrom::Start(len.saturating_sub(MATCH_LOG_TAIL_BYTES))).ok()?;
+
+    let mut tail = String::new();
+    file.read_to_string(&mut tail).ok()?;
+    tail.lines()
+        .rev()
+        .find_map(|line| serde_json::from_str(line).ok())
+}
+
 /// Find a template image within a larger image using template matching
-fn find_template(
+pub fn find_template(
     screen: &DynamicImage,
     template: &DynamicImage,
     threshold: f32,
 ) -> Option<(i32, i32)> {
+    best_template_match(screen, template, threshold)
+        .filter(|(correlation, _, _)| *correlation >= threshold)
+        .map(|(_, x, y)| (x, y))
+}
+
+/// Locate the best match for a template, returning its correlation score and position
+///
+/// The threshold only controls when the coarse first pass is refined; the best score
+/// is returned whether or not it reaches the threshold.
+fn best_template_match(
+    screen: &DynamicImage,
+    template: &DynamicImage,
+    threshold: f32,
+) -> Option<(f32, i32, i32)> {
     // Convert images to grayscale for faster processing
     let screen_gray = screen.to_luma8();
     let template_gray = template.to_luma8();
@@ -255,51 +1032,33 @@ fn find_template(
             template_gray.clone()
         };
 
-        // Simple normalized cross-correlation template matching
-        let mut best_match = (0.0, 0, 0);
-
         // Step size for faster scanning (check every 2nd pixel first)
         let initial_step = 2;
-        let mut found_match = false;
-
-        // First pass: scan with step size
-        for y in (0..=(screen_height - scaled_height)).step_by(initial_step) {
-            for x in (0..=(screen_width - scaled_width)).step_by(initial_step) {
-                // Calculate correlation values
-                let mut template_sum_squared = 0.0;
-                let mut screen_sum_squared = 0.0;
-                let mut cross_correlation = 0.0;
-
-                // Calculate normalized cross-correlation for this position
-                // Use sampling to speed up initial pass
-                for ty in (0..scaled_height).step_by(initial_step) {
-                    for tx in (0..scaled_width).step_by(initial_step) {
-                        let template_pixel = template_to_use.get_pixel(tx, ty)[0] as f32;
-                        let screen_pixel = screen_gray.get_pixel(x + tx, y + ty)[0] as f32;
-
-                        cross_correlation += template_pixel * screen_pixel;
-                        template_sum_squared += template_pixel * template_pixel;
-                        screen_sum_squared += screen_pixel * screen_pixel;
-                    }
-                }
+        let max_x = screen_width - scaled_width;
+
+        // Best position in one row of the first pass, sampling every `initial_step` pixels
+        let scan_row = |y: u32| -> (f32, i32, i32) {
+            (0..=max_x).step_by(initial_step).fold((0.0, 0, 0), |best, x| {
+                let correlation = correlation_at(&screen_gray, &template_to_use, x, y, initial_step);
+                if correlation > best.0 { (correlation, x as i32, y as i32) } else { best }
+            })
+        };
 
-                // Normalize the correlation
-                let denominator = (template_sum_squared * screen_sum_squared).sqrt();
-                let correlation = if denominator > 0.0 {
-                    cross_correlation / denominator
-                } else {
-                    0.0
-                };
-
-                // Update best match if this is better
-                if correlation > best_match.0 {
-                    best_match = (correlation, x as i32, y as i32);
-                    if correlation >= threshold * 0.8 { // Lower threshold for first pass
-                        found_match = true;
-                    }
-                }
-            }
-        }
+        // First pass: scan with step size. Rows are independent, so with `parallel-matching` they're
+        // scanned across threads; reducing them in row order keeps the result identical either way.
+        let rows: Vec<u32> = (0..=(screen_height - scaled_height)).step_by(initial_step).collect();
+
+        #[cfg(feature = "parallel-matching")]
+        let row_matches: Vec<(f32, i32, i32)> = rows.par_iter().map(|&y| scan_row(y)).collect();
+        #[cfg(not(feature = "parallel-matching"))]
+        let row_matches: Vec<(f32, i32, i32)> = rows.iter().map(|&y| scan_row(y)).collect();
+
+        let mut best_match = row_matches
+            .into_iter()
+            .fold((0.0, 0, 0), |best, row| if row.0 > best.0 { row } else { best });
+
+        // Lower threshold for first pass
+        let found_match = best_match.0 > 0.0 && best_match.0 >= threshold * 0.8;
 
         // If we found a good match in the first pass, refine it
         if found_match {
@@ -314,30 +1073,7 @@ fn find_template(
             // Second pass: detailed scan in the refined area
             for y in start_y..=end_y {
                 for x in start_x..=end_x {
-                    // Calculate correlation values
-                    let mut template_sum_squared = 0.0;
-                    let mut screen_sum_squared = 0.0;
-                    let mut cross_correlation = 0.0;
-
-                    // Calculate normalized cross-correlation for this position
-                    for ty in 0..scaled_height {
-                        for tx in 0..scaled_width {
-                            let template_pixel = template_to_use.get_pixel(tx, ty)[0] as f32;
-                            let screen_pixel = screen_gray.get_pixel(x + tx, y + ty)[0] as f32;
-
-                            cross_correlation += template_pixel * screen_pixel;
-                            template_sum_squared += template_pixel * template_pixel;
-                            screen_sum_squared += screen_pixel * screen_pixel;
-                        }
-                    }
-
-                    // Normalize the correlation
-                    let denominator = (template_sum_squared * screen_sum_squared).sqrt();
-                    let correlation = if denominator > 0.0 {
-                        cross_correlation / denominator
-                    } else {
-                        0.0
-                    };
+                    let correlation = correlation_at(&screen_gray, &template_to_use, x, y, 1);
 
                     // Update best match if this is better
                     if correlation > best_match.0 {
@@ -353,14 +1089,136 @@ fn find_template(
         }
     }
 
-    // Return the best match if it exceeds the threshold
-    if global_best_match.0 >= threshold {
-        Some((global_best_match.1, global_best_match.2))
+    Some((global_best_match.0, global_best_match.1, global_best_match.2))
+}
+
+/// Normalized cross-correlation of the template placed at (x, y), sampling every `step` template pixels
+pub fn correlation_at(screen: &GrayImage, template: &GrayImage, x: u32, y: u32, step: usize) -> f32 {
+    correlation_with(screen, template, x, y, step, accumulate_row)
+}
+
+/// `correlation_at` without the AVX2 path, to check and time the vectorized one against
+pub fn correlation_at_scalar(screen: &GrayImage, template: &GrayImage, x: u32, y: u32, step: usize) -> f32 {
+    correlation_with(screen, template, x, y, step, |template_row, screen_row, step, sums| {
+        accumulate_row_from(template_row, screen_row, 0, step, sums)
+    })
+}
+
+/// Normalized cross-correlation with each row's sums added up by `accumulate`
+fn correlation_with(
+    screen: &GrayImage,
+    template: &GrayImage,
+    x: u32,
+    y: u32,
+    step: usize,
+    accumulate: impl Fn(&[u8], &[u8], usize, &mut [f32; 3]),
+) -> f32 {
+    let (template_width, template_height) = template.dimensions();
+    let (template_width, screen_width) = (template_width as usize, screen.width() as usize);
+    let (template_pixels, screen_pixels) = (template.as_raw(), screen.as_raw());
+
+    // (cross correlation, template sum squared, screen sum squared)
+    let mut sums = [0.0; 3];
+
+    for ty in (0..template_height).step_by(step) {
+        let template_start = ty as usize * template_width;
+        let screen_start = (y + ty) as usize * screen_width + x as usize;
+        accumulate(
+            &template_pixels[template_start..template_start + template_width],
+            &screen_pixels[screen_start..screen_start + template_width],
+            step,
+            &mut sums,
+        );
+    }
+
+    // Normalize the correlation
+    let [cross_correlation, template_sum_squared, screen_sum_squared] = sums;
+    let denominator = (template_sum_squared * screen_sum_squared).sqrt();
+    if denominator > 0.0 {
+        cross_correlation / denominator
     } else {
-        None
+        0.0
     }
 }
 
+/// Add every `step`th pixel pair of one template row and the screen row under it to `sums`
+fn accumulate_row(template_row: &[u8], screen_row: &[u8], step: usize, sums: &mut [f32; 3]) {
+    // Builds with AVX2 enabled (e.g. `-C target-cpu=native`) take 8 pixels at a time and leave the rest here
+    #[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+    let start = if step <= 2 {
+        // SAFETY: this is only compiled in when the target has AVX2
+        unsafe { accumulate_row_avx2(template_row, screen_row, step, sums) }
+    } else {
+        0
+    };
+    #[cfg(not(all(target_arch = "x86_64", target_feature = "avx2")))]
+    let start = 0;
+
+    accumulate_row_from(template_row, screen_row, start, step, sums);
+}
+
+/// The scalar loop of `accumulate_row`, over every `step`th pixel from `start`
+fn accumulate_row_from(template_row: &[u8], screen_row: &[u8], start: usize, step: usize, sums: &mut [f32; 3]) {
+    for tx in (start..template_row.len()).step_by(step) {
+        let template_pixel = template_row[tx] as f32;
+        let screen_pixel = screen_row[tx] as f32;
+
+        sums[0] += template_pixel * screen_pixel;
+        sums[1] += template_pixel * template_pixel;
+        sums[2] += screen_pixel * screen_pixel;
+    }
+}
+
+/// The vectorized part of `accumulate_row` for a step of 1 or 2. Returns the index of the
+/// first pixel it didn't cover, which the scalar loop picks up from.
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+unsafe fn accumulate_row_avx2(template_row: &[u8], screen_row: &[u8], step: usize, sums: &mut [f32; 3]) -> usize {
+    use std::arch::x86_64::*;
+
+    debug_assert!(step == 1 || step == 2);
+    debug_assert_eq!(template_row.len(), screen_row.len());
+
+    // Eight samples span this many bytes of the row
+    let chunk = 8 * step;
+    // Moves the even bytes of a 16 byte load to the front, for a step of 2
+    let even_bytes = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, -1, -1, -1, -1, -1, -1, -1, -1);
+
+    let load = |row: &[u8], offset: usize| -> __m256 {
+        let bytes = if step == 1 {
+            _mm_loadl_epi64(row.as_ptr().add(offset) as *const __m128i)
+        } else {
+            _mm_shuffle_epi8(_mm_loadu_si128(row.as_ptr().add(offset) as *const __m128i), even_bytes)
+        };
+        _mm256_cvtepi32_ps(_mm256_cvtepu8_epi32(bytes))
+    };
+
+    let mut cross_correlation = _mm256_setzero_ps();
+    let mut template_sum_squared = _mm256_setzero_ps();
+    let mut screen_sum_squared = _mm256_setzero_ps();
+
+    let mut tx = 0;
+    while tx + chunk <= template_row.len() {
+        let template_pixels = load(template_row, tx);
+        let screen_pixels = load(screen_row, tx);
+
+        cross_correlation = _mm256_add_ps(cross_correlation, _mm256_mul_ps(template_pixels, screen_pixels));
+        template_sum_squared = _mm256_add_ps(template_sum_squared, _mm256_mul_ps(template_pixels, template_pixels));
+        screen_sum_squared = _mm256_add_ps(screen_sum_squared, _mm256_mul_ps(screen_pixels, screen_pixels));
+        tx += chunk;
+    }
+
+    let lane_sum = |lanes: __m256| -> f32 {
+        let mut values = [0.0f32; 8];
+        _mm256_storeu_ps(values.as_mut_ptr(), lanes);
+        values.iter().sum()
+    };
+    sums[0] += lane_sum(cross_correlation);
+    sums[1] += lane_sum(template_sum_squared);
+    sums[2] += lane_sum(screen_sum_squared);
+
+    tx
+}
+
 /// Convert a base64 encoded image to a DynamicImage
 pub fn base64_to_image(base64_data: &str) -> Result<DynamicImage> {
     println!("Converting base64 to image, data length: {}", base64_data.len());
@@ -395,6 +1253,11 @@ pub fn base64_to_image(base64_data: &str) -> Result<DynamicImage> {
     }
 }
 
+/// Whether any pixel of an image is less than fully opaque
+pub fn has_transparency(image: &DynamicImage) -> bool {
+    image.color().has_alpha() && image.to_rgba8().pixels().any(|pixel| pixel.0[3] < 255)
+}
+
 /// Convert a DynamicImage to base64 encoded string
 pub fn image_to_base64(image: &DynamicImage) -> Result<String> {
     let mut buffer = Vec::new();
@@ -423,13 +1286,48 @@ pub fn capture_screen() -> Result<RgbaImage> {
             return Err(AppError::ParseError("Failed to create compatible DC".to_string()));
         }
 
-        // Create a compatible bitmap
-        let bitmap = Gdi::CreateCompatibleBitmap(screen_dc, screen_width, screen_height);
-        if bitmap.is_invalid() {
-            Gdi::DeleteDC(compatible_dc);
-            Gdi::ReleaseDC(HWND(0), screen_dc);
-            return Err(AppError::ParseError("Failed to create compatible bitmap".to_string()));
-        }
+        // Describe a 32-bit top-down DIB the size of the screen
+        let bitmap_info = Gdi::BITMAPINFO {
+            bmiHeader: Gdi::BITMAPINFOHEADER {
+                biSize: size_of::<Gdi::BITMAPINFOHEADER>() as u32,
+                biWidth: screen_width,
+                biHeight: -screen_height, // Negative height for top-down DIB
+                biPlanes: 1,
+                biBitCount: 32,
+                biCompression: 0, // BI_RGB = 0
+                biSizeImage: 0,
+                biXPelsPerMeter: 0,
+                biYPelsPerMeter: 0,
+                biClrUsed: 0,
+                biClrImportant: 0,
+            },
+            bmiColors: [Gdi::RGBQUAD::default()],
+        };
+
+        // A DIB section lives in system memory, so BitBlt writes pixels we can read directly
+        // without a GetDIBits round trip
+        let mut bits: *mut std::ffi::c_void = std::ptr::null_mut();
+        let bitmap = match Gdi::CreateDIBSection(
+            compatible_dc,
+            &bitmap_info,
+            Gdi::DIB_RGB_COLORS,
+            &mut bits,
+            HANDLE(0),
+            0
+        ) {
+            Ok(bitmap) if !bits.is_null() => bitmap,
+            Ok(bitmap) => {
+                Gdi::DeleteObject(bitmap);
+                Gdi::DeleteDC(compatible_dc);
+                Gdi::ReleaseDC(HWND(0), screen_dc);
+                return Err(AppError::ParseError("DIB section has no pixel buffer".to_string()));
+            },
+            Err(e) => {
+                Gdi::DeleteDC(compatible_dc);
+                Gdi::ReleaseDC(HWND(0), screen_dc);
+                return Err(AppError::ParseError(format!("Failed to create DIB section: {}", e)));
+            },
+        };
 
         // Select the bitmap into the compatible DC
         let old_bitmap = Gdi::SelectObject(compatible_dc, bitmap);
@@ -450,66 +1348,144 @@ pub fn capture_screen() -> Result<RgbaImage> {
             return Err(AppError::ParseError("Failed to copy screen to bitmap".to_string()));
         }
 
-        // Get bitmap information
+        // Make sure GDI has finished drawing before the bits are read
+        let _ = Gdi::GdiFlush();
+
+        // Copy out of the DIB (it is freed below) and convert BGRA to RGBA in the same pass
+        let buffer_size = (screen_width * screen_height * 4) as usize;
+        let pixels = std::slice::from_raw_parts(bits as *const u8, buffer_size);
+        let mut buffer = Vec::with_capacity(buffer_size);
+        for bgra in pixels.chunks_exact(4) {
+            buffer.extend_from_slice(&[bgra[2], bgra[1], bgra[0], bgra[3]]);
+        }
+
+        // Clean up
+        Gdi::SelectObject(compatible_dc, old_bitmap);
+        Gdi::DeleteObject(bitmap);
+        Gdi::DeleteDC(compatible_dc);
+        Gdi::ReleaseDC(HWND(0), screen_dc);
+
+        // Create an RgbaImage from the buffer
+        RgbaImage::from_raw(screen_width as u32, screen_height as u32, buffer)
+            .ok_or_else(|| AppError::ParseError("Failed to create image from buffer".to_string()))
+    }
+}
+
+/// Capture the client area of the top-level window with exactly this title, along with the
+/// client area's top-left corner in desktop coordinates. `PrintWindow` asks the window to draw
+/// itself, so this works while it's covered by other windows.
+pub fn capture_window(title: &str) -> Result<(RgbaImage, (i32, i32))> {
+    let title: Vec<u16> = title.encode_utf16().chain(std::iter::once(0)).collect();
+
+    unsafe {
+        let hwnd = FindWindowW(PCWSTR::null(), PCWSTR(title.as_ptr()));
+        if hwnd.0 == 0 {
+            return Err(AppError::ParseError("No window with that title is open".to_string()));
+        }
+
+        let mut client = RECT::default();
+        GetClientRect(hwnd, &mut client)
+            .map_err(|e| AppError::ParseError(format!("Failed to read the window size: {}", e)))?;
+        let (width, height) = (client.right - client.left, client.bottom - client.top);
+        if width <= 0 || height <= 0 {
+            return Err(AppError::ParseError("The window is minimized or has no client area".to_string()));
+        }
+
+        let mut origin = POINT::default();
+        let _ = Gdi::ClientToScreen(hwnd, &mut origin);
+
+        let window_dc = Gdi::GetDC(hwnd);
+        if window_dc.is_invalid() {
+            return Err(AppError::ParseError("Failed to get window DC".to_string()));
+        }
+
+        let compatible_dc = Gdi::CreateCompatibleDC(window_dc);
+        let bitmap = Gdi::CreateCompatibleBitmap(window_dc, width, height);
+        if compatible_dc.is_invalid() || bitmap.is_invalid() {
+            Gdi::DeleteObject(bitmap);
+            Gdi::DeleteDC(compatible_dc);
+            Gdi::ReleaseDC(hwnd, window_dc);
+            return Err(AppError::ParseError("Failed to create a bitmap for the window".to_string()));
+        }
+        let old_bitmap = Gdi::SelectObject(compatible_dc, bitmap);
+
+        let printed = PrintWindow(hwnd, compatible_dc, PW_CLIENTONLY).as_bool();
+
+        // Read the pixels back as a 32-bit top-down DIB
         let mut bitmap_info = Gdi::BITMAPINFO {
             bmiHeader: Gdi::BITMAPINFOHEADER {
                 biSize: size_of::<Gdi::BITMAPINFOHEADER>() as u32,
-                biWidth: screen_width,
-                biHeight: -screen_height, // Negative height for top-down DIB
+                biWidth: width,
+                biHeight: -height,
                 biPlanes: 1,
                 biBitCount: 32,
                 biCompression: 0, // BI_RGB = 0
-                biSizeImage: 0,
-                biXPelsPerMeter: 0,
-                biYPelsPerMeter: 0,
-                biClrUsed: 0,
-                biClrImportant: 0,
+                ..Default::default()
             },
             bmiColors: [Gdi::RGBQUAD::default()],
         };
-
-        // Create a buffer for the bitmap data
-        let buffer_size = (screen_width * screen_height * 4) as usize;
-        // Use with_capacity to avoid unnecessary initialization
-        let mut buffer = Vec::with_capacity(buffer_size);
-        buffer.resize(buffer_size, 0);
-
-        // Get the bitmap data
-        if Gdi::GetDIBits(
+        let mut pixels = vec![0u8; (width * height * 4) as usize];
+        Gdi::SelectObject(compatible_dc, old_bitmap);
+        let lines = Gdi::GetDIBits(
             compatible_dc,
             bitmap,
             0,
-            screen_height as u32,
-            Some(buffer.as_mut_ptr() as *mut std::ffi::c_void),
+            height as u32,
+            Some(pixels.as_mut_ptr() as *mut _),
             &mut bitmap_info,
-            Gdi::DIB_RGB_COLORS
-        ) == 0 {
-            Gdi::SelectObject(compatible_dc, old_bitmap);
-            Gdi::DeleteObject(bitmap);
-            Gdi::DeleteDC(compatible_dc);
-            Gdi::ReleaseDC(HWND(0), screen_dc);
-            return Err(AppError::ParseError("Failed to get bitmap data".to_string()));
-        }
+            Gdi::DIB_RGB_COLORS,
+        );
 
         // Clean up
-        Gdi::SelectObject(compatible_dc, old_bitmap);
         Gdi::DeleteObject(bitmap);
         Gdi::DeleteDC(compatible_dc);
-        Gdi::ReleaseDC(HWND(0), screen_dc);
+        Gdi::ReleaseDC(hwnd, window_dc);
 
-        // Convert BGRA to RGBA
-        for i in (0..buffer_size).step_by(4) {
-            let b = buffer[i];
-            buffer[i] = buffer[i + 2];
-            buffer[i + 2] = b;
+        if !printed {
+            return Err(AppError::ParseError("The window didn't draw itself for the capture".to_string()));
+        }
+        if lines == 0 {
+            return Err(AppError::ParseError("Failed to read the window bitmap".to_string()));
         }
 
-        // Create an RgbaImage from the buffer
-        RgbaImage::from_raw(screen_width as u32, screen_height as u32, buffer)
-            .ok_or_else(|| AppError::ParseError("Failed to create image from buffer".to_string()))
+        // BGRA to RGBA; PrintWindow leaves alpha undefined, so make every pixel opaque
+        for bgra in pixels.chunks_exact_mut(4) {
+            bgra.swap(0, 2);
+            bgra[3] = 255;
+        }
+
+        let image = RgbaImage::from_raw(width as u32, height as u32, pixels)
+            .ok_or_else(|| AppError::ParseError("Failed to create image from buffer".to_string()))?;
+        Ok((image, (origin.x, origin.y)))
     }
 }
 
+/// Points per side of the grid sampled by `average_region_color`
+const COLOR_SAMPLE_GRID: u32 = 8;
+
+/// Average color of a screen region, from an 8×8 grid of points spread over it
+pub fn average_region_color(x: i32, y: i32, width: u32, height: u32) -> Result<[u8; 3]> {
+    let region = capture_screen_area(x, y, width.max(1), height.max(1))?;
+    let (width, height) = region.dimensions();
+
+    let mut sums = [0u32; 3];
+    let mut samples = 0;
+    for row in 0..COLOR_SAMPLE_GRID.min(height) {
+        for col in 0..COLOR_SAMPLE_GRID.min(width) {
+            // Sample the middle of each grid cell
+            let px = (col * 2 + 1) * width / (COLOR_SAMPLE_GRID.min(width) * 2);
+            let py = (row * 2 + 1) * height / (COLOR_SAMPLE_GRID.min(height) * 2);
+            let pixel = region.get_pixel(px, py);
+            for (sum, &channel) in sums.iter_mut().zip(&pixel.0[..3]) {
+                *sum += channel as u32;
+            }
+            samples += 1;
+        }
+    }
+
+    Ok(sums.map(|sum| (sum / samples.max(1)) as u8))
+}
+
 /// Capture a screenshot of a specific area of the screen
 pub fn capture_screen_area(x: i32, y: i32, width: u32, height: u32) -> Result<RgbaImage> {
     let full_screenshot = capture_screen()?;
@@ -517,3 +1493,214 @@ pub fn capture_screen_area(x: i32, y: i32, width: u32, height: u32) -> Result<Rg
         .crop(x as u32, y as u32, width, height);
     Ok(cropped.to_rgba8())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An empty directory under the system temp dir, removed again by the caller
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("mouse_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn exported_target_imports_with_the_same_settings() {
+        let dir = temp_dir("target_round_trip");
+        let mut library = ImageLibrary::new(dir.join("targets"));
+
+        let image = DynamicImage::ImageRgba8(RgbaImage::from_fn(6, 4, |x, y| {
+            image::Rgba([(x * 40) as u8, (y * 60) as u8, 128, 255])
+        }));
+        let id = library.add_image_target("Play button", &image).unwrap().id;
+        {
+            let target = &mut library.targets[0];
+            target.threshold = 0.7;
+            target.original_threshold = 0.75;
+            target.click_offset = Some((3, 2));
+            target.click_alignment = Some(ClickAlignment::Custom(1, 2));
+            target.auto_scroll_to_target = true;
+            target.max_scroll_attempts = 9;
+            target.category = "Menus".to_string();
+            target.auto_adjust_threshold = true;
+            target.miss_tolerance = 7;
+        }
+
+        let png_path = dir.join("play_button.png");
+        library.export_target(&id, &png_path).unwrap();
+        assert!(png_path.with_extension("json").exists());
+
+        let mut other = ImageLibrary::new(dir.join("other_targets"));
+        let imported = other.import_target(&png_path).unwrap();
+        let original = &library.targets[0];
+
+        assert_ne!(imported.id, original.id);
+        assert_eq!(imported.name, original.name);
+        assert_eq!(imported.image_data, original.image_data);
+        // The lowered threshold isn't exported, only the one the user chose
+        assert_eq!(imported.threshold, original.original_threshold);
+        assert_eq!(imported.original_threshold, original.original_threshold);
+        assert_eq!(imported.click_offset, original.click_offset);
+        assert_eq!(imported.click_alignment, original.click_alignment);
+        assert_eq!(imported.auto_scroll_to_target, original.auto_scroll_to_target);
+        assert_eq!(imported.max_scroll_attempts, original.max_scroll_attempts);
+        assert_eq!(imported.category, original.category);
+        assert_eq!(imported.auto_adjust_threshold, original.auto_adjust_threshold);
+        assert_eq!(imported.miss_tolerance, original.miss_tolerance);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fn noise(width: u32, height: u32, seed: u64) -> GrayImage {
+        use rand::{Rng, SeedableRng};
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        GrayImage::from_fn(width, height, |_, _| image::Luma([rng.gen()]))
+    }
+
+    #[test]
+    fn vectorized_correlation_matches_the_scalar_loop() {
+        let screen = noise(200, 120, 1);
+        // Widths around multiples of 8 and 16 leave every possible remainder for the scalar loop
+        for template_width in 1..=40 {
+            let template = noise(template_width, 12, template_width as u64);
+            for step in [1, 2, 3] {
+                for (x, y) in [(0, 0), (7, 3), (150, 100)] {
+                    let vectorized = correlation_at(&screen, &template, x, y, step);
+                    let scalar = correlation_at_scalar(&screen, &template, x, y, step);
+                    assert!(
+                        (vectorized - scalar).abs() < 1e-4,
+                        "width {} step {} at ({}, {}): {} vs {}",
+                        template_width, step, x, y, vectorized, scalar
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn find_template_locates_a_template_cut_from_the_screen() {
+        // Blurred so the coarse first pass, which only visits every other pixel, lands near the
+        // match; on raw noise a one pixel shift looks no more alike than any other position
+        let screen = image::imageops::blur(&noise(320, 200, 7), 3.0);
+        let template = image::imageops::crop_imm(&screen, 181, 57, 40, 30).to_image();
+
+        let found = find_template(
+            &DynamicImage::ImageLuma8(screen),
+            &DynamicImage::ImageLuma8(template),
+            DEFAULT_THRESHOLD,
+        );
+        assert_eq!(found, Some((181, 57)));
+    }
+
+    #[test]
+    fn find_template_misses_a_template_that_is_not_there() {
+        let screen = DynamicImage::ImageLuma8(noise(320, 200, 7));
+        let template = DynamicImage::ImageLuma8(noise(40, 30, 8));
+
+        assert_eq!(find_template(&screen, &template, 0.95), None);
+    }
+
+    #[test]
+    fn find_any_on_screen_clicks_the_target_present_and_logs_every_score() {
+        let dir = temp_dir("find_any");
+        let mut library = ImageLibrary::new(dir.join("targets"));
+
+        let screen = DynamicImage::ImageLuma8(image::imageops::blur(&noise(320, 200, 7), 3.0)).to_rgba8();
+        let present = image::imageops::crop_imm(&screen, 181, 57, 40, 30).to_image();
+        // The correlation isn't mean-centered, so anything mid-gray would score well against the
+        // blurred screen. Hard black and white squares don't.
+        let absent = DynamicImage::ImageLuma8(GrayImage::from_fn(40, 30, |x, y| image::Luma([if (x / 4 + y / 4) % 2 == 0 { 0 } else { 255 }])));
+        let present_id = library.add_image_target("Present", &DynamicImage::ImageRgba8(present)).unwrap().id;
+        // IDs come from the clock, so make sure the second one differs
+        thread::sleep(Duration::from_millis(2));
+        let absent_id = library.add_image_target("Absent", &absent).unwrap().id;
+
+        let found = library.find_any_on_screen(&screen, &[&absent_id, &present_id]).unwrap();
+        let expected = library.targets.iter().find(|t| t.id == present_id).unwrap().click_point(181, 57, 40, 30);
+        assert_eq!(found, Some((present_id.clone(), expected.0, expected.1)));
+        assert_eq!(library.last_match(), Some((181, 57, 40, 30)));
+
+        assert!(library.last_match_log(&present_id).unwrap().found);
+        assert!(!library.last_match_log(&absent_id).unwrap().found);
+        assert!(library.match_log_path(&absent_id).exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn reloaded_match_logs_include_searches_by_another_library() {
+        let dir = temp_dir("reload_match_logs");
+        let mut searcher = ImageLibrary::new(dir.join("targets"));
+        let screen = RgbaImage::from_fn(64, 48, |x, y| image::Rgba([(x * 4) as u8, (y * 5) as u8, 90, 255]));
+        let template = image::imageops::crop_imm(&screen, 10, 10, 16, 16).to_image();
+        let id = searcher.add_image_target("Gradient", &DynamicImage::ImageRgba8(template)).unwrap().id;
+
+        let mut viewer = ImageLibrary::new(dir.join("targets"));
+        viewer.load_targets().unwrap();
+        searcher.find_any_on_screen(&screen, &[&id]).unwrap();
+        assert!(viewer.last_match_log(&id).is_none());
+
+        viewer.reload_match_logs();
+        let logged = viewer.last_match_log(&id).unwrap();
+        assert_eq!(logged.score, searcher.last_match_log(&id).unwrap().score);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn repeated_misses_lower_the_threshold_until_a_match_restores_it() {
+        let dir = temp_dir("threshold_decay");
+        let mut library = ImageLibrary::new(dir.join("targets"));
+        let image = DynamicImage::ImageRgba8(RgbaImage::from_pixel(4, 4, image::Rgba([1, 2, 3, 255])));
+        let id = library.add_image_target("Decaying", &image).unwrap().id;
+        library.targets[0].auto_adjust_threshold = true;
+        library.targets[0].miss_tolerance = 3;
+        library.targets[0].threshold = THRESHOLD_DECAY_FLOOR + THRESHOLD_DECAY_STEP;
+        library.targets[0].original_threshold = THRESHOLD_DECAY_FLOOR + THRESHOLD_DECAY_STEP;
+
+        for _ in 0..2 {
+            library.record_search_result(&id, false);
+        }
+        assert!(!library.targets[0].threshold_adjusted());
+
+        library.record_search_result(&id, false);
+        assert!((library.targets[0].threshold - THRESHOLD_DECAY_FLOOR).abs() < 1e-6);
+
+        // Already at the floor, so further misses change nothing
+        for _ in 0..3 {
+            library.record_search_result(&id, false);
+        }
+        assert!((library.targets[0].threshold - THRESHOLD_DECAY_FLOOR).abs() < 1e-6);
+        assert_eq!(library.take_threshold_adjustments().len(), 1);
+
+        library.record_search_result(&id, true);
+        assert!(!library.targets[0].threshold_adjusted());
+        assert_eq!(library.take_threshold_adjustments().len(), 1);
+
+        // The saved copy follows the in-memory one
+        let mut reloaded = ImageLibrary::new(dir.join("targets"));
+        reloaded.load_targets().unwrap();
+        assert_eq!(reloaded.targets[0].threshold, library.targets[0].threshold);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn png_without_metadata_imports_with_defaults() {
+        let dir = temp_dir("target_no_metadata");
+        let png_path = dir.join("Lonely Icon.png");
+        RgbaImage::from_pixel(3, 3, image::Rgba([10, 20, 30, 255])).save(&png_path).unwrap();
+
+        let mut library = ImageLibrary::new(dir.join("targets"));
+        let imported = library.import_target(&png_path).unwrap();
+
+        assert_eq!(imported.name, "Lonely Icon");
+        assert_eq!(imported.threshold, DEFAULT_THRESHOLD);
+        assert_eq!(imported.category, DEFAULT_CATEGORY);
+        assert_eq!(imported.click_alignment, None);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -59,7 +59,7 @@ pub fn spawn_keyboard_handler(
 }
 
 pub fn handle_timed_pause(is_paused: &AtomicBool) -> Result<()> {
-    println!("Enter the pause duration (e.g., 1m30s): ");
+    println!("Enter the pause duration (e.g., 1h30m, 1m30s, 500ms): ");
     io::stdout().flush()?;
     disable_raw_mode()?;
 
@@ -82,25 +82,114 @@ pub fn handle_timed_pause(is_paused: &AtomicBool) -> Result<()> {
 }
 
 pub fn parse_duration(s: &str) -> Result<Duration> {
-    let re = Regex::new(r"(?i)^((?P<m>\d+)m)?((?P<s>\d+)s)?$")
+    let re = Regex::new(r"(?i)^((?P<h>\d+)h)?((?P<m>\d+)m)?((?P<s>\d+)s)?((?P<ms>\d+)ms)?$")
         .map_err(|e| AppError::ParseError(e.to_string()))?;
-        
-    if let Some(caps) = re.captures(s) {
-        let minutes = caps
-            .name("m")
-            .and_then(|m| m.as_str().parse().ok())
-            .unwrap_or(0);
-        let seconds = caps
-            .name("s")
-            .and_then(|s| s.as_str().parse().ok())
-            .unwrap_or(0);
-        
-        if minutes == 0 && seconds == 0 {
-            return Err(AppError::ParseError("Duration must be greater than 0".into()));
+
+    let caps = re
+        .captures(s)
+        .ok_or_else(|| AppError::ParseError("Invalid duration format".into()))?;
+
+    // Missing components count as zero; present ones must fit in a u64
+    let component = |name: &str| -> Result<u64> {
+        match caps.name(name) {
+            Some(value) => value
+                .as_str()
+                .parse()
+                .map_err(|_| AppError::ParseError(format!("Duration component '{}' is too large", value.as_str()))),
+            None => Ok(0),
+        }
+    };
+
+    let hours = component("h")?;
+    let minutes = component("m")?;
+    let seconds = component("s")?;
+    let millis = component("ms")?;
+
+    let total_ms = hours
+        .checked_mul(3_600_000)
+        .and_then(|total| minutes.checked_mul(60_000)?.checked_add(total))
+        .and_then(|total| seconds.checked_mul(1_000)?.checked_add(total))
+        .and_then(|total| millis.checked_add(total))
+        .ok_or_else(|| AppError::ParseError("Duration is too large".into()))?;
+
+    if total_ms == 0 {
+        return Err(AppError::ParseError("Duration must be greater than 0".into()));
+    }
+
+    Ok(Duration::from_millis(total_ms))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(s: &str) -> f64 {
+        parse_duration(s).unwrap().as_secs_f64()
+    }
+
+    #[test]
+    fn parses_each_unit_on_its_own() {
+        assert_eq!(secs("2h"), 7200.0);
+        assert_eq!(secs("5m"), 300.0);
+        assert_eq!(secs("45s"), 45.0);
+        assert_eq!(secs("500ms"), 0.5);
+    }
+
+    #[test]
+    fn parses_mixed_units() {
+        assert_eq!(secs("1h30m"), 5400.0);
+        assert_eq!(secs("1m30s"), 90.0);
+        assert_eq!(secs("1h30m45s"), 5445.0);
+        assert_eq!(secs("1h0m0s250ms"), 3600.25);
+    }
+
+    #[test]
+    fn accepts_leading_zeros_and_any_case() {
+        assert_eq!(secs("01h05m"), 3900.0);
+        assert_eq!(secs("007s"), 7.0);
+        assert_eq!(secs("1H30M"), 5400.0);
+        assert_eq!(secs("500MS"), 0.5);
+    }
+
+    #[test]
+    fn rejects_bare_numbers() {
+        assert!(parse_duration("90").is_err());
+        assert!(parse_duration("0").is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        for input in ["", "abc", "-5m", "1.5h", "5 m", "30s1m", "1d", "m", "h30m", "1h 30m"] {
+            assert!(
+                matches!(parse_duration(input), Err(AppError::ParseError(_))),
+                "{:?} should not parse",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_zero_duration() {
+        for input in ["0s", "0h0m", "000ms"] {
+            assert!(matches!(parse_duration(input), Err(AppError::ParseError(_))));
         }
-        
-        Ok(Duration::from_secs(minutes * 60 + seconds))
-    } else {
-        Err(AppError::ParseError("Invalid duration format".into()))
+    }
+
+    #[test]
+    fn rejects_components_that_overflow() {
+        // Too many digits for a u64 at all
+        assert!(matches!(
+            parse_duration("99999999999999999999999s"),
+            Err(AppError::ParseError(_))
+        ));
+        // Fits in a u64 but not once converted to milliseconds
+        assert!(matches!(
+            parse_duration(&format!("{}h", u64::MAX / 1000)),
+            Err(AppError::ParseError(_))
+        ));
+        assert!(matches!(
+            parse_duration(&format!("1s{}ms", u64::MAX)),
+            Err(AppError::ParseError(_))
+        ));
     }
 }
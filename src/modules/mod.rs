@@ -5,3 +5,19 @@ pub mod input;
 pub mod mouse;
 pub mod profiles;
 pub mod image_recognition;
+pub mod preferences;
+pub mod paths;
+pub mod event_log;
+pub mod broadcast;
+pub mod monitors;
+pub mod validation;
+pub mod swap_cell;
+pub mod timing;
+pub mod dialogs;
+pub mod clipboard;
+pub mod toml_format;
+pub mod webhook;
+pub mod i18n;
+pub mod system_info;
+pub mod sound;
+pub mod notifications;
@@ -0,0 +1,92 @@
+use windows::Win32::Foundation::{BOOL, HWND, LPARAM, RECT};
+use windows::Win32::Graphics::Gdi::{EnumDisplayMonitors, GetDC, GetDeviceCaps, GetMonitorInfoW, ReleaseDC, HDC, HMONITOR, HORZRES, MONITORINFO, MONITORINFOEXW, VERTRES};
+use windows::Win32::UI::WindowsAndMessaging::MONITORINFOF_PRIMARY;
+
+/// A display and its position on the virtual desktop
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorInfo {
+    /// Device name such as \\.\DISPLAY1
+    pub name: String,
+    /// Top-left corner in virtual desktop coordinates
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub primary: bool,
+}
+
+impl MonitorInfo {
+    /// Label shown in monitor selectors
+    pub fn display_name(&self, index: u32) -> String {
+        let primary = if self.primary { ", primary" } else { "" };
+        format!("Monitor {} ({}x{}{})", index + 1, self.width, self.height, primary)
+    }
+}
+
+/// Primary screen size as GDI reports it, which is what screenshots are captured at.
+/// A process that isn't DPI aware gets scaled-down sizes from other APIs.
+pub fn gdi_screen_size() -> (i32, i32) {
+    unsafe {
+        let screen_dc = GetDC(HWND(0));
+        if screen_dc.is_invalid() {
+            return (0, 0);
+        }
+        let size = (GetDeviceCaps(screen_dc, HORZRES), GetDeviceCaps(screen_dc, VERTRES));
+        ReleaseDC(HWND(0), screen_dc);
+        size
+    }
+}
+
+/// All connected monitors. The primary monitor is always index 0, the rest follow left to right.
+pub fn list_monitors() -> Vec<MonitorInfo> {
+    let mut monitors: Vec<MonitorInfo> = Vec::new();
+
+    unsafe {
+        let _ = EnumDisplayMonitors(
+            HDC(0),
+            None,
+            Some(collect_monitor),
+            LPARAM(&mut monitors as *mut Vec<MonitorInfo> as isize),
+        );
+    }
+
+    monitors.sort_by_key(|monitor| (!monitor.primary, monitor.x, monitor.y));
+    monitors
+}
+
+/// The monitor at a given index, if it is connected
+pub fn monitor(index: u32) -> Option<MonitorInfo> {
+    list_monitors().into_iter().nth(index as usize)
+}
+
+/// Bounding box of all monitors as (left, top, right, bottom), right and bottom exclusive
+pub fn virtual_desktop_bounds() -> Option<(i32, i32, i32, i32)> {
+    list_monitors().iter().fold(None, |bounds, m| {
+        let (left, top, right, bottom) = bounds.unwrap_or((m.x, m.y, m.x + m.width, m.y + m.height));
+        Some((left.min(m.x), top.min(m.y), right.max(m.x + m.width), bottom.max(m.y + m.height)))
+    })
+}
+
+unsafe extern "system" fn collect_monitor(monitor: HMONITOR, _hdc: HDC, _clip: *mut RECT, data: LPARAM) -> BOOL {
+    let monitors = &mut *(data.0 as *mut Vec<MonitorInfo>);
+
+    let mut info = MONITORINFOEXW::default();
+    info.monitorInfo.cbSize = std::mem::size_of::<MONITORINFOEXW>() as u32;
+
+    if GetMonitorInfoW(monitor, &mut info as *mut MONITORINFOEXW as *mut MONITORINFO).as_bool() {
+        let rect = info.monitorInfo.rcMonitor;
+        let name_len = info.szDevice.iter().position(|&c| c == 0).unwrap_or(info.szDevice.len());
+
+        monitors.push(MonitorInfo {
+            name: String::from_utf16_lossy(&info.szDevice[..name_len]),
+            x: rect.left,
+            y: rect.top,
+            width: rect.right - rect.left,
+            height: rect.bottom - rect.top,
+            primary: info.monitorInfo.dwFlags & MONITORINFOF_PRIMARY != 0,
+        });
+    }
+
+    // Keep enumerating
+    BOOL(1)
+}
@@ -1,4 +1,4 @@
-use enigo::{Enigo, MouseControllable};
+use enigo::{Enigo, Key, KeyboardControllable, MouseControllable};
 use rand::Rng;
 use rand_distr::{Distribution, Normal};
 use std::thread;
@@ -10,11 +10,17 @@ use windows::{
         GetWindowRect,
     },
     Win32::Foundation::RECT,
+    Win32::UI::Input::KeyboardAndMouse::{GetAsyncKeyState, GetDoubleClickTime, VK_LBUTTON, VK_MBUTTON, VK_RBUTTON},
     Win32::System::Console::GetConsoleWindow,
 };
 use crate::modules::error::Result;
 use crate::modules::ui::encode_text;
-use crate::modules::config::{Config, ClickArea, AreaSelectionMode};
+use crate::modules::monitors::{self, MonitorInfo};
+use crate::modules::timing;
+use crate::modules::config::{Config, ClickArea, AntiDetectionConfig, MouseMovementConfig, AreaSelectionMode, ClickModifiers, ClickGap, ClickTiming, MIN_CLICK_GAP_MS, MAX_CLICK_GAP_MS};
+
+/// Moves this long or longer use the largest step size
+const FULL_STEP_DISTANCE_PX: f64 = 2000.0;
 
 pub fn calculate_centered_area(enigo: &Enigo, width: i32, height: i32) -> (i32, i32) {
     let screen_size = enigo.main_display_size();
@@ -25,80 +31,107 @@ pub fn calculate_centered_area(enigo: &Enigo, width: i32, height: i32) -> (i32,
     (start_x, start_y)
 }
 
+/// Top-left corner of an area in virtual desktop coordinates
 pub fn calculate_click_area(enigo: &Enigo, area: &ClickArea) -> (i32, i32) {
+    // Fall back to the primary display if the area's monitor has been disconnected
+    let Some(monitor) = monitors::monitor(area.monitor_index) else {
+        return if area.centered {
+            calculate_centered_area(enigo, area.width, area.height)
+        } else {
+            (area.x_offset, area.y_offset)
+        };
+    };
+
+    area_origin_on(&monitor, area)
+}
+
+/// Top-left corner of an area placed on `monitor`, in virtual desktop coordinates
+pub fn area_origin_on(monitor: &MonitorInfo, area: &ClickArea) -> (i32, i32) {
     if area.centered {
-        calculate_centered_area(enigo, area.width, area.height)
+        (monitor.x + (monitor.width - area.width) / 2, monitor.y + (monitor.height - area.height) / 2)
     } else {
-        (area.x_offset, area.y_offset)
+        (monitor.x + area.x_offset, monitor.y + area.y_offset)
     }
 }
 
+/// Pick the area for the next click and where it starts on screen.
+/// Also returns the area's index in `multi_area.areas`, or `None` when the primary area was used.
 pub fn get_next_click_area(
     config: &Config,
     current_index: &mut usize,
     rng: &mut impl Rng
-) -> (ClickArea, (i32, i32)) {
+) -> (ClickArea, (i32, i32), Option<usize>) {
+    // Disabled areas are skipped in every selection mode
+    let active = config.multi_area.active_areas();
+
     // If multi-area is not enabled, use the primary click area
-    if !config.multi_area.enabled || config.multi_area.areas.is_empty() {
-        return (config.click_area.clone(), calculate_click_area(&Enigo::new(), &config.click_area));
+    if !config.multi_area.enabled || active.is_empty() {
+        return (config.click_area.clone(), calculate_click_area(&Enigo::new(), &config.click_area), None);
     }
 
-    // Get the next area based on the selection mode
-    let (area, _) = match config.multi_area.selection_mode {
+    // Get the next area based on the selection mode, as a position in `active`
+    let position = match config.multi_area.selection_mode {
         AreaSelectionMode::Sequential => {
-            // Get the next area in sequence
-            let area = &config.multi_area.areas[*current_index].0;
+            // Get the next area in sequence; the list may have shrunk since the last click
+            let position = *current_index % active.len();
 
             // Update the index for next time
-            *current_index = (*current_index + 1) % config.multi_area.areas.len();
+            *current_index = (position + 1) % active.len();
 
-            (area.clone(), 1.0)
+            position
         },
         AreaSelectionMode::Random => {
             // Pick a random area
-            let index = rng.gen_range(0..config.multi_area.areas.len());
-            let area = &config.multi_area.areas[index].0;
-
-            (area.clone(), 1.0)
+            rng.gen_range(0..active.len())
         },
         AreaSelectionMode::Weighted => {
             // Calculate total weight
-            let total_weight: f32 = config.multi_area.areas.iter()
-                .map(|(_, weight)| *weight)
+            let total_weight: f32 = active.iter()
+                .map(|(_, _, weight)| weight.max(0.0))
                 .sum();
 
-            // Generate a random value between 0 and total_weight
-            let mut random_value = rng.gen_range(0.0..total_weight);
-
-            // Find the area based on the random value
-            let mut selected_index = 0;
-
-            for (i, (_, weight)) in config.multi_area.areas.iter().enumerate() {
-                if random_value <= *weight {
-                    selected_index = i;
-                    break;
+            if total_weight <= 0.0 {
+                rng.gen_range(0..active.len())
+            } else {
+                // Generate a random value between 0 and total_weight
+                let mut random_value = rng.gen_range(0.0..total_weight);
+
+                // Find the area based on the random value
+                let mut selected = 0;
+
+                for (position, (_, _, weight)) in active.iter().enumerate() {
+                    if random_value <= weight.max(0.0) {
+                        selected = position;
+                        break;
+                    }
+                    random_value -= weight.max(0.0);
                 }
-                random_value -= weight;
-            }
 
-            let (area, weight) = &config.multi_area.areas[selected_index];
-            (area.clone(), *weight)
+                selected
+            }
         },
     };
+    let (index, area, _) = active[position];
+    let area = area.clone();
 
     // Calculate the coordinates for the selected area
     let coords = calculate_click_area(&Enigo::new(), &area);
 
-    (area, coords)
+    (area, coords, Some(index))
 }
 
+/// Random point inside an area whose top-left corner is already offset onto its monitor.
+/// With an `anchor`, `start_x` and `start_y` are offsets from that point instead.
 pub fn generate_random_coordinates(
     start_x: i32,
     start_y: i32,
     width: i32,
     height: i32,
+    anchor: Option<(i32, i32)>,
     rng: &mut impl Rng,
 ) -> (i32, i32) {
+    let (anchor_x, anchor_y) = anchor.unwrap_or((0, 0));
+    let (start_x, start_y) = (anchor_x + start_x, anchor_y + start_y);
     let x = rng.gen_range(start_x..start_x + width);
     let y = rng.gen_range(start_y..start_y + height);
     (x, y)
@@ -126,19 +159,28 @@ pub fn safe_move_mouse(x: i32, y: i32) -> bool {
     }
 }
 
+/// Step length for a move of the given distance, growing linearly from the minimum to the maximum
+fn adaptive_step_px(distance: f64, movement: &MouseMovementConfig) -> f64 {
+    let min_step = movement.min_step_px.max(1.0) as f64;
+    let max_step = (movement.max_step_px as f64).max(min_step);
+    let scale = (distance / FULL_STEP_DISTANCE_PX).min(1.0);
+    min_step + (max_step - min_step) * scale
+}
+
 pub fn simulate_human_movement(
    enigo: &mut Enigo,
    target_x: i32,
    target_y: i32,
+   movement: &MouseMovementConfig,
+   dry_run: bool,
    _rng: &mut impl Rng,
 ) -> Result<()> {
-   let screen_size = enigo.main_display_size();
-   let max_x = screen_size.0 as i32;
-   let max_y = screen_size.1 as i32;
+   // Clamp to the whole virtual desktop so areas on secondary monitors stay reachable
+   let (min_x, min_y, max_x, max_y) = desktop_bounds(enigo);
 
    // Clamp target coordinates
-   let target_x = target_x.clamp(0, max_x - 1);
-   let target_y = target_y.clamp(0, max_y - 1);
+   let target_x = target_x.clamp(min_x, max_x - 1);
+   let target_y = target_y.clamp(min_y, max_y - 1);
 
    let start_pos = enigo.mouse_location();
    let dx = target_x - start_pos.0;
@@ -150,8 +192,11 @@ pub fn simulate_human_movement(
    }
 
    let distance = ((dx * dx + dy * dy) as f64).sqrt();
-   let steps = (distance / 10.0).ceil() as usize;
-   let step_time = Duration::from_millis(2);
+   let steps = (distance / adaptive_step_px(distance, movement)).ceil().max(1.0) as usize;
+
+   // Shorten each step's pause when the whole move would otherwise run past the cap
+   let max_step_time = Duration::from_millis(movement.max_move_time_ms) / steps as u32;
+   let step_time = Duration::from_millis(movement.step_time_ms).min(max_step_time);
 
    for i in 1..=steps {
        let progress = i as f64 / steps as f64;
@@ -161,11 +206,11 @@ pub fn simulate_human_movement(
        let y = (start_pos.1 as f64 + dy as f64 * progress) as i32;
 
        // Clamp coordinates
-       let x = x.clamp(0, max_x - 1);
-       let y = y.clamp(0, max_y - 1);
+       let x = x.clamp(min_x, max_x - 1);
+       let y = y.clamp(min_y, max_y - 1);
 
-       // Use Windows API directly
-       if !safe_move_mouse(x, y) {
+       // Use Windows API directly. A dry run keeps the timing but leaves the cursor where it is.
+       if !dry_run && !safe_move_mouse(x, y) {
             let error_msg = format!("Failed to move mouse to ({}, {})", x, y);
             eprintln!("{} | {}", encode_text(&error_msg), error_msg);
             continue;
@@ -177,17 +222,28 @@ pub fn simulate_human_movement(
    Ok(())
 }
 
-pub fn simulate_idle_movement(enigo: &mut Enigo, rng: &mut impl Rng) -> Result<()> {
-    // Reduce the frequency of idle movements significantly
-    if rng.gen_bool(0.001) {
+/// The virtual desktop as (left, top, right, bottom), or the primary display if the monitors can't be listed
+fn desktop_bounds(enigo: &Enigo) -> (i32, i32, i32, i32) {
+    monitors::virtual_desktop_bounds().unwrap_or_else(|| {
         let screen_size = enigo.main_display_size();
+        (0, 0, screen_size.0, screen_size.1)
+    })
+}
+
+/// Move `pos` by `offset` without leaving `bounds`, given as (left, top, right, bottom)
+fn nudged_position(pos: (i32, i32), offset: (i32, i32), (min_x, min_y, max_x, max_y): (i32, i32, i32, i32)) -> (i32, i32) {
+    ((pos.0 + offset.0).clamp(min_x, max_x - 1), (pos.1 + offset.1).clamp(min_y, max_y - 1))
+}
+
+// Occasionally nudge the cursor by a pixel. Nothing moves in a dry run.
+pub fn simulate_idle_movement(enigo: &mut Enigo, dry_run: bool, rng: &mut impl Rng) -> Result<()> {
+    // Reduce the frequency of idle movements significantly
+    if !dry_run && rng.gen_bool(0.001) {
         let current_pos = enigo.mouse_location();
 
-        // Ensure new position is within screen bounds
-        let new_x = (current_pos.0 + rng.gen_range(-1..=1))
-            .clamp(0, screen_size.0 as i32 - 1);
-        let new_y = (current_pos.1 + rng.gen_range(-1..=1))
-            .clamp(0, screen_size.1 as i32 - 1);
+        // Stay on the virtual desktop, so a cursor on a secondary monitor isn't pulled onto the primary one
+        let offset = (rng.gen_range(-1..=1), rng.gen_range(-1..=1));
+        let (new_x, new_y) = nudged_position(current_pos, offset, desktop_bounds(enigo));
 
         // Only move if the position has actually changed
         if new_x != current_pos.0 || new_y != current_pos.1 {
@@ -211,6 +267,10 @@ pub fn get_click_type(rng: &mut impl Rng, config: &Config) -> crate::modules::co
         .map(|(_, weight)| weight)
         .sum();
 
+    if total_weight <= 0.0 {
+        return config.click_options.click_type;
+    }
+
     // Generate a random value between 0 and total_weight
     let mut random_value = rng.gen_range(0.0..total_weight);
 
@@ -226,50 +286,179 @@ pub fn get_click_type(rng: &mut impl Rng, config: &Config) -> crate::modules::co
     ClickType::Single
 }
 
-pub fn human_like_click(enigo: &mut Enigo, rng: &mut impl Rng, config: &Config) -> Result<()> {
-    let normal = Normal::new(
-        config.click_timing.click_duration_mean,
-        config.click_timing.click_duration_std_dev
-    ).unwrap();
+pub fn get_click_modifiers(rng: &mut impl Rng, config: &Config) -> ClickModifiers {
+    if !config.click_options.randomize_click_type {
+        return config.click_options.modifiers;
+    }
+
+    let total_weight: f32 = config.click_options.modifier_weights.iter()
+        .map(|(_, weight)| weight)
+        .sum();
+
+    if total_weight <= 0.0 {
+        return ClickModifiers::default();
+    }
+
+    let mut random_value = rng.gen_range(0.0..total_weight);
+
+    for (modifiers, weight) in &config.click_options.modifier_weights {
+        if random_value <= *weight {
+            return *modifiers;
+        }
+        random_value -= weight;
+    }
+
+    ClickModifiers::default()
+}
+
+/// Modifier keys held down for the duration of a click.
+/// The keys are released when this is dropped, so an aborted click never leaves them stuck.
+struct HeldModifiers<'a> {
+    enigo: &'a mut Enigo,
+    keys: Vec<Key>,
+}
+
+impl<'a> HeldModifiers<'a> {
+    fn press(enigo: &'a mut Enigo, modifiers: ClickModifiers, rng: &mut impl Rng) -> Self {
+        let mut keys = Vec::new();
+        if modifiers.ctrl { keys.push(Key::Control); }
+        if modifiers.shift { keys.push(Key::Shift); }
+        if modifiers.alt { keys.push(Key::Alt); }
+
+        for key in &keys {
+            enigo.key_down(*key);
+        }
+
+        // Give the target application a moment to register the modifiers, like a human would
+        if !keys.is_empty() {
+            thread::sleep(Duration::from_millis(rng.gen_range(20..60)));
+        }
+
+        Self { enigo, keys }
+    }
+}
+
+impl Drop for HeldModifiers<'_> {
+    fn drop(&mut self) {
+        for key in self.keys.iter().rev() {
+            self.enigo.key_up(*key);
+        }
+    }
+}
+
+// Sample the pause between presses of a double or triple click
+pub fn sample_click_gap(rng: &mut impl Rng, gap: &ClickGap) -> Duration {
+    let gap_ms = match Normal::new(gap.mean, gap.std_dev.max(0.0)) {
+        Ok(normal) => normal.sample(rng),
+        Err(_) => gap.mean,
+    };
+
+    Duration::from_millis(gap_ms.clamp(MIN_CLICK_GAP_MS, MAX_CLICK_GAP_MS) as u64)
+}
+
+// The Windows double-click speed: the longest gap between presses that still counts as a double click
+pub fn system_double_click_ms() -> u32 {
+    unsafe { GetDoubleClickTime() }
+}
+
+// The gap distribution for multi-clicks, read from the system setting each time if the profile asks for it.
+// The system value is an upper limit, so presses aim for half of it to be counted reliably.
+pub fn double_click_gap(timing: &ClickTiming) -> ClickGap {
+    if !timing.use_system_double_click_time {
+        return timing.double_click_gap;
+    }
+
+    let system_ms = system_double_click_ms() as f64;
+    ClickGap {
+        mean: system_ms * 0.5,
+        std_dev: system_ms * 0.1,
+    }
+}
+
+// Whether a mouse button is currently held, by the user or by a press that was never released
+pub fn get_mouse_button_state(button: enigo::MouseButton) -> bool {
+    let key = match button {
+        enigo::MouseButton::Right => VK_RBUTTON,
+        enigo::MouseButton::Middle => VK_MBUTTON,
+        _ => VK_LBUTTON,
+    };
+
+    // The most significant bit is set while the key is down
+    unsafe { GetAsyncKeyState(key.0 as i32) < 0 }
+}
+
+// Click with a human-like hold time. Returns `None` without clicking if the button is already held,
+// since pressing it again would leave it stuck down after the first release.
+pub fn human_like_click(enigo: &mut Enigo, rng: &mut impl Rng, config: &Config) -> Result<Option<crate::modules::config::ClickType>> {
+    let click_type = get_click_type(rng, config);
+
+    let button = match click_type {
+        crate::modules::config::ClickType::Right => enigo::MouseButton::Right,
+        crate::modules::config::ClickType::Middle => enigo::MouseButton::Middle,
+        _ => enigo::MouseButton::Left,
+    };
+
+    // A dry run sends nothing, so a held button can't get stuck
+    let dry_run = config.dry_run;
+    if !dry_run && get_mouse_button_state(button) {
+        eprintln!("Warning: {:?} button is already pressed, skipping click", button);
+        return Ok(None);
+    }
+
+    let (mean, std_dev) = config.click_timing.click_duration_for(click_type);
+    let normal = Normal::new(mean, std_dev).unwrap();
 
     let click_duration = normal.sample(rng) as f64;
     let clamped_duration = click_duration.clamp(40.0, 150.0) as u64;
 
-    let click_type = get_click_type(rng, config);
+    let modifiers = get_click_modifiers(rng, config);
 
-    match click_type {
-        crate::modules::config::ClickType::Single => {
-            enigo.mouse_down(enigo::MouseButton::Left);
-            thread::sleep(Duration::from_millis(clamped_duration));
-            enigo.mouse_up(enigo::MouseButton::Left);
-        },
-        crate::modules::config::ClickType::Double => {
-            // First click
-            enigo.mouse_down(enigo::MouseButton::Left);
-            thread::sleep(Duration::from_millis(clamped_duration));
-            enigo.mouse_up(enigo::MouseButton::Left);
-
-            // Gap between clicks
-            thread::sleep(Duration::from_millis(config.click_timing.double_click_gap));
-
-            // Second click
-            enigo.mouse_down(enigo::MouseButton::Left);
-            thread::sleep(Duration::from_millis(clamped_duration));
-            enigo.mouse_up(enigo::MouseButton::Left);
-        },
-        crate::modules::config::ClickType::Right => {
-            enigo.mouse_down(enigo::MouseButton::Right);
-            thread::sleep(Duration::from_millis(clamped_duration));
-            enigo.mouse_up(enigo::MouseButton::Right);
-        },
-        crate::modules::config::ClickType::Middle => {
-            enigo.mouse_down(enigo::MouseButton::Middle);
-            thread::sleep(Duration::from_millis(clamped_duration));
-            enigo.mouse_up(enigo::MouseButton::Middle);
-        },
+    let held = HeldModifiers::press(enigo, if dry_run { ClickModifiers::default() } else { modifiers }, rng);
+    let enigo = &mut *held.enigo;
+    let gap = double_click_gap(&config.click_timing);
+
+    for press in 0..click_type.press_count() {
+        // Each gap in a multi-click is sampled separately so no two are identical
+        if press > 0 {
+            thread::sleep(sample_click_gap(rng, &gap));
+        }
+
+        if !dry_run {
+            enigo.mouse_down(button);
+        }
+        thread::sleep(Duration::from_millis(clamped_duration));
+        if !dry_run {
+            enigo.mouse_up(button);
+        }
     }
 
-    Ok(())
+    if !modifiers.is_empty() {
+        thread::sleep(Duration::from_millis(rng.gen_range(20..60)));
+    }
+    drop(held);
+
+    Ok(Some(click_type))
+}
+
+// Sample the delay before the next click, minus the calibrated time a click itself takes
+pub fn random_sleep_duration(rng: &mut impl Rng, config: &Config) -> Duration {
+    let delay = Duration::from_secs_f32(
+        rng.gen_range(config.click_timing.min_delay..config.click_timing.max_delay)
+    );
+    let compensation = Duration::from_secs_f64(config.click_timing.latency_compensation_ms.max(0.0) / 1000.0);
+
+    delay.saturating_sub(compensation)
+}
+
+// Sample how long to wait after the cursor arrives before pressing, if reaction delays are on
+pub fn reaction_delay(rng: &mut impl Rng, config: &Config) -> Option<Duration> {
+    let reaction = &config.click_timing.reaction_delay;
+    if !reaction.enabled {
+        return None;
+    }
+
+    let max_ms = reaction.max_ms.max(reaction.min_ms);
+    Some(Duration::from_millis(rng.gen_range(reaction.min_ms..=max_ms)))
 }
 
 pub fn handle_sleep_period(
@@ -277,21 +466,150 @@ pub fn handle_sleep_period(
     rng: &mut impl Rng,
     is_paused: &AtomicBool,
     should_quit: &AtomicBool,
-    config: &Config,
+    sleep_duration: Duration,
+    anti_detection: &AntiDetectionConfig,
+    dry_run: bool,
 ) -> Result<()> {
-    let sleep_duration = Duration::from_secs_f32(
-        rng.gen_range(config.click_timing.min_delay..config.click_timing.max_delay)
-    );
     let sleep_start = Instant::now();
 
     while sleep_start.elapsed() < sleep_duration
         && !is_paused.load(Ordering::SeqCst)
         && !should_quit.load(Ordering::SeqCst)
     {
-        thread::sleep(Duration::from_millis(100));
+        timing::poll_sleep(100, anti_detection);
 
         // Ignore any errors from idle movement
-        let _ = simulate_idle_movement(enigo, rng);
+        let _ = simulate_idle_movement(enigo, dry_run, rng);
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::config::{ClickTimingBuilder, ReactionDelayConfig};
+
+    fn monitor(x: i32, y: i32, width: i32, height: i32) -> MonitorInfo {
+        MonitorInfo {
+            name: String::new(),
+            x,
+            y,
+            width,
+            height,
+            primary: x == 0 && y == 0,
+        }
+    }
+
+    fn area(width: i32, height: i32, centered: bool, x_offset: i32, y_offset: i32) -> ClickArea {
+        ClickArea { width, height, centered, x_offset, y_offset, ..Default::default() }
+    }
+
+    #[test]
+    fn areas_on_the_primary_monitor_are_unshifted() {
+        let primary = monitor(0, 0, 1920, 1080);
+        assert_eq!(area_origin_on(&primary, &area(200, 100, true, 0, 0)), (860, 490));
+        assert_eq!(area_origin_on(&primary, &area(200, 100, false, 30, 40)), (30, 40));
+    }
+
+    #[test]
+    fn areas_on_a_secondary_monitor_are_offset_by_its_position() {
+        let secondary = monitor(1920, 0, 2560, 1440);
+        assert_eq!(area_origin_on(&secondary, &area(200, 100, true, 0, 0)), (1920 + 1180, 670));
+        assert_eq!(area_origin_on(&secondary, &area(200, 100, false, 30, 40)), (1950, 40));
+    }
+
+    #[test]
+    fn areas_on_a_monitor_left_of_and_above_the_primary_have_negative_coordinates() {
+        let left = monitor(-1280, -200, 1280, 1024);
+        assert_eq!(area_origin_on(&left, &area(200, 100, true, 0, 0)), (-1280 + 540, -200 + 462));
+        assert_eq!(area_origin_on(&left, &area(200, 100, false, 30, 40)), (-1250, -160));
+    }
+
+    #[test]
+    fn idle_nudges_stay_on_the_monitor_the_cursor_is_on() {
+        // A secondary monitor left of the primary and another to its right
+        let bounds = (-1280, 0, 1920 + 2560, 1440);
+        assert_eq!(nudged_position((-1000, 500), (-1, 1), bounds), (-1001, 501));
+        assert_eq!(nudged_position((3000, 1200), (1, -1), bounds), (3001, 1199));
+
+        // Only the edges of the whole desktop hold the cursor back
+        assert_eq!(nudged_position((-1280, 0), (-1, -1), bounds), (-1280, 0));
+        assert_eq!(nudged_position((4479, 1439), (1, 1), bounds), (4479, 1439));
+    }
+
+    fn config_with(click_timing: ClickTiming) -> Config {
+        Config { click_timing, ..Default::default() }
+    }
+
+    fn seeded_rng() -> rand::rngs::StdRng {
+        rand::SeedableRng::seed_from_u64(42)
+    }
+
+    #[test]
+    fn sleep_duration_stays_between_the_delays() {
+        let config = config_with(ClickTimingBuilder::default().min_delay(1.0).max_delay(1.5).build());
+        let mut rng = seeded_rng();
+        for _ in 0..100 {
+            let delay = random_sleep_duration(&mut rng, &config);
+            assert!(delay >= Duration::from_secs_f32(1.0) && delay < Duration::from_secs_f32(1.5), "{:?}", delay);
+        }
+    }
+
+    #[test]
+    fn sleep_duration_takes_off_the_latency_compensation() {
+        let config = config_with(
+            ClickTimingBuilder::default().min_delay(1.0).max_delay(1.001).latency_compensation_ms(250.0).build(),
+        );
+        let delay = random_sleep_duration(&mut seeded_rng(), &config);
+        assert!(delay >= Duration::from_millis(750) && delay < Duration::from_millis(752), "{:?}", delay);
+
+        // Never below zero, however large the compensation
+        let config = config_with(
+            ClickTimingBuilder::default().min_delay(0.1).max_delay(0.2).latency_compensation_ms(5000.0).build(),
+        );
+        assert_eq!(random_sleep_duration(&mut seeded_rng(), &config), Duration::ZERO);
+    }
+
+    #[test]
+    fn reaction_delay_is_only_sampled_when_enabled() {
+        assert_eq!(reaction_delay(&mut seeded_rng(), &Config::default()), None);
+
+        let reaction = ReactionDelayConfig { enabled: true, min_ms: 100, max_ms: 120 };
+        let config = config_with(ClickTimingBuilder::default().reaction_delay(reaction).build());
+        let mut rng = seeded_rng();
+        for _ in 0..50 {
+            let delay = reaction_delay(&mut rng, &config).unwrap();
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(120), "{:?}", delay);
+        }
+
+        // A maximum below the minimum collapses to the minimum
+        let reaction = ReactionDelayConfig { enabled: true, min_ms: 90, max_ms: 10 };
+        let config = config_with(ClickTimingBuilder::default().reaction_delay(reaction).build());
+        assert_eq!(reaction_delay(&mut seeded_rng(), &config), Some(Duration::from_millis(90)));
+    }
+
+    #[test]
+    fn click_gap_comes_from_the_profile_unless_the_system_setting_is_used() {
+        let gap = ClickGap { mean: 150.0, std_dev: 0.0 };
+        let timing = ClickTimingBuilder::default().double_click_gap(gap).build();
+
+        assert_eq!(double_click_gap(&timing), gap);
+        assert_eq!(sample_click_gap(&mut seeded_rng(), &gap), Duration::from_millis(150));
+    }
+
+    #[test]
+    fn sampled_click_gaps_are_clamped_to_the_allowed_range() {
+        let mut rng = seeded_rng();
+        let too_short = ClickGap { mean: 0.0, std_dev: 0.0 };
+        let too_long = ClickGap { mean: 10_000.0, std_dev: 0.0 };
+
+        assert_eq!(sample_click_gap(&mut rng, &too_short), Duration::from_millis(MIN_CLICK_GAP_MS as u64));
+        assert_eq!(sample_click_gap(&mut rng, &too_long), Duration::from_millis(MAX_CLICK_GAP_MS as u64));
+    }
+
+    #[test]
+    fn areas_larger_than_the_monitor_are_centered_past_its_edges() {
+        let small = monitor(0, 0, 800, 600);
+        assert_eq!(area_origin_on(&small, &area(1000, 700, true, 0, 0)), (-100, -50));
+    }
+}
@@ -0,0 +1,32 @@
+/// Whether this build can show desktop notifications at all
+pub const AVAILABLE: bool = cfg!(feature = "desktop-notifications");
+
+/// Title every notification is shown under
+#[cfg(feature = "desktop-notifications")]
+const APP_NAME: &str = "Mouse Clicker";
+
+/// The app icon shipped in `assets` next to the executable, if it's there
+#[cfg(feature = "desktop-notifications")]
+fn icon_path() -> Option<std::path::PathBuf> {
+    let path = std::env::current_exe().ok()?.parent()?.join("assets").join("icon.png");
+    path.exists().then_some(path)
+}
+
+/// Show a desktop notification without blocking the caller. Does nothing in builds
+/// without the `desktop-notifications` feature.
+#[cfg(feature = "desktop-notifications")]
+pub fn show(body: String) {
+    std::thread::spawn(move || {
+        let mut notification = notify_rust::Notification::new();
+        notification.summary(APP_NAME).body(&body);
+        if let Some(icon) = icon_path() {
+            notification.icon(&icon.to_string_lossy());
+        }
+        if let Err(e) = notification.show() {
+            eprintln!("Failed to show notification: {}", e);
+        }
+    });
+}
+
+#[cfg(not(feature = "desktop-notifications"))]
+pub fn show(_body: String) {}
@@ -0,0 +1,207 @@
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+#[cfg(windows)]
+use windows::core::HSTRING;
+#[cfg(windows)]
+use windows::Win32::Storage::FileSystem::{MoveFileExW, MOVEFILE_REPLACE_EXISTING, MOVEFILE_WRITE_THROUGH};
+
+/// Folder created under the per-user application data directory
+const APP_DIR_NAME: &str = "MouseClicker";
+
+/// Marker file next to the executable that turns on portable mode
+const PORTABLE_MARKER: &str = "portable.txt";
+
+/// Command line flag that turns on portable mode
+pub const PORTABLE_FLAG: &str = "--portable";
+
+/// Written to the data directory once legacy folders have been migrated
+const MIGRATION_MARKER: &str = ".migrated";
+
+/// Entries that older versions created in the working directory
+const LEGACY_ENTRIES: [&str; 4] = ["profiles", "targets", "logs", "app_prefs.json"];
+
+/// The resolved data location for this run
+#[derive(Debug, Clone)]
+pub struct DataLocation {
+    pub dir: PathBuf,
+    pub portable: bool,
+}
+
+static DATA_LOCATION: OnceLock<DataLocation> = OnceLock::new();
+
+/// Resolve the data directory for this run. Must be called before anything touches the disk.
+pub fn init(portable_flag: bool) -> &'static DataLocation {
+    DATA_LOCATION.get_or_init(|| resolve(portable_flag))
+}
+
+/// The resolved data location, resolving it from the defaults if `init` wasn't called
+pub fn location() -> &'static DataLocation {
+    init(false)
+}
+
+/// Base directory that every file the app writes lives under
+pub fn data_dir() -> &'static Path {
+    &location().dir
+}
+
+pub fn profiles_dir() -> PathBuf {
+    data_dir().join("profiles")
+}
+
+pub fn targets_dir() -> PathBuf {
+    data_dir().join("targets")
+}
+
+pub fn logs_dir() -> PathBuf {
+    data_dir().join("logs")
+}
+
+pub fn preferences_file() -> PathBuf {
+    data_dir().join("app_prefs.json")
+}
+
+/// Open the data directory in the system file browser
+pub fn open_data_dir() -> io::Result<()> {
+    let opener = if cfg!(windows) { "explorer" } else { "xdg-open" };
+    std::process::Command::new(opener).arg(data_dir()).spawn()?;
+    Ok(())
+}
+
+/// Open the folder containing `path` in the system file browser, with the file selected where supported
+pub fn reveal_in_file_browser(path: &Path) -> io::Result<()> {
+    if cfg!(windows) {
+        std::process::Command::new("explorer").arg("/select,").arg(path).spawn()?;
+    } else {
+        let folder = path.parent().unwrap_or(path);
+        std::process::Command::new("xdg-open").arg(folder).spawn()?;
+    }
+    Ok(())
+}
+
+/// Write `contents` to `path` so that readers see either the old file or the new one, never
+/// part of it. The data goes to `<file name>.tmp` next to it first, which then replaces the file.
+pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let file_name = path.file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("{:?} is not a file path", path)))?;
+    let mut temp_name = file_name.to_os_string();
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+
+    let result = (|| {
+        let mut file = fs::File::create(&temp_path)?;
+        file.write_all(contents.as_ref())?;
+        file.sync_all()?;
+        drop(file);
+        replace_file(&temp_path, path)
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result
+}
+
+/// Move `source` over `destination`, replacing it if it exists. `fs::rename` doesn't promise to
+/// replace an existing file on Windows, so this asks for it explicitly there.
+#[cfg(windows)]
+fn replace_file(source: &Path, destination: &Path) -> io::Result<()> {
+    unsafe {
+        MoveFileExW(
+            &HSTRING::from(source),
+            &HSTRING::from(destination),
+            MOVEFILE_REPLACE_EXISTING | MOVEFILE_WRITE_THROUGH,
+        )
+    }.map_err(|e| io::Error::other(e.message().to_string()))
+}
+
+#[cfg(not(windows))]
+fn replace_file(source: &Path, destination: &Path) -> io::Result<()> {
+    fs::rename(source, destination)
+}
+
+fn resolve(portable_flag: bool) -> DataLocation {
+    let exe_dir = env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf));
+
+    let portable = portable_flag
+        || exe_dir.as_ref().is_some_and(|dir| dir.join(PORTABLE_MARKER).exists());
+
+    let dir = match (&exe_dir, portable) {
+        (Some(exe_dir), true) => exe_dir.clone(),
+        _ => user_data_dir().unwrap_or_else(|| PathBuf::from(".")),
+    };
+
+    if let Err(e) = fs::create_dir_all(&dir) {
+        eprintln!("Failed to create data directory {:?}: {}", dir, e);
+    }
+
+    migrate_legacy_data(&dir);
+
+    DataLocation { dir, portable }
+}
+
+/// Per-user location such as %APPDATA%/MouseClicker
+fn user_data_dir() -> Option<PathBuf> {
+    let base = env::var_os("APPDATA")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local").join("share")))?;
+
+    Some(base.join(APP_DIR_NAME))
+}
+
+/// Copy folders that older versions left in the working directory into the data directory, once
+fn migrate_legacy_data(data_dir: &Path) {
+    let marker = data_dir.join(MIGRATION_MARKER);
+    if marker.exists() {
+        return;
+    }
+
+    let Ok(working_dir) = env::current_dir() else { return };
+    if same_dir(&working_dir, data_dir) {
+        let _ = fs::write(&marker, "");
+        return;
+    }
+
+    for entry in LEGACY_ENTRIES {
+        let source = working_dir.join(entry);
+        let destination = data_dir.join(entry);
+        if !source.exists() || destination.exists() {
+            continue;
+        }
+
+        println!("Migrating {:?} to {:?}", source, destination);
+        if let Err(e) = copy_recursive(&source, &destination) {
+            eprintln!("Failed to migrate {:?}: {}", source, e);
+            // Leave the marker unwritten so the next launch tries again
+            return;
+        }
+    }
+
+    if let Err(e) = fs::write(&marker, "") {
+        eprintln!("Failed to write migration marker: {}", e);
+    }
+}
+
+fn same_dir(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+fn copy_recursive(source: &Path, destination: &Path) -> io::Result<()> {
+    if source.is_dir() {
+        fs::create_dir_all(destination)?;
+        for entry in fs::read_dir(source)? {
+            let entry = entry?;
+            copy_recursive(&entry.path(), &destination.join(entry.file_name()))?;
+        }
+    } else {
+        fs::copy(source, destination)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::Path;
+use crate::gui::theme::AppTheme;
+use crate::modules::error::{AppError, Result};
+use crate::modules::event_log::EventKind;
+use crate::modules::config::ValidationWarning;
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub enum HudCorner {
+    TopLeft,
+    #[default]
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HudPreferences {
+    pub enabled: bool,
+    pub corner: HudCorner,
+    pub opacity: f32,
+    pub hide_when_stopped: bool,
+    pub position: Option<(f32, f32)>,  // Set once the HUD has been dragged, overrides the corner
+}
+
+impl Default for HudPreferences {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            corner: HudCorner::default(),
+            opacity: 0.85,
+            hide_when_stopped: true,
+            position: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct SoundConfig {
+    #[serde(default)]
+    pub on_stop: bool,
+    #[serde(default)]
+    pub on_error: bool,
+    #[serde(default)]
+    pub on_target_found: bool,
+}
+
+// Scheduling priority the clicker thread runs at
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub enum ThreadPriority {
+    #[default]
+    Normal,
+    AboveNormal,
+    High,
+    Realtime,
+}
+
+impl ThreadPriority {
+    pub const ALL: [ThreadPriority; 4] = [
+        ThreadPriority::Normal,
+        ThreadPriority::AboveNormal,
+        ThreadPriority::High,
+        ThreadPriority::Realtime,
+    ];
+
+    pub fn warning(self) -> Option<ValidationWarning> {
+        (self == ThreadPriority::Realtime).then_some(ValidationWarning::RealtimePriorityRisk)
+    }
+}
+
+// Where session events are POSTed as JSON, and which of them
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WebhookConfig {
+    pub enabled: bool,
+    pub url: String,
+    pub on_events: Vec<EventKind>,
+    #[serde(default)]
+    pub auth_header: Option<String>,  // Sent as the Authorization header, e.g. "Bearer <token>"
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            url: String::new(),
+            on_events: vec![EventKind::Started, EventKind::Stopped, EventKind::Error],
+            auth_header: None,
+        }
+    }
+}
+
+// Most profiles kept in the recent list
+pub const MAX_RECENT_PROFILES: usize = 10;
+
+// Application-wide settings that aren't part of a profile
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct AppPreferences {
+    #[serde(default)]
+    pub hud: HudPreferences,
+    #[serde(default)]
+    pub click_event_log: bool,  // Write every click to a session log under logs/
+    #[serde(default)]
+    pub custom_theme: Option<AppTheme>,  // Full color scheme, used instead of the built-in dark and light themes
+    #[serde(default)]
+    pub overlay_enabled: bool,  // Always-on-top session overlay, shown whether or not the main window is minimized
+    #[serde(default)]
+    pub overlay_position: Option<(f32, f32)>,  // Set once the overlay has been dragged
+    #[serde(default)]
+    pub onboarding_complete: bool,  // Set once the first-run tutorial is finished or skipped
+    #[serde(default)]
+    pub onboarding_step: usize,  // Tutorial step to resume from
+    #[serde(default)]
+    pub color_overrides: HashMap<String, [u8; 3]>,  // Individual theme colors picked under Appearance, applied over the theme
+    #[serde(default)]
+    pub language: String,  // UI language code, e.g. "de"; empty means English
+    #[serde(default)]
+    pub sound_alerts: SoundConfig,  // System sounds played when a session stops, fails or finds a target
+    #[serde(default)]
+    pub webhook: WebhookConfig,
+    #[serde(default)]
+    pub thread_priority: ThreadPriority,  // Raised for tighter click timing at the cost of other programs
+    #[serde(default)]
+    pub desktop_notifications: bool,  // OS notification when a session ends on its own, and on errors if on_error is set
+    #[serde(default)]
+    pub recent_profiles: VecDeque<String>,  // Names of recently loaded profiles, most recent first
+}
+
+impl AppPreferences {
+    // Move a loaded profile to the front of the recent list
+    pub fn note_recent_profile(&mut self, profile_name: &str) {
+        self.forget_recent_profile(profile_name);
+        self.recent_profiles.push_front(profile_name.to_string());
+        self.recent_profiles.truncate(MAX_RECENT_PROFILES);
+    }
+
+    // Drop a renamed or deleted profile from the recent list
+    pub fn forget_recent_profile(&mut self, profile_name: &str) {
+        self.recent_profiles.retain(|name| name != profile_name);
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| AppError::ParseError(format!("Failed to serialize preferences: {}", e)))?;
+
+        fs::write(path, json)?;
+
+        Ok(())
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(AppPreferences::default());
+        }
+
+        let json = fs::read_to_string(path)?;
+
+        let preferences = serde_json::from_str(&json)
+            .map_err(|e| AppError::ParseError(format!("Failed to deserialize preferences: {}", e)))?;
+
+        Ok(preferences)
+    }
+}
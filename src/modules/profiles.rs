@@ -1,15 +1,82 @@
 use std::fs;
-use std::path::PathBuf;
-use crate::modules::config::Config;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+use chrono::{DateTime, Utc};
+use crate::modules::config::{ClickArea, Config};
 use crate::modules::error::{AppError, Result};
+use crate::modules::toml_format;
+use crate::modules::validation::ensure_valid_name;
+
+/// File format a profile is stored in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileFormat {
+    Json,
+    Toml,
+}
+
+impl ProfileFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ProfileFormat::Json => "json",
+            ProfileFormat::Toml => "toml",
+        }
+    }
+
+    fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" => Some(ProfileFormat::Json),
+            "toml" => Some(ProfileFormat::Toml),
+            _ => None,
+        }
+    }
+}
+
+/// Details of the file a profile is stored in
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileMetadata {
+    pub file_size_bytes: u64,
+    pub last_modified: DateTime<Utc>,
+    pub path: PathBuf,
+}
+
+/// What the profile list shows about a profile, read without loading the whole config
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileSummary {
+    pub name: String,
+    pub click_area: ClickArea,
+    /// The fixed click type, e.g. "Double", or "Random (weighted)"
+    pub click_type_info: String,
+    /// Extra click areas, counted whether or not multi-area mode is on
+    pub area_count: usize,
+    pub modified: Option<DateTime<Utc>>,
+}
+
+impl ProfileSummary {
+    /// Pick out the summarized fields from a profile parsed as plain JSON. Fields that are
+    /// missing or malformed fall back to their defaults rather than failing the whole summary.
+    fn from_value(name: String, profile: &serde_json::Value, modified: Option<DateTime<Utc>>) -> Self {
+        let click_area = serde_json::from_value(profile["click_area"].clone()).unwrap_or_default();
+
+        let click_options = &profile["click_options"];
+        let click_type_info = if click_options["randomize_click_type"].as_bool().unwrap_or(false) {
+            "Random (weighted)".to_string()
+        } else {
+            click_options["click_type"].as_str().unwrap_or("Single").to_string()
+        };
+
+        let area_count = profile["multi_area"]["areas"].as_array().map_or(0, Vec::len);
+
+        Self { name, click_area, click_type_info, area_count, modified }
+    }
+}
 
 pub struct ProfileManager {
     profiles_dir: PathBuf,
 }
 
 impl ProfileManager {
-    pub fn new(profiles_dir: &str) -> Self {
-        let dir = PathBuf::from(profiles_dir);
+    pub fn new(profiles_dir: impl AsRef<Path>) -> Self {
+        let dir = profiles_dir.as_ref().to_path_buf();
         if !dir.exists() {
             fs::create_dir_all(&dir).expect("Failed to create profiles directory");
         }
@@ -17,53 +84,203 @@ impl ProfileManager {
     }
 
     pub fn get_profile_path(&self, profile_name: &str) -> PathBuf {
-        self.profiles_dir.join(format!("{}.json", profile_name))
+        self.profile_path_as(profile_name, ProfileFormat::Json)
+    }
+
+    pub fn get_profile_path_toml(&self, profile_name: &str) -> PathBuf {
+        self.profile_path_as(profile_name, ProfileFormat::Toml)
+    }
+
+    fn profile_path_as(&self, profile_name: &str, format: ProfileFormat) -> PathBuf {
+        self.profiles_dir.join(format!("{}.{}", profile_name, format.extension()))
+    }
+
+    // The file a profile is stored in, whichever format it uses. JSON wins if both exist.
+    fn existing_profile_path(&self, profile_name: &str) -> Option<PathBuf> {
+        [self.get_profile_path(profile_name), self.get_profile_path_toml(profile_name)]
+            .into_iter()
+            .find(|path| path.exists())
+    }
+
+    pub fn profile_exists(&self, profile_name: &str) -> bool {
+        self.existing_profile_path(profile_name).is_some()
     }
 
+    /// Save a profile, keeping the format of its existing file. New profiles are saved as JSON.
     pub fn save_profile(&self, config: &Config) -> Result<()> {
-        let path = self.get_profile_path(&config.profile_name);
-        config.save(path.to_str().unwrap())?;
+        let format = self.existing_profile_path(&config.profile_name)
+            .and_then(|path| ProfileFormat::from_path(&path))
+            .unwrap_or(ProfileFormat::Json);
+        self.save_profile_as(config, format)
+    }
+
+    pub fn save_profile_as(&self, config: &Config, format: ProfileFormat) -> Result<()> {
+        ensure_valid_name(&config.profile_name)?;
+        let path = self.profile_path_as(&config.profile_name, format);
+        config.save_atomic(&path)?;
         Ok(())
     }
 
     pub fn load_profile(&self, profile_name: &str) -> Result<Config> {
-        let path = self.get_profile_path(profile_name);
-        if !path.exists() {
+        let Some(path) = self.existing_profile_path(profile_name) else {
             return Err(AppError::ParseError(format!("Profile '{}' not found", profile_name)));
-        }
+        };
         Config::load(path.to_str().unwrap())
     }
 
+    pub fn profile_metadata(&self, profile_name: &str) -> Result<ProfileMetadata> {
+        let Some(path) = self.existing_profile_path(profile_name) else {
+            return Err(AppError::ParseError(format!("Profile '{}' not found", profile_name)));
+        };
+        let metadata = fs::metadata(&path)?;
+        Ok(ProfileMetadata {
+            file_size_bytes: metadata.len(),
+            last_modified: metadata.modified()?.into(),
+            path,
+        })
+    }
+
     pub fn list_profiles(&self) -> Vec<String> {
-        let mut profiles = Vec::new();
-        if let Ok(entries) = fs::read_dir(&self.profiles_dir) {
-            for entry in entries.flatten() {
-                if let Ok(file_type) = entry.file_type() {
-                    if file_type.is_file() {
-                        if let Some(file_name) = entry.file_name().to_str() {
-                            if file_name.ends_with(".json") {
-                                if let Some(profile_name) = file_name.strip_suffix(".json") {
-                                    profiles.push(profile_name.to_string());
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
+        let mut profiles: Vec<String> = Self::profile_files(&self.profiles_dir)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|path| path.file_stem()?.to_str().map(str::to_string))
+            .collect();
         profiles.sort();
+        profiles.dedup();
         profiles
     }
 
+    /// Every profile with a summary of its settings, in the same order as `list_profiles`.
+    /// Profiles that can't be read are still listed, with default values.
+    pub fn list_profiles_with_summary(&self) -> Vec<ProfileSummary> {
+        self.list_profiles()
+            .into_iter()
+            .map(|name| {
+                let path = self.existing_profile_path(&name);
+                let modified = path.as_ref()
+                    .and_then(|path| fs::metadata(path).ok()?.modified().ok())
+                    .map(DateTime::<Utc>::from);
+                let profile = path.as_deref()
+                    .and_then(|path| {
+                        Self::read_profile_value(path)
+                            .map_err(|e| eprintln!("Failed to summarize profile '{}': {}", name, e))
+                            .ok()
+                    })
+                    .unwrap_or_default();
+
+                ProfileSummary::from_value(name, &profile, modified)
+            })
+            .collect()
+    }
+
+    // A profile file as an untyped value, without building and defaulting a full `Config`
+    fn read_profile_value(path: &Path) -> Result<serde_json::Value> {
+        let contents = fs::read_to_string(path)?;
+        match ProfileFormat::from_path(path) {
+            Some(ProfileFormat::Toml) => toml_format::from_str(&contents),
+            _ => Ok(serde_json::from_str(&contents)?),
+        }
+    }
+
+    // Profile names become file names, so only allow a safe set of characters
+    pub fn validate_profile_name(name: &str) -> Result<()> {
+        let re = Regex::new(r"^[A-Za-z0-9 _\-()]+$").unwrap();
+        if name.trim().is_empty() || !re.is_match(name) {
+            return Err(AppError::ParseError(
+                "Profile names may only contain letters, numbers, spaces, '-', '_' and parentheses".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn rename_profile(&self, old: &str, new: &str) -> Result<()> {
+        ensure_valid_name(new)?;
+        Self::validate_profile_name(new)?;
+
+        if self.profile_exists(new) {
+            return Err(AppError::ParseError(format!("Profile '{}' already exists", new)));
+        }
+
+        let Some(old_path) = self.existing_profile_path(old) else {
+            return Err(AppError::ParseError(format!("Profile '{}' not found", old)));
+        };
+        let format = ProfileFormat::from_path(&old_path).unwrap_or(ProfileFormat::Json);
+
+        let mut config = self.load_profile(old)?;
+        config.profile_name = new.to_string();
+        self.save_profile_as(&config, format)?;
+
+        fs::remove_file(old_path)?;
+        Ok(())
+    }
+
     pub fn delete_profile(&self, profile_name: &str) -> Result<()> {
-        let path = self.get_profile_path(profile_name);
-        if !path.exists() {
+        let Some(path) = self.existing_profile_path(profile_name) else {
             return Err(AppError::ParseError(format!("Profile '{}' not found", profile_name)));
-        }
-        fs::remove_file(path).map_err(|e| AppError::IoError(e))?;
+        };
+        fs::remove_file(path).map_err(AppError::from)?;
         Ok(())
     }
 
+    /// JSON and TOML profile files in a directory, sorted by name
+    fn profile_files(dir: &Path) -> Result<Vec<PathBuf>> {
+        let mut files: Vec<PathBuf> = fs::read_dir(dir)?
+            .flatten()
+            .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_file()))
+            .map(|entry| entry.path())
+            .filter(|path| ProfileFormat::from_path(path).is_some())
+            .collect();
+        files.sort();
+        Ok(files)
+    }
+
+    /// Copy every profile into `dest_dir`, returning the names of the profiles copied
+    pub fn export_all(&self, dest_dir: &Path) -> Result<Vec<String>> {
+        fs::create_dir_all(dest_dir)?;
+
+        let mut exported = Vec::new();
+        for path in Self::profile_files(&self.profiles_dir)? {
+            let Some(file_name) = path.file_name() else { continue };
+            fs::copy(&path, dest_dir.join(file_name))?;
+
+            if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                exported.push(name.to_string());
+            }
+        }
+        Ok(exported)
+    }
+
+    /// Copy every profile file in `src_dir` into the profiles directory, returning (imported, skipped).
+    /// Files already present are skipped unless `overwrite` is set, as are files that aren't valid profiles.
+    pub fn import_all(&self, src_dir: &Path, overwrite: bool) -> Result<(usize, usize)> {
+        let mut imported = 0;
+        let mut skipped = 0;
+
+        for path in Self::profile_files(src_dir)? {
+            let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                skipped += 1;
+                continue;
+            };
+
+            let Some(format) = ProfileFormat::from_path(&path) else {
+                skipped += 1;
+                continue;
+            };
+
+            let dest = self.profile_path_as(name, format);
+            let valid = ensure_valid_name(name).is_ok() && Config::load(path.to_str().unwrap_or_default()).is_ok();
+            if !valid || (self.profile_exists(name) && !overwrite) {
+                skipped += 1;
+                continue;
+            }
+
+            fs::copy(&path, dest)?;
+            imported += 1;
+        }
+        Ok((imported, skipped))
+    }
+
     pub fn create_default_profile(&self) -> Result<Config> {
         let config = Config::default();
         self.save_profile(&config)?;
@@ -0,0 +1,29 @@
+use windows::Win32::System::Diagnostics::Debug::MessageBeep;
+use windows::Win32::UI::WindowsAndMessaging::{MB_ICONERROR, MB_ICONWARNING, MB_OK, MESSAGEBOX_STYLE};
+
+/// Events that can play a sound, each with its own system sound
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SoundAlert {
+    Stopped,
+    Error,
+    TargetFound,
+}
+
+impl SoundAlert {
+    fn style(self) -> MESSAGEBOX_STYLE {
+        match self {
+            SoundAlert::Stopped => MB_ICONWARNING,
+            SoundAlert::Error => MB_ICONERROR,
+            SoundAlert::TargetFound => MB_OK,
+        }
+    }
+}
+
+/// Play the system sound for an alert. Returns immediately; the sound plays in the background.
+pub fn play(alert: SoundAlert) {
+    unsafe {
+        if let Err(e) = MessageBeep(alert.style()) {
+            eprintln!("Failed to play alert sound: {}", e);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::{Arc, RwLock};
+
+/// A value that one thread replaces wholesale while other threads read snapshots of it
+///
+/// Readers only hold the lock long enough to clone an `Arc`, so they never wait on
+/// a writer doing real work, and a snapshot stays valid after the value is replaced.
+pub struct SwapCell<T> {
+    current: RwLock<Arc<T>>,
+}
+
+impl<T> SwapCell<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            current: RwLock::new(Arc::new(value)),
+        }
+    }
+
+    /// The current value
+    pub fn load(&self) -> Arc<T> {
+        Arc::clone(&self.current.read().unwrap())
+    }
+
+    /// Replace the value; readers holding the old snapshot keep it until they drop it
+    pub fn store(&self, value: T) {
+        *self.current.write().unwrap() = Arc::new(value);
+    }
+}
+
+impl<T: Default> Default for SwapCell<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::thread;
+
+    #[test]
+    fn old_snapshot_outlives_a_store() {
+        let cell = SwapCell::new(String::from("first"));
+        let snapshot = cell.load();
+        cell.store(String::from("second"));
+
+        assert_eq!(*snapshot, "first");
+        assert_eq!(*cell.load(), "second");
+    }
+
+    #[test]
+    fn readers_see_whole_values_in_order_while_another_thread_stores() {
+        const STORES: u64 = 20_000;
+        // Each value repeats its number, so a reader seeing a half-written value would notice
+        let cell = Arc::new(SwapCell::new(vec![0u64; 16]));
+        let done = Arc::new(AtomicBool::new(false));
+
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let cell = Arc::clone(&cell);
+                let done = Arc::clone(&done);
+                thread::spawn(move || {
+                    let mut last = 0;
+                    loop {
+                        // Checked before loading, so the final value is always seen
+                        let finished = done.load(Ordering::SeqCst);
+                        let value = cell.load();
+                        assert!(value.iter().all(|&n| n == value[0]), "torn value {:?}", value);
+                        assert!(value[0] >= last, "went back from {} to {}", last, value[0]);
+                        last = value[0];
+                        if finished {
+                            return last;
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for n in 1..=STORES {
+            cell.store(vec![n; 16]);
+        }
+        done.store(true, Ordering::SeqCst);
+
+        for reader in readers {
+            assert_eq!(reader.join().unwrap(), STORES);
+        }
+    }
+}
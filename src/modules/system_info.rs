@@ -0,0 +1,86 @@
+use windows::core::{w, PCWSTR};
+use windows::Win32::System::Registry::{RegGetValueW, HKEY_LOCAL_MACHINE, RRF_RT_REG_SZ};
+use crate::modules::monitors;
+
+/// Registry key Windows keeps its product name and build number under
+const CURRENT_VERSION_KEY: PCWSTR = w!("SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion");
+
+/// The user's environment, as shown in Settings and pasted into bug reports
+#[derive(Debug, Clone, PartialEq)]
+pub struct SystemInfo {
+    /// Operating system with its build number where known, e.g. "Windows 10 Pro (build 19045)"
+    pub os: String,
+    /// Primary monitor resolution in physical pixels
+    pub resolution: Option<(i32, i32)>,
+    /// Display scaling, 100 at 96 DPI
+    pub dpi_percent: u32,
+    /// Logical CPU cores available to the process
+    pub cpu_cores: Option<usize>,
+}
+
+impl SystemInfo {
+    /// Read the current system information. `pixels_per_point` is the window's native
+    /// scale factor, which is the DPI scaling of the monitor it's on.
+    pub fn collect(pixels_per_point: f32) -> Self {
+        let resolution = monitors::list_monitors()
+            .into_iter()
+            .find(|monitor| monitor.primary)
+            .map(|monitor| (monitor.width, monitor.height));
+
+        Self {
+            os: os_version(),
+            resolution,
+            dpi_percent: (pixels_per_point * 100.0).round() as u32,
+            cpu_cores: std::thread::available_parallelism().ok().map(|cores| cores.get()),
+        }
+    }
+
+    /// One field per line, for pasting into a bug report
+    pub fn report(&self) -> String {
+        let resolution = self.resolution
+            .map(|(width, height)| format!("{}x{}", width, height))
+            .unwrap_or_else(|| "Unknown".to_string());
+        let cpu_cores = self.cpu_cores
+            .map(|cores| cores.to_string())
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        format!(
+            "Mouse Clicker {}\nOS: {}\nScreen Resolution: {}\nDPI Scaling: {}%\nCPU Cores: {}",
+            env!("CARGO_PKG_VERSION"), self.os, resolution, self.dpi_percent, cpu_cores,
+        )
+    }
+}
+
+/// Product name and build from the registry, falling back to the bare OS name
+fn os_version() -> String {
+    let product = read_version_value(w!("ProductName"));
+    let build = read_version_value(w!("CurrentBuild"));
+
+    match (product, build) {
+        (Some(product), Some(build)) => format!("{} (build {})", product, build),
+        (Some(product), None) => product,
+        (None, Some(build)) => format!("{} (build {})", std::env::consts::OS, build),
+        (None, None) => std::env::consts::OS.to_string(),
+    }
+}
+
+fn read_version_value(name: PCWSTR) -> Option<String> {
+    let mut buffer = [0u16; 256];
+    let mut size = std::mem::size_of_val(&buffer) as u32;
+
+    unsafe {
+        RegGetValueW(
+            HKEY_LOCAL_MACHINE,
+            CURRENT_VERSION_KEY,
+            name,
+            RRF_RT_REG_SZ,
+            None,
+            Some(buffer.as_mut_ptr() as *mut _),
+            Some(&mut size),
+        ).ok()?;
+    }
+
+    let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    let value = String::from_utf16_lossy(&buffer[..len]);
+    (!value.is_empty()).then_some(value)
+}
@@ -0,0 +1,131 @@
+use rand::Rng;
+use std::thread;
+use std::time::{Duration, Instant};
+use windows::core::PCWSTR;
+use windows::Win32::Foundation::{CloseHandle, WAIT_OBJECT_0};
+use windows::Win32::System::Threading::{
+    CreateWaitableTimerW, GetCurrentThread, SetThreadPriority, SetWaitableTimer, WaitForSingleObject, INFINITE,
+    THREAD_PRIORITY, THREAD_PRIORITY_ABOVE_NORMAL, THREAD_PRIORITY_HIGHEST, THREAD_PRIORITY_NORMAL, THREAD_PRIORITY_TIME_CRITICAL,
+};
+use crate::modules::config::AntiDetectionConfig;
+use crate::modules::error::{AppError, Result};
+use crate::modules::preferences::ThreadPriority;
+
+/// Sleep for `base_ms` plus or minus up to `jitter_ms`, timed by an OS waitable timer
+///
+/// Falls back to `thread::sleep` if the timer can't be created.
+pub fn jittered_sleep(base_ms: u64, jitter_ms: u64) {
+    let jitter = if jitter_ms > 0 {
+        let jitter_ms = jitter_ms as i64;
+        rand::thread_rng().gen_range(-jitter_ms..=jitter_ms)
+    } else {
+        0
+    };
+
+    let duration = Duration::from_millis((base_ms as i64 + jitter).max(0) as u64);
+    if duration.is_zero() {
+        return;
+    }
+
+    let start = Instant::now();
+    if !waitable_timer_sleep(duration) {
+        thread::sleep(duration);
+    }
+
+    // Timers can fire up to a scheduler tick early; make up the difference
+    if let Some(remaining) = duration.checked_sub(start.elapsed()) {
+        thread::sleep(remaining);
+    }
+}
+
+/// Short sleep used by polling loops, jittered at the OS level when anti-detection is on
+pub fn poll_sleep(base_ms: u64, anti_detection: &AntiDetectionConfig) {
+    if anti_detection.enabled {
+        jittered_sleep(base_ms, anti_detection.os_timer_jitter_ms);
+    } else {
+        thread::sleep(Duration::from_millis(base_ms));
+    }
+}
+
+/// Set the scheduling priority of the calling thread. Threads start at normal priority.
+pub fn set_current_thread_priority(priority: ThreadPriority) -> Result<()> {
+    let level: THREAD_PRIORITY = match priority {
+        ThreadPriority::Normal => THREAD_PRIORITY_NORMAL,
+        ThreadPriority::AboveNormal => THREAD_PRIORITY_ABOVE_NORMAL,
+        ThreadPriority::High => THREAD_PRIORITY_HIGHEST,
+        ThreadPriority::Realtime => THREAD_PRIORITY_TIME_CRITICAL,
+    };
+
+    unsafe {
+        SetThreadPriority(GetCurrentThread(), level)
+            .map_err(|e| AppError::SystemError(format!("Failed to set thread priority to {:?}: {}", priority, e)))
+    }
+}
+
+/// Block on a one-shot waitable timer. Returns false if the timer couldn't be used.
+fn waitable_timer_sleep(duration: Duration) -> bool {
+    unsafe {
+        let Ok(timer) = CreateWaitableTimerW(None, true, PCWSTR::null()) else {
+            return false;
+        };
+
+        // A negative due time is relative to now, in 100 ns units
+        let due_time = -((duration.as_nanos() / 100) as i64);
+        let slept = SetWaitableTimer(timer, &due_time, 0, None, None, false).is_ok()
+            && WaitForSingleObject(timer, INFINITE) == WAIT_OBJECT_0;
+
+        let _ = CloseHandle(timer);
+        slept
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Slack allowed above the requested time, for timer resolution and a busy test machine
+    const TOLERANCE: Duration = Duration::from_millis(100);
+
+    fn time(sleep: impl FnOnce()) -> Duration {
+        let start = Instant::now();
+        sleep();
+        start.elapsed()
+    }
+
+    #[test]
+    fn normal_priority_can_always_be_set() {
+        // Setting it on a test thread whose priority is already normal needs no privileges
+        let set = thread::spawn(|| set_current_thread_priority(ThreadPriority::Normal)).join().unwrap();
+        assert!(set.is_ok(), "{:?}", set);
+    }
+
+    #[test]
+    fn sleeps_at_least_as_long_as_requested() {
+        let elapsed = time(|| jittered_sleep(40, 0));
+        assert!(elapsed >= Duration::from_millis(40), "slept {:?}", elapsed);
+        assert!(elapsed < Duration::from_millis(40) + TOLERANCE, "slept {:?}", elapsed);
+    }
+
+    #[test]
+    fn jitter_stays_within_its_range() {
+        for _ in 0..5 {
+            let elapsed = time(|| jittered_sleep(40, 15));
+            assert!(elapsed >= Duration::from_millis(25), "slept {:?}", elapsed);
+            assert!(elapsed < Duration::from_millis(55) + TOLERANCE, "slept {:?}", elapsed);
+        }
+    }
+
+    #[test]
+    fn jitter_larger_than_the_base_never_sleeps_a_negative_time() {
+        let elapsed = time(|| jittered_sleep(0, 20));
+        assert!(elapsed < Duration::from_millis(20) + TOLERANCE, "slept {:?}", elapsed);
+    }
+
+    #[test]
+    fn poll_sleep_without_anti_detection_sleeps_the_base_time() {
+        let anti_detection = AntiDetectionConfig { enabled: false, os_timer_jitter_ms: 500 };
+        let elapsed = time(|| poll_sleep(30, &anti_detection));
+        assert!(elapsed >= Duration::from_millis(30), "slept {:?}", elapsed);
+        assert!(elapsed < Duration::from_millis(30) + TOLERANCE, "slept {:?}", elapsed);
+    }
+}
@@ -0,0 +1,118 @@
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Number, Value as Json};
+use toml_edit::{Array, ArrayOfTables, DocumentMut, InlineTable, Item, Table, Value};
+use crate::modules::error::{AppError, Result};
+
+/// Serialize a value as a TOML document. Structs become tables and enums quoted strings,
+/// the same shapes they take in JSON. Fields that are `None` are left out.
+pub fn to_string<T: Serialize>(value: &T) -> Result<String> {
+    let Json::Object(fields) = serde_json::to_value(value)? else {
+        return Err(AppError::ParseError("Only structs can be written as TOML".to_string()));
+    };
+
+    let mut document = DocumentMut::new();
+    *document.as_table_mut() = json_to_table(&fields);
+    Ok(document.to_string())
+}
+
+/// Parse a TOML document into a value
+pub fn from_str<T: DeserializeOwned>(toml: &str) -> Result<T> {
+    let document: DocumentMut = toml.parse()
+        .map_err(|e| AppError::ParseError(format!("Invalid TOML: {}", e)))?;
+
+    Ok(serde_json::from_value(table_to_json(document.as_table()))?)
+}
+
+fn json_to_table(fields: &Map<String, Json>) -> Table {
+    let mut table = Table::new();
+    for (key, value) in fields {
+        if let Some(item) = json_to_item(value) {
+            table.insert(key, item);
+        }
+    }
+    table
+}
+
+/// Objects become `[table]` sections and lists of objects `[[array]]` sections; everything else is written inline
+fn json_to_item(value: &Json) -> Option<Item> {
+    match value {
+        Json::Object(fields) => Some(Item::Table(json_to_table(fields))),
+        Json::Array(items) if !items.is_empty() && items.iter().all(Json::is_object) => {
+            let mut tables = ArrayOfTables::new();
+            for item in items {
+                if let Json::Object(fields) = item {
+                    tables.push(json_to_table(fields));
+                }
+            }
+            Some(Item::ArrayOfTables(tables))
+        },
+        value => json_to_value(value).map(Item::Value),
+    }
+}
+
+fn json_to_value(value: &Json) -> Option<Value> {
+    match value {
+        // TOML has no null, so missing keys stand in for `None`
+        Json::Null => None,
+        Json::Bool(b) => Some(Value::from(*b)),
+        Json::Number(n) => Some(match n.as_i64() {
+            Some(i) => Value::from(i),
+            None => Value::from(shortest_float(n.as_f64().unwrap_or_default())),
+        }),
+        Json::String(s) => Some(Value::from(s.as_str())),
+        Json::Array(items) => {
+            let mut array = Array::new();
+            for item in items.iter().filter_map(json_to_value) {
+                array.push(item);
+            }
+            Some(Value::Array(array))
+        },
+        Json::Object(fields) => {
+            let mut table = InlineTable::new();
+            for (key, value) in fields {
+                if let Some(value) = json_to_value(value) {
+                    table.insert(key, value);
+                }
+            }
+            Some(Value::InlineTable(table))
+        },
+    }
+}
+
+/// `f32` fields arrive widened to `f64`, so 0.1 would be written as 0.10000000149011612.
+/// Values that are exactly an `f32` are written with the digits the `f32` would print.
+fn shortest_float(value: f64) -> f64 {
+    let narrow = value as f32;
+    if narrow as f64 == value {
+        narrow.to_string().parse().unwrap_or(value)
+    } else {
+        value
+    }
+}
+
+fn table_to_json(table: &Table) -> Json {
+    Json::Object(table.iter().map(|(key, item)| (key.to_string(), item_to_json(item))).collect())
+}
+
+fn item_to_json(item: &Item) -> Json {
+    match item {
+        Item::None => Json::Null,
+        Item::Value(value) => value_to_json(value),
+        Item::Table(table) => table_to_json(table),
+        Item::ArrayOfTables(tables) => Json::Array(tables.iter().map(table_to_json).collect()),
+    }
+}
+
+fn value_to_json(value: &Value) -> Json {
+    match value {
+        Value::String(s) => Json::String(s.value().clone()),
+        Value::Integer(i) => Json::Number((*i.value()).into()),
+        Value::Float(f) => Number::from_f64(*f.value()).map_or(Json::Null, Json::Number),
+        Value::Boolean(b) => Json::Bool(*b.value()),
+        Value::Datetime(d) => Json::String(d.value().to_string()),
+        Value::Array(items) => Json::Array(items.iter().map(value_to_json).collect()),
+        Value::InlineTable(table) => Json::Object(
+            table.iter().map(|(key, value)| (key.to_string(), value_to_json(value))).collect()
+        ),
+    }
+}
@@ -21,7 +21,7 @@ pub fn print_banner() {
     "#);
 }
 
-pub fn print_summary(start_time: Instant, click_count: u32) {
+pub fn print_summary(start_time: Instant, click_count: u64) {
     let duration = start_time.elapsed();
 
     let msg1 = format!("Program finished. Total clicks: {}", click_count);
@@ -39,14 +39,14 @@ pub fn print_summary(start_time: Instant, click_count: u32) {
 
 pub fn clear_screen() -> Result<()> {
     let (_, rows) = crossterm::terminal::size()
-        .map_err(|e| crate::modules::error::AppError::IoError(e))?;
+        .map_err(crate::modules::error::AppError::from)?;
 
     execute!(
         io::stdout(),
         cursor::MoveTo(0, 0),
         Clear(ClearType::FromCursorDown),
         cursor::MoveTo(0, rows - 1),
-    ).map_err(|e| crate::modules::error::AppError::IoError(e))?;
+    ).map_err(crate::modules::error::AppError::from)?;
 
     Ok(())
 }
@@ -55,3 +55,72 @@ pub fn encode_text(text: &str) -> String {
     let digest = md5::compute(text.as_bytes());
     format!("{:x}", digest)
 }
+
+/// Format a count with comma thousands separators, e.g. 1234567 as "1,234,567"
+pub fn format_thousands(value: u64) -> String {
+    let digits = value.to_string();
+    let mut formatted = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            formatted.push(',');
+        }
+        formatted.push(digit);
+    }
+    formatted
+}
+
+/// Format a duration as "0:05", "12:34" or, from an hour up, "1:23:45"
+pub fn format_duration(secs: f32) -> String {
+    let total = secs.max(0.0) as u64;
+    let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+/// Format a duration as HH:MM:SS.mmm, for displays where the milliseconds matter
+pub fn format_duration_precise(secs: f32) -> String {
+    let total_ms = (secs.max(0.0) as f64 * 1000.0).round() as u64;
+    let total = total_ms / 1000;
+    format!("{:02}:{:02}:{:02}.{:03}", total / 3600, total / 60 % 60, total % 60, total_ms % 1000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thousands_are_separated_with_commas() {
+        assert_eq!(format_thousands(0), "0");
+        assert_eq!(format_thousands(999), "999");
+        assert_eq!(format_thousands(1_000), "1,000");
+        assert_eq!(format_thousands(1_234_567), "1,234,567");
+        assert_eq!(format_thousands(u32::MAX as u64 + 1), "4,294,967,296");
+        assert_eq!(format_thousands(u64::MAX), "18,446,744,073,709,551,615");
+    }
+
+    #[test]
+    fn durations_switch_format_at_the_hour() {
+        assert_eq!(format_duration(0.0), "0:00");
+        assert_eq!(format_duration(59.0), "0:59");
+        assert_eq!(format_duration(60.0), "1:00");
+        assert_eq!(format_duration(3599.0), "59:59");
+        assert_eq!(format_duration(3600.0), "1:00:00");
+        assert_eq!(format_duration(86400.0), "24:00:00");
+    }
+
+    #[test]
+    fn durations_round_down_and_never_go_negative() {
+        assert_eq!(format_duration(59.9), "0:59");
+        assert_eq!(format_duration(-5.0), "0:00");
+    }
+
+    #[test]
+    fn precise_durations_keep_the_milliseconds() {
+        assert_eq!(format_duration_precise(0.0), "00:00:00.000");
+        assert_eq!(format_duration_precise(61.25), "00:01:01.250");
+        assert_eq!(format_duration_precise(3600.5), "01:00:00.500");
+    }
+}
@@ -0,0 +1,110 @@
+use regex::Regex;
+use std::sync::OnceLock;
+use crate::modules::error::{AppError, Result};
+
+/// Longest suffix a name gets when it becomes a file name, the one of a target's match log.
+/// The `.tmp` added to `.json` and `.toml` during an atomic save is shorter.
+const LONGEST_FILE_SUFFIX: &str = ".matchlog.jsonl";
+
+/// Longest name accepted, in bytes. Most filesystems cap file names at 255, suffix included.
+const MAX_NAME_BYTES: usize = 255 - LONGEST_FILE_SUFFIX.len();
+
+/// Patterns that make a name unsafe to use in a file name, with the reason shown to the user
+fn deny_patterns() -> &'static [(Regex, &'static str)] {
+    static PATTERNS: OnceLock<Vec<(Regex, &'static str)>> = OnceLock::new();
+    PATTERNS.get_or_init(|| {
+        [
+            (r"[/\\]", "contains a path separator"),
+            (r"\.\.", "contains '..'"),
+            (r"[\x00-\x1F\x7F]", "contains control characters"),
+            (r#"[<>:"|?*]"#, "contains a character that isn't allowed in file names"),
+        ]
+        .into_iter()
+        .map(|(pattern, reason)| (Regex::new(pattern).expect("name deny pattern is valid"), reason))
+        .collect()
+    })
+}
+
+/// Check that a user-supplied name can safely become part of a file name
+pub fn validate_name(name: &str) -> std::result::Result<(), &'static str> {
+    if name.trim().is_empty() {
+        return Err("name is empty");
+    }
+
+    if name.len() > MAX_NAME_BYTES {
+        return Err("name is longer than 240 bytes");
+    }
+
+    match deny_patterns().iter().find(|(pattern, _)| pattern.is_match(name)) {
+        Some((_, reason)) => Err(reason),
+        None => Ok(()),
+    }
+}
+
+/// `validate_name` as an application error, for use before building a path
+pub fn ensure_valid_name(name: &str) -> Result<()> {
+    validate_name(name).map_err(|issue| AppError::ParseError(format!("Invalid name: {}", issue)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_leave_room_for_the_longest_suffix() {
+        let longest = "a".repeat(MAX_NAME_BYTES);
+        assert_eq!(validate_name(&longest), Ok(()));
+        assert_eq!(format!("{}{}", longest, LONGEST_FILE_SUFFIX).len(), 255);
+
+        let issue = validate_name(&format!("{}a", longest)).unwrap_err();
+        assert_eq!(issue, format!("name is longer than {} bytes", MAX_NAME_BYTES));
+    }
+
+    #[test]
+    fn length_is_counted_in_bytes() {
+        // Three bytes each in UTF-8
+        assert!(validate_name(&"€".repeat(80)).is_ok());
+        assert!(validate_name(&"€".repeat(81)).is_err());
+    }
+
+    #[test]
+    fn blank_names_are_rejected() {
+        assert_eq!(validate_name(""), Err("name is empty"));
+        assert_eq!(validate_name("  \t"), Err("name is empty"));
+    }
+
+    #[test]
+    fn path_separators_are_rejected() {
+        assert_eq!(validate_name("a/b"), Err("contains a path separator"));
+        assert_eq!(validate_name("a\\b"), Err("contains a path separator"));
+    }
+
+    #[test]
+    fn parent_directory_references_are_rejected() {
+        assert_eq!(validate_name(".."), Err("contains '..'"));
+        assert_eq!(validate_name("v1..2"), Err("contains '..'"));
+        assert!(validate_name("v1.2").is_ok());
+    }
+
+    #[test]
+    fn control_characters_are_rejected() {
+        for name in ["tab\there", "new\nline", "nul\0", "del\x7F"] {
+            assert_eq!(validate_name(name), Err("contains control characters"), "{:?}", name);
+        }
+    }
+
+    #[test]
+    fn characters_windows_forbids_in_file_names_are_rejected() {
+        for c in ['<', '>', ':', '"', '|', '?', '*'] {
+            let name = format!("a{}b", c);
+            assert_eq!(validate_name(&name), Err("contains a character that isn't allowed in file names"), "{:?}", name);
+        }
+    }
+
+    #[test]
+    fn ordinary_names_are_accepted() {
+        for name in ["Default", "Fishing spot #2", "Büro (links)", "play_button-v3.final"] {
+            assert_eq!(validate_name(name), Ok(()), "{:?}", name);
+        }
+    }
+}
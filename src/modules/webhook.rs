@@ -0,0 +1,187 @@
+use std::ffi::c_void;
+use windows::core::{PCWSTR, PWSTR};
+use windows::Win32::Networking::WinHttp::{
+    WinHttpCloseHandle, WinHttpConnect, WinHttpCrackUrl, WinHttpOpen, WinHttpOpenRequest, WinHttpQueryHeaders,
+    WinHttpReceiveResponse, WinHttpSendRequest, WinHttpSetTimeouts, URL_COMPONENTS, WINHTTP_ACCESS_TYPE_AUTOMATIC_PROXY,
+    WINHTTP_FLAG_SECURE, WINHTTP_INTERNET_SCHEME_HTTPS, WINHTTP_OPEN_REQUEST_FLAGS, WINHTTP_QUERY_FLAG_NUMBER,
+    WINHTTP_QUERY_STATUS_CODE,
+};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use crate::gui::app::AppState;
+use crate::modules::error::{AppError, Result};
+use crate::modules::event_log::SessionEvent;
+
+/// How long any one phase of a delivery (resolve, connect, send, receive) may take
+const TIMEOUT_MS: i32 = 10_000;
+
+/// Outcome of one webhook delivery
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeliveryResult {
+    /// The endpoint answered with a 2xx status
+    Delivered,
+    /// The endpoint answered with any other status
+    Rejected(u16),
+    /// The request never got an answer
+    Failed(String),
+}
+
+impl DeliveryResult {
+    pub fn is_success(&self) -> bool {
+        *self == DeliveryResult::Delivered
+    }
+
+    /// Short reason shown in the status bar tooltip
+    pub fn summary(&self) -> String {
+        match self {
+            DeliveryResult::Delivered => "OK".to_string(),
+            DeliveryResult::Rejected(status) => format!("FAILED: {}", status),
+            DeliveryResult::Failed(reason) => format!("FAILED: {}", reason),
+        }
+    }
+}
+
+/// POST a JSON body to `url` and block until the endpoint answers.
+/// `auth_header` is sent as the `Authorization` header when given.
+pub fn post_json(url: &str, body: &str, auth_header: Option<&str>) -> DeliveryResult {
+    match send(url, body, auth_header) {
+        Ok(status) if (200..300).contains(&status) => DeliveryResult::Delivered,
+        Ok(status) => DeliveryResult::Rejected(status),
+        Err(e) => DeliveryResult::Failed(e.to_string()),
+    }
+}
+
+fn wide(s: &str) -> Vec<u16> {
+    s.encode_utf16().chain(std::iter::once(0)).collect()
+}
+
+/// Closes a WinHTTP handle when dropped
+struct Handle(*mut c_void);
+
+impl Handle {
+    fn new(handle: *mut c_void, what: &str) -> Result<Self> {
+        if handle.is_null() {
+            Err(AppError::ParseError(format!("Failed to {}: {}", what, windows::core::Error::from_win32())))
+        } else {
+            Ok(Self(handle))
+        }
+    }
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = WinHttpCloseHandle(self.0);
+        }
+    }
+}
+
+/// Send the request and return the response status code
+fn send(url: &str, body: &str, auth_header: Option<&str>) -> Result<u16> {
+    let url_wide = wide(url);
+    let mut host = [0u16; 256];
+    let mut path = [0u16; 2048];
+
+    let mut parts = URL_COMPONENTS {
+        dwStructSize: std::mem::size_of::<URL_COMPONENTS>() as u32,
+        lpszHostName: PWSTR(host.as_mut_ptr()),
+        dwHostNameLength: host.len() as u32,
+        lpszUrlPath: PWSTR(path.as_mut_ptr()),
+        dwUrlPathLength: path.len() as u32,
+        // Asking for the extra info (query string) separately would drop it from the path
+        dwExtraInfoLength: 0,
+        ..Default::default()
+    };
+
+    unsafe {
+        WinHttpCrackUrl(&url_wide[..url_wide.len() - 1], 0, &mut parts)
+            .map_err(|e| AppError::ParseError(format!("Invalid webhook URL '{}': {}", url, e)))?;
+
+        let session = Handle::new(
+            WinHttpOpen(PCWSTR(wide("MouseClicker").as_ptr()), WINHTTP_ACCESS_TYPE_AUTOMATIC_PROXY, PCWSTR::null(), PCWSTR::null(), 0),
+            "open HTTP session",
+        )?;
+        let _ = WinHttpSetTimeouts(session.0, TIMEOUT_MS, TIMEOUT_MS, TIMEOUT_MS, TIMEOUT_MS);
+
+        let connection = Handle::new(
+            WinHttpConnect(session.0, PCWSTR(host.as_ptr()), parts.nPort, 0),
+            "connect",
+        )?;
+
+        let flags = if parts.nScheme == WINHTTP_INTERNET_SCHEME_HTTPS { WINHTTP_FLAG_SECURE } else { WINHTTP_OPEN_REQUEST_FLAGS(0) };
+        let request = Handle::new(
+            WinHttpOpenRequest(connection.0, PCWSTR(wide("POST").as_ptr()), PCWSTR(path.as_ptr()), PCWSTR::null(), PCWSTR::null(), std::ptr::null(), flags),
+            "open request",
+        )?;
+
+        let mut headers = "Content-Type: application/json\r\n".to_string();
+        if let Some(auth) = auth_header {
+            headers.push_str(&format!("Authorization: {}\r\n", auth));
+        }
+        let headers: Vec<u16> = headers.encode_utf16().collect();
+
+        WinHttpSendRequest(request.0, Some(&headers), Some(body.as_ptr() as *const c_void), body.len() as u32, body.len() as u32, 0)
+            .map_err(|e| AppError::ParseError(format!("Failed to send webhook: {}", e)))?;
+        WinHttpReceiveResponse(request.0, std::ptr::null_mut())
+            .map_err(|e| AppError::ParseError(format!("No response from webhook: {}", e)))?;
+
+        let mut status = 0u32;
+        let mut status_len = std::mem::size_of::<u32>() as u32;
+        WinHttpQueryHeaders(
+            request.0,
+            WINHTTP_QUERY_STATUS_CODE | WINHTTP_QUERY_FLAG_NUMBER,
+            PCWSTR::null(),
+            Some(&mut status as *mut u32 as *mut c_void),
+            &mut status_len,
+            std::ptr::null_mut(),
+        ).map_err(|e| AppError::ParseError(format!("Failed to read webhook response: {}", e)))?;
+
+        Ok(status as u16)
+    }
+}
+
+/// POST a session event to `url` as JSON
+pub fn post_event(url: &str, event: &SessionEvent, auth_header: Option<&str>) -> DeliveryResult {
+    match serde_json::to_string(event) {
+        Ok(body) => post_json(url, &body, auth_header),
+        Err(e) => DeliveryResult::Failed(format!("Failed to serialize event: {}", e)),
+    }
+}
+
+/// Delivers session events to the configured webhook from a background thread,
+/// so a slow endpoint never holds up the GUI or the clicker
+pub struct WebhookSender {
+    _handle: JoinHandle<()>,
+}
+
+impl WebhookSender {
+    /// Start delivering every event logged to the state from now on. The webhook settings
+    /// are read for each event, so changes apply without a restart.
+    pub fn start(state: Arc<Mutex<AppState>>) -> Self {
+        let events = state.lock().unwrap().session_events.subscribe();
+
+        let handle = thread::spawn(move || {
+            for event in events {
+                let config = match state.lock() {
+                    Ok(state) => state.preferences.webhook.clone(),
+                    Err(_) => break,
+                };
+                if !config.enabled || config.url.trim().is_empty() || !config.on_events.contains(&event.kind) {
+                    continue;
+                }
+
+                let result = post_event(config.url.trim(), &event, config.auth_header.as_deref());
+
+                // Failures are reported but not logged as events, which could be sent and fail again
+                if let Ok(mut state) = state.lock() {
+                    state.record_webhook_delivery(&result);
+                    if !result.is_success() {
+                        state.error_queue.send(format!("Webhook delivery of {:?} event {}", event.kind, result.summary()));
+                    }
+                }
+            }
+        });
+
+        Self { _handle: handle }
+    }
+}